@@ -1,11 +1,22 @@
 use bencher::{benchmark_group, benchmark_main, black_box, Bencher};
 use rand::Rng;
 use ta::indicators::{
-	AverageTrueRange, BollingerBands, ChandelierExit, CommodityChannelIndex, EfficiencyRatio,
-	ExponentialMovingAverage, FastStochastic, KeltnerChannel, Maximum, MeanAbsoluteDeviation,
-	Minimum, MoneyFlowIndex, MovingAverageConvergenceDivergence, OnBalanceVolume,
-	PercentagePriceOscillator, RateOfChange, RelativeStrengthIndex, SimpleMovingAverage,
-	SlowStochastic, StandardDeviation, TrueRange, WeightedMovingAverage,
+	AccumulationDistribution, Aroon, AverageTrueRange, AverageTrueRangePercent, BalanceOfPower,
+	BollingerBandPctB, BollingerBandWidth, BollingerBands, ChaikinMoneyFlow, ChaikinOscillator,
+	ChandeMomentumOscillator, ChandelierExit, ChoppinessIndex, CommodityChannelIndex, CoppockCurve,
+	CumulativeReturn, DetrendedPriceOscillator, DirectionalMovementIndex, DonchianChannel,
+	DoubleExponentialMovingAverage, EaseOfMovement, EfficiencyRatio, ElderRay,
+	ExponentialMovingAverage, FastStochastic, ForceIndex, HistoricalVolatility, HullMovingAverage,
+	IchimokuCloud, KaufmanAdaptiveMovingAverage, KeltnerChannel, KlingerOscillator, Kurtosis,
+	LinearRegression, LogReturn, MassIndex, MaxDrawdown, Maximum, MeanAbsoluteDeviation, Median,
+	MedianPrice, Minimum, MoneyFlowIndex, MovingAverageConvergenceDivergence, OnBalanceVolume,
+	ParabolicSar, PercentagePriceOscillator, Percentile, PivotPoints, PriceVolumeTrend,
+	RateOfChange, RelativeStrengthIndex, RollingSum, SharpeRatio, SimpleMovingAverage, Skewness,
+	SlowStochastic, StandardDeviation, StandardError, StochasticOscillator, StochasticRsi,
+	TimeSeriesForecast, TripleExponentialAverage, TripleExponentialMovingAverage, TrueRange,
+	TypicalPrice, UltimateOscillator, Variance, VolumeRsi, VolumeWeightedAveragePrice,
+	VortexIndicator, WeightedClose, WeightedMovingAverage, WilliamsPercentRange,
+	ZeroLagExponentialMovingAverage, Zigzag,
 };
 use ta::{DataItem, Next};
 
@@ -52,26 +63,80 @@ macro_rules! bench_indicators {
 }
 
 bench_indicators!(
+	AccumulationDistribution,
+	Aroon,
 	AverageTrueRange,
+	AverageTrueRangePercent,
+	BalanceOfPower,
+	BollingerBandPctB,
+	BollingerBandWidth,
+	ChaikinMoneyFlow,
+	ChaikinOscillator,
+	ChandeMomentumOscillator,
+	ChoppinessIndex,
+	CoppockCurve,
+	CumulativeReturn,
+	DetrendedPriceOscillator,
+	DirectionalMovementIndex,
+	DonchianChannel,
+	DoubleExponentialMovingAverage,
+	EaseOfMovement,
+	ElderRay,
 	ExponentialMovingAverage,
+	ForceIndex,
+	HistoricalVolatility,
+	HullMovingAverage,
+	KaufmanAdaptiveMovingAverage,
+	KlingerOscillator,
+	Kurtosis,
+	LinearRegression,
+	LogReturn,
+	MassIndex,
+	MaxDrawdown,
 	MeanAbsoluteDeviation,
 	BollingerBands,
 	ChandelierExit,
 	EfficiencyRatio,
 	FastStochastic,
+	IchimokuCloud,
 	KeltnerChannel,
 	Maximum,
+	Median,
+	MedianPrice,
 	Minimum,
 	MoneyFlowIndex,
 	MovingAverageConvergenceDivergence,
 	OnBalanceVolume,
+	ParabolicSar,
 	PercentagePriceOscillator,
 	CommodityChannelIndex,
+	Percentile,
+	PivotPoints,
+	PriceVolumeTrend,
 	RateOfChange,
 	RelativeStrengthIndex,
+	RollingSum,
+	SharpeRatio,
 	SimpleMovingAverage,
+	Skewness,
 	SlowStochastic,
 	StandardDeviation,
+	StandardError,
+	StochasticOscillator,
+	StochasticRsi,
+	TimeSeriesForecast,
+	TripleExponentialAverage,
+	TripleExponentialMovingAverage,
 	TrueRange,
-	WeightedMovingAverage
+	TypicalPrice,
+	UltimateOscillator,
+	Variance,
+	VolumeRsi,
+	VolumeWeightedAveragePrice,
+	VortexIndicator,
+	WeightedClose,
+	WeightedMovingAverage,
+	WilliamsPercentRange,
+	ZeroLagExponentialMovingAverage,
+	Zigzag
 );
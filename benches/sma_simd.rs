@@ -0,0 +1,41 @@
+use bencher::{benchmark_group, benchmark_main, black_box, Bencher};
+use rand::Rng;
+use ta::indicators::SimpleMovingAverage;
+use ta::Next;
+
+const ITEMS_COUNT: usize = 1_000_000;
+const PERIOD: usize = 20;
+
+fn rand_prices() -> Vec<f64> {
+	let mut rng = rand::thread_rng();
+	(0..ITEMS_COUNT)
+		.map(|_| rng.gen_range(0.0, 1000.0))
+		.collect()
+}
+
+fn sma_next(bench: &mut Bencher) {
+	let prices = rand_prices();
+	let mut output = vec![0.0; ITEMS_COUNT];
+	let mut sma = SimpleMovingAverage::new(PERIOD).unwrap();
+
+	bench.iter(|| {
+		for (out, price) in output.iter_mut().zip(&prices) {
+			*out = sma.next(*price);
+		}
+		black_box(&output);
+	})
+}
+
+fn sma_next_slice_simd(bench: &mut Bencher) {
+	let prices = rand_prices();
+	let mut output = vec![0.0; ITEMS_COUNT];
+	let mut sma = SimpleMovingAverage::new(PERIOD).unwrap();
+
+	bench.iter(|| {
+		sma.next_slice_simd(&prices, &mut output);
+		black_box(&output);
+	})
+}
+
+benchmark_group!(benches, sma_next, sma_next_slice_simd);
+benchmark_main!(benches);
@@ -0,0 +1,74 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use ta::indicators::{
+	BollingerBands, ExponentialMovingAverage, Minimum, MovingAverageConvergenceDivergence,
+	RelativeStrengthIndex, SimpleMovingAverage,
+};
+use ta::Next;
+
+const ITEMS_COUNT: usize = 100_000;
+const SEED: u64 = 42;
+
+/// Pre-generated prices, so that the random number generator is not measured.
+fn rand_prices() -> Vec<f64> {
+	let mut rng = StdRng::seed_from_u64(SEED);
+	(0..ITEMS_COUNT)
+		.map(|_| rng.gen_range(1.0, 1000.0))
+		.collect()
+}
+
+macro_rules! bench_indicators {
+    ($c:expr, $prices:expr, $($name:literal => $indicator:expr),* $(,)?) => {
+        let mut group = $c.benchmark_group("next");
+        group.throughput(Throughput::Elements(ITEMS_COUNT as u64));
+        $(
+            group.bench_function($name, |b| {
+                let mut indicator = $indicator;
+                b.iter(|| {
+                    for &price in $prices {
+                        black_box(indicator.next(price));
+                    }
+                })
+            });
+        )*
+        group.finish();
+
+        let mut group = $c.benchmark_group("next_slice");
+        group.throughput(Throughput::Elements(ITEMS_COUNT as u64));
+        $(
+            group.bench_function($name, |b| {
+                let mut indicator = $indicator;
+                let mut output = Vec::with_capacity(ITEMS_COUNT);
+                b.iter(|| {
+                    output.clear();
+                    indicator.next_slice($prices, &mut output);
+                    black_box(&output);
+                })
+            });
+        )*
+        group.finish();
+    };
+}
+
+fn indicators(c: &mut Criterion) {
+	let prices = rand_prices();
+
+	bench_indicators!(
+		c,
+		&prices,
+		"MIN(14)" => Minimum::new(14).unwrap(),
+		"MIN(200)" => Minimum::new(200).unwrap(),
+		"SMA(14)" => SimpleMovingAverage::new(14).unwrap(),
+		"SMA(200)" => SimpleMovingAverage::new(200).unwrap(),
+		"EMA(14)" => ExponentialMovingAverage::new(14).unwrap(),
+		"MACD(12, 26, 9)" => MovingAverageConvergenceDivergence::new(12, 26, 9).unwrap(),
+		"BB(20, 2)" => BollingerBands::new(20, 2.0).unwrap(),
+		"RSI(14)" => RelativeStrengthIndex::new(14).unwrap(),
+	);
+}
+
+criterion_group!(benches, indicators);
+criterion_main!(benches);
@@ -0,0 +1,280 @@
+//! Feeds arbitrary sequences of `f64` values, including NaN, infinities and subnormals, to
+//! every indicator.
+//!
+//! Checks that no indicator panics and that after a reset the same sequence produces the
+//! same outputs. `Minimum` and `Maximum` are also compared to the naive result of their window,
+//! and must recover a finite output after a NaN.
+#![no_main]
+
+use core::fmt::Debug;
+
+use libfuzzer_sys::fuzz_target;
+use ta::indicators::*;
+use ta::signals::*;
+use ta::{DataItem, Next, Reset};
+
+/// Length of the series built by repeating the inputs, to accumulate rounding errors.
+const LONG: usize = 100_000;
+
+fn check<I, T>(mut indicator: I, inputs: &[T])
+where
+	I: Next<T> + Reset,
+	I::Output: Debug,
+	T: Copy,
+{
+	// Debug formatting makes NaN outputs compare equal
+	let outputs: Vec<String> = inputs
+		.iter()
+		.map(|&input| format!("{:?}", indicator.next(input)))
+		.collect();
+
+	indicator.reset();
+	for (&input, output) in inputs.iter().zip(&outputs) {
+		assert_eq!(&format!("{:?}", indicator.next(input)), output);
+	}
+}
+
+/// Same as `check` for `f64` outputs, without formatting them, for the long series.
+fn check_f64<I>(mut indicator: I, inputs: &[f64])
+where
+	I: Next<f64, Output = f64> + Reset,
+{
+	let outputs: Vec<u64> = inputs
+		.iter()
+		.map(|&input| indicator.next(input).to_bits())
+		.collect();
+
+	indicator.reset();
+	for (&input, &output) in inputs.iter().zip(&outputs) {
+		assert_eq!(indicator.next(input).to_bits(), output);
+	}
+}
+
+/// Compares the outputs to `fold` applied to the non-NaN values of the window, then checks that
+/// a NaN followed by `period` finite values gives a finite output.
+fn check_window<I>(mut indicator: I, period: usize, inputs: &[f64], fold: fn(f64, f64) -> f64)
+where
+	I: Next<f64, Output = f64>,
+{
+	let expected = |window: &[f64]| {
+		window
+			.iter()
+			.copied()
+			.filter(|x| !x.is_nan())
+			.reduce(fold)
+			.unwrap_or(f64::NAN)
+	};
+
+	for (i, &input) in inputs.iter().enumerate() {
+		let output = indicator.next(input);
+		let expected = expected(&inputs[(i + 1).saturating_sub(period)..=i]);
+		assert!(
+			output == expected || (output.is_nan() && expected.is_nan()),
+			"{} instead of {} at {}",
+			output,
+			expected,
+			i
+		);
+	}
+
+	indicator.next(f64::NAN);
+	let finite: Vec<f64> = (0..period).map(|i| i as f64).collect();
+	let mut output = f64::NAN;
+	for &x in &finite {
+		output = indicator.next(x);
+	}
+	assert_eq!(output, expected(&finite));
+}
+
+macro_rules! check_all {
+	($inputs:expr, $($indicator:ident),* $(,)?) => {
+		$(check($indicator::default(), $inputs);)*
+	};
+}
+
+fuzz_target!(|data: &[u8]| {
+	let inputs: Vec<f64> = data
+		.chunks_exact(8)
+		.map(|bytes| f64::from_le_bytes(bytes.try_into().unwrap()))
+		.collect();
+
+	check_all!(
+		&inputs,
+		Aroon,
+		AverageTrueRange,
+		BollingerBandPctB,
+		BollingerBandWidth,
+		BollingerBands,
+		ChandeMomentumOscillator,
+		CoppockCurve,
+		CumulativeReturn,
+		DetrendedPriceOscillator,
+		DonchianChannel,
+		DoubleExponentialMovingAverage,
+		EfficiencyRatio,
+		ElderRay,
+		ExponentialMovingAverage,
+		FastStochastic,
+		HistoricalVolatility,
+		HullMovingAverage,
+		IchimokuCloud,
+		KaufmanAdaptiveMovingAverage,
+		KeltnerChannel,
+		Kurtosis,
+		LinearRegression,
+		LogReturn,
+		MaxDrawdown,
+		Maximum,
+		MeanAbsoluteDeviation,
+		Median,
+		MedianPrice,
+		Minimum,
+		MovingAverageConvergenceDivergence,
+		ParabolicSar,
+		PercentagePriceOscillator,
+		Percentile,
+		RateOfChange,
+		RelativeStrengthIndex,
+		RollingSum,
+		SharpeRatio,
+		SimpleMovingAverage,
+		Skewness,
+		SlowStochastic,
+		StandardDeviation,
+		StandardError,
+		StochasticOscillator,
+		StochasticRsi,
+		TripleExponentialAverage,
+		TripleExponentialAverageSignal,
+		TripleExponentialMovingAverage,
+		TimeSeriesForecast,
+		TrueRange,
+		TypicalPrice,
+		Variance,
+		VolumeRsi,
+		WeightedClose,
+		WeightedMovingAverage,
+		WilliamsPercentRange,
+		ZeroLagExponentialMovingAverage,
+		Zigzag,
+	);
+
+	let bars: Vec<DataItem> = inputs
+		.chunks_exact(5)
+		.filter_map(|values| {
+			DataItem::builder()
+				.open(values[0])
+				.high(values[1])
+				.low(values[2])
+				.close(values[3])
+				.volume(values[4])
+				.build()
+				.ok()
+		})
+		.collect();
+	let bars: Vec<&DataItem> = bars.iter().collect();
+
+	check_all!(
+		&bars,
+		AccumulationDistribution,
+		Aroon,
+		AverageTrueRange,
+		AverageTrueRangePercent,
+		BalanceOfPower,
+		BollingerBandPctB,
+		BollingerBandWidth,
+		BollingerBands,
+		ChaikinMoneyFlow,
+		ChaikinOscillator,
+		ChandeMomentumOscillator,
+		ChandelierExit,
+		ChoppinessIndex,
+		CommodityChannelIndex,
+		CoppockCurve,
+		CumulativeReturn,
+		DetrendedPriceOscillator,
+		DirectionalMovementIndex,
+		Divergence,
+		DonchianChannel,
+		DoubleExponentialMovingAverage,
+		EaseOfMovement,
+		EfficiencyRatio,
+		ElderRay,
+		ExponentialMovingAverage,
+		FastStochastic,
+		ForceIndex,
+		HistoricalVolatility,
+		HullMovingAverage,
+		IchimokuCloud,
+		KaufmanAdaptiveMovingAverage,
+		KeltnerChannel,
+		KlingerOscillator,
+		Kurtosis,
+		LinearRegression,
+		LogReturn,
+		MassIndex,
+		MaxDrawdown,
+		Maximum,
+		MeanAbsoluteDeviation,
+		Median,
+		MedianPrice,
+		Minimum,
+		MoneyFlowIndex,
+		MovingAverageConvergenceDivergence,
+		OnBalanceVolume,
+		ParabolicSar,
+		PercentagePriceOscillator,
+		Percentile,
+		PivotPoints,
+		PriceVolumeTrend,
+		RateOfChange,
+		RelativeStrengthIndex,
+		RollingSum,
+		SharpeRatio,
+		SimpleMovingAverage,
+		Skewness,
+		SlowStochastic,
+		StandardDeviation,
+		StandardError,
+		StochasticOscillator,
+		StochasticRsi,
+		ThresholdSignal,
+		TripleExponentialAverage,
+		TripleExponentialAverageSignal,
+		TripleExponentialMovingAverage,
+		TimeSeriesForecast,
+		TrueRange,
+		TypicalPrice,
+		UltimateOscillator,
+		Variance,
+		VolumeRsi,
+		VolumeWeightedAveragePrice,
+		VolumeWeightedAveragePriceBands,
+		VortexIndicator,
+		WeightedClose,
+		WeightedMovingAverage,
+		WilliamsPercentRange,
+		ZeroLagExponentialMovingAverage,
+		Zigzag,
+	);
+
+	let pairs: Vec<(f64, f64)> = inputs
+		.chunks_exact(2)
+		.map(|pair| (pair[0], pair[1]))
+		.collect();
+	check_all!(&pairs, Correlation, Covariance, Crossover);
+
+	// the long series is slow, it is only checked for a few input lengths, with a trailing byte
+	// which is not decoded otherwise
+	if !inputs.is_empty() && data.len() % 64 == 1 {
+		let long: Vec<f64> = inputs.iter().copied().cycle().take(LONG).collect();
+		check_f64(SimpleMovingAverage::default(), &long);
+		check_f64(Minimum::default(), &long);
+		check_f64(Maximum::default(), &long);
+	}
+
+	for period in [1, 3, 14] {
+		check_window(Minimum::new(period).unwrap(), period, &inputs, f64::min);
+		check_window(Maximum::new(period).unwrap(), period, &inputs, f64::max);
+	}
+});
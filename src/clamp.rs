@@ -0,0 +1,153 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Wraps an indicator to bound its output to the range `[min, max]`.
+///
+/// Useful when downstream code relies on the documented range of an oscillator, whatever
+/// happens during its warm-up. A NaN output stays NaN.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::RateOfChange;
+/// use ta::{Clamp, Next};
+///
+/// let roc = RateOfChange::new(1).unwrap();
+/// let mut clamp = Clamp::new(roc, -10.0, 10.0).unwrap();
+///
+/// assert_eq!(clamp.next(100.0), 0.0);
+/// assert_eq!(clamp.next(105.0), 5.0);
+/// assert_eq!(clamp.next(210.0), 10.0);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Clamp<I> {
+	indicator: I,
+	min: f64,
+	max: f64,
+}
+
+impl<I> Clamp<I> {
+	pub fn new(indicator: I, min: f64, max: f64) -> Result<Self> {
+		if min.is_nan() || max.is_nan() || min > max {
+			return Err(TaError::InvalidParameter);
+		}
+
+		Ok(Self {
+			indicator,
+			min,
+			max,
+		})
+	}
+
+	pub fn min(&self) -> f64 {
+		self.min
+	}
+
+	pub fn max(&self) -> f64 {
+		self.max
+	}
+
+	/// Returns the wrapped indicator.
+	pub fn into_inner(self) -> I {
+		self.indicator
+	}
+}
+
+impl<I: Period> Period for Clamp<I> {
+	fn period(&self) -> usize {
+		self.indicator.period()
+	}
+}
+
+impl<I: Ready> Ready for Clamp<I> {
+	fn is_ready(&self) -> bool {
+		self.indicator.is_ready()
+	}
+}
+
+impl<T, I: Next<T, Output = f64>> Next<T> for Clamp<I> {
+	type Output = f64;
+
+	fn next(&mut self, input: T) -> Self::Output {
+		self.indicator.next(input).clamp(self.min, self.max)
+	}
+}
+
+impl<I: Reset> Reset for Clamp<I> {
+	fn reset(&mut self) {
+		self.indicator.reset();
+	}
+}
+
+impl<I: fmt::Display> fmt::Display for Clamp<I> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "CLAMP({}, {}, {})", self.indicator, self.min, self.max)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::indicators::{RateOfChange, SimpleMovingAverage as Sma};
+	use crate::test_helper::*;
+
+	#[test]
+	fn test_new() {
+		let sma = Sma::new(1).unwrap();
+		assert!(Clamp::new(sma.clone(), 1.0, 0.0).is_err());
+		assert!(Clamp::new(sma.clone(), f64::NAN, 1.0).is_err());
+		assert!(Clamp::new(sma.clone(), 0.0, f64::NAN).is_err());
+		assert!(Clamp::new(sma.clone(), 1.0, 1.0).is_ok());
+		assert!(Clamp::new(sma, f64::NEG_INFINITY, 0.0).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let mut clamp = Clamp::new(Sma::new(2).unwrap(), 0.0, 100.0).unwrap();
+		assert_eq!(clamp.next(50.0), 50.0);
+		assert_eq!(clamp.next(250.0), 100.0);
+		assert_eq!(clamp.next(-300.0), 0.0);
+		assert_eq!(clamp.next(-20.0), 0.0);
+		assert_eq!(clamp.next(40.0), 10.0);
+	}
+
+	#[test]
+	fn test_next_nan() {
+		// ROC of a series starting at 0 is NaN
+		let mut clamp = Clamp::new(RateOfChange::new(1).unwrap(), 0.0, 100.0).unwrap();
+		assert!(clamp.next(0.0).is_nan());
+	}
+
+	#[test]
+	fn test_next_bar() {
+		let mut clamp = Clamp::new(Sma::new(1).unwrap(), 0.0, 10.0).unwrap();
+		assert_eq!(clamp.next(&Bar::new().close(5)), 5.0);
+		assert_eq!(clamp.next(&Bar::new().close(15)), 10.0);
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut clamp = Clamp::new(Sma::new(2).unwrap(), 0.0, 100.0).unwrap();
+		assert_eq!(clamp.next(50.0), 50.0);
+		assert_eq!(clamp.next(70.0), 60.0);
+		assert!(clamp.is_ready());
+
+		clamp.reset();
+		assert!(!clamp.is_ready());
+		assert_eq!(clamp.next(70.0), 70.0);
+		assert_eq!(clamp.into_inner().period(), 2);
+	}
+
+	#[test]
+	fn test_display() {
+		let clamp = Clamp::new(Sma::new(3).unwrap(), 0.0, 100.0).unwrap();
+		assert_eq!(format!("{}", clamp), "CLAMP(SMA(3), 0, 100)");
+		assert_eq!(clamp.min(), 0.0);
+		assert_eq!(clamp.max(), 100.0);
+	}
+}
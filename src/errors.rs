@@ -1,7 +1,6 @@
-use std::error::Error;
-use std::fmt::{Display, Formatter};
+use core::fmt::{Display, Formatter};
 
-pub type Result<T> = std::result::Result<T, TaError>;
+pub type Result<T> = core::result::Result<T, TaError>;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum TaError {
@@ -11,7 +10,7 @@ pub enum TaError {
 }
 
 impl Display for TaError {
-	fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+	fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
 		match *self {
 			TaError::InvalidParameter => write!(f, "invalid parameter"),
 			TaError::DataItemIncomplete => write!(f, "data item is incomplete"),
@@ -20,8 +19,9 @@ impl Display for TaError {
 	}
 }
 
-impl Error for TaError {
-	fn source(&self) -> Option<&(dyn Error + 'static)> {
+#[cfg(feature = "std")]
+impl std::error::Error for TaError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
 		match *self {
 			TaError::InvalidParameter => None,
 			TaError::DataItemIncomplete => None,
@@ -0,0 +1,210 @@
+//! Builds indicators from their names, e.g. from a configuration file or a user query.
+//!
+//! # Example
+//!
+//! ```
+//! use ta::factory;
+//!
+//! let mut ema = factory::build("EMA", &[3.0]).unwrap();
+//! assert_eq!(ema.next(2.0), 2.0);
+//! assert_eq!(ema.next(5.0), 3.5);
+//! assert_eq!(format!("{}", ema), "EMA(3)");
+//!
+//! ema.reset();
+//! assert_eq!(ema.next(1.0), 1.0);
+//!
+//! assert!(factory::build("EMA", &[]).is_err());
+//! assert!(factory::build("NOPE", &[]).is_err());
+//! ```
+use alloc::boxed::Box;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::*;
+use crate::{Next, Reset};
+
+/// An indicator taking and returning `f64` values, as built by [build](fn.build.html).
+///
+/// It is implemented for every indicator implementing `Next<f64, Output = f64>`, `Reset`
+/// and `Display`.
+pub trait IndicatorFn: fmt::Display {
+	fn next(&mut self, input: f64) -> f64;
+
+	fn reset(&mut self);
+}
+
+impl<I: Next<f64, Output = f64> + Reset + fmt::Display> IndicatorFn for I {
+	fn next(&mut self, input: f64) -> f64 {
+		Next::next(self, input)
+	}
+
+	fn reset(&mut self) {
+		Reset::reset(self)
+	}
+}
+
+/// Conversions of the parameters given to [build](fn.build.html).
+mod param {
+	use crate::errors::{Result, TaError};
+
+	/// A period or another integer parameter greater than 0.
+	pub fn period(param: f64) -> Result<usize> {
+		match count(param)? {
+			0 => Err(TaError::InvalidParameter),
+			period => Ok(period),
+		}
+	}
+
+	/// An integer parameter, which can be 0.
+	pub fn count(param: f64) -> Result<usize> {
+		let count = param as usize;
+		if count as f64 == param {
+			Ok(count)
+		} else {
+			Err(TaError::InvalidParameter)
+		}
+	}
+
+	pub fn value(param: f64) -> Result<f64> {
+		Ok(param)
+	}
+}
+
+macro_rules! register_indicators {
+	($($name:literal($($param:ident: $kind:ident),*) => $build:expr,)*) => {
+		/// Names of the indicators known to [build](fn.build.html).
+		pub const NAMES: &[&str] = &[$($name),*];
+
+		/// Builds the indicator with the given name, which is the prefix of its `Display`
+		/// output and is case insensitive, and parameters, in the order of its constructor.
+		///
+		/// Integer parameters are given as whole `f64` values. An error is returned for
+		/// unknown names, a wrong number of parameters or invalid parameters.
+		pub fn build(name: &str, params: &[f64]) -> Result<Box<dyn IndicatorFn>> {
+			$(
+				if name.eq_ignore_ascii_case($name) {
+					return match params {
+						&[$($param),*] => {
+							$(let $param = param::$kind($param)?;)*
+							Ok(Box::new($build?))
+						}
+						_ => Err(TaError::InvalidParameter),
+					};
+				}
+			)*
+			Err(TaError::InvalidParameter)
+		}
+	};
+}
+
+register_indicators! {
+	"ATR"(period: period) => AverageTrueRange::new(period),
+	"BB%B"(period: period, multiplier: value) => BollingerBandPctB::new(period, multiplier),
+	"BBW"(period: period, multiplier: value) => BollingerBandWidth::new(period, multiplier),
+	"CMO"(period: period) => ChandeMomentumOscillator::new(period),
+	"COPPOCK"(wma: period, roc1: period, roc2: period) => CoppockCurve::new(wma, roc1, roc2),
+	"CUM_RETURN"() => Ok::<_, TaError>(CumulativeReturn::new()),
+	"DEMA"(period: period) => DoubleExponentialMovingAverage::new(period),
+	"DPO"(period: period) => DetrendedPriceOscillator::new(period),
+	"EMA"(period: period) => ExponentialMovingAverage::new(period),
+	"ER"(period: period) => EfficiencyRatio::new(period),
+	"FAST_STOCH"(period: period) => FastStochastic::new(period),
+	"HMA"(period: period) => HullMovingAverage::new(period),
+	"HV"(period: period, days: period) => HistoricalVolatility::new(period, days),
+	"KAMA"(er: period, fast: period, slow: period) => KaufmanAdaptiveMovingAverage::new(er, fast, slow),
+	"KURT"(period: period) => Kurtosis::new(period),
+	"LOG_RETURN"() => Ok::<_, TaError>(LogReturn::new()),
+	"MAD"(period: period) => MeanAbsoluteDeviation::new(period),
+	"MAX"(period: period) => Maximum::new(period),
+	"MDD"(period: period) => MaxDrawdown::new(period),
+	"MEDIAN"(period: period) => Median::new(period),
+	"MIN"(period: period) => Minimum::new(period),
+	"PERCENTILE"(period: period, quantile: value) => Percentile::new(period, quantile),
+	"ROC"(period: period) => RateOfChange::new(period),
+	"RSI"(period: period) => RelativeStrengthIndex::new(period),
+	"SAR"(initial_af: value, step: value, max_af: value) => ParabolicSar::new(initial_af, step, max_af),
+	"SD"(period: period) => StandardDeviation::new(period),
+	"SHARPE"(period: period, risk_free_rate: value, days: period) => SharpeRatio::new(period, risk_free_rate, days),
+	"SKEW"(period: period) => Skewness::new(period),
+	"SLOW_STOCH"(stochastic: period, ema: period) => SlowStochastic::new(stochastic, ema),
+	"SMA"(period: period) => SimpleMovingAverage::new(period),
+	"STDERR"(period: period) => StandardError::new(period),
+	"SUM"(period: period) => RollingSum::new(period),
+	"TEMA"(period: period) => TripleExponentialMovingAverage::new(period),
+	"TRIX"(period: period) => TripleExponentialAverage::new(period),
+	"TRUE_RANGE"() => Ok::<_, TaError>(TrueRange::new()),
+	"TSF"(period: period) => TimeSeriesForecast::new(period),
+	"VAR"(period: period, ddof: count) => Variance::new(period, ddof),
+	"VRSI"(period: period) => VolumeRsi::new(period),
+	"WILLR"(period: period) => WilliamsPercentRange::new(period),
+	"WMA"(period: period) => WeightedMovingAverage::new(period),
+	"ZLEMA"(period: period) => ZeroLagExponentialMovingAverage::new(period),
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use alloc::format;
+
+	#[test]
+	fn test_build() {
+		let mut sma = build("SMA", &[2.0]).unwrap();
+		let mut expected = SimpleMovingAverage::new(2).unwrap();
+		for x in &[4.0, 6.0, 2.0, 9.0] {
+			assert_eq!(sma.next(*x), Next::next(&mut expected, *x));
+		}
+
+		let mut var = build("VAR", &[3.0, 0.0]).unwrap();
+		assert_eq!(var.next(1.0), 0.0);
+		assert_eq!(var.next(3.0), 1.0);
+	}
+
+	#[test]
+	fn test_build_case_insensitive() {
+		assert_eq!(format!("{}", build("ema", &[9.0]).unwrap()), "EMA(9)");
+		assert_eq!(
+			format!("{}", build("Cum_Return", &[]).unwrap()),
+			"CUM_RETURN"
+		);
+	}
+
+	#[test]
+	fn test_build_invalid() {
+		assert!(build("XYZ", &[14.0]).is_err());
+		assert!(build("RSI", &[]).is_err());
+		assert!(build("RSI", &[14.0, 2.0]).is_err());
+		assert!(build("RSI", &[0.0]).is_err());
+		assert!(build("RSI", &[-3.0]).is_err());
+		assert!(build("RSI", &[2.5]).is_err());
+		assert!(build("RSI", &[f64::NAN]).is_err());
+		assert!(build("PERCENTILE", &[5.0, 1.5]).is_err());
+		assert!(build("LOG_RETURN", &[1.0]).is_err());
+	}
+
+	#[test]
+	fn test_names() {
+		for name in NAMES {
+			let params: &[f64] = match *name {
+				"CUM_RETURN" | "LOG_RETURN" | "TRUE_RANGE" => &[],
+				"BB%B" | "BBW" | "HV" | "SLOW_STOCH" => &[5.0, 3.0],
+				"PERCENTILE" => &[5.0, 0.5],
+				"VAR" => &[5.0, 0.0],
+				"COPPOCK" | "KAMA" => &[3.0, 4.0, 5.0],
+				"SAR" => &[0.02, 0.02, 0.2],
+				"SHARPE" => &[5.0, 0.0, 252.0],
+				_ => &[5.0],
+			};
+			let indicator = build(name, params).expect(name);
+			assert!(format!("{}", indicator).starts_with(name));
+		}
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut rsi = build("RSI", &[3.0]).unwrap();
+		let first = rsi.next(10.0);
+		rsi.next(11.0);
+		rsi.reset();
+		assert_eq!(rsi.next(10.0), first);
+	}
+}
@@ -0,0 +1,134 @@
+use core::fmt;
+
+use crate::{Close, High, Low, Next, Period, Ready, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+define_indicator! {
+	/// Accumulation/Distribution Line (A/D).
+	///
+	/// A cumulative volume indicator which weights the volume of each period by the location
+	/// of the close within the range of the period.
+	///
+	/// # Formula
+	///
+	/// CLV = ((C - L) - (H - C)) / (H - L)
+	///
+	/// A/D<sub>t</sub> = A/D<sub>t-1</sub> + CLV × V
+	///
+	/// Where:
+	///
+	/// * _CLV_ - close location value, 0 if the high and the low are equal
+	/// * _H_, _L_, _C_ - high, low and close prices
+	/// * _V_ - volume
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ta::indicators::AccumulationDistribution;
+	/// use ta::{DataItem, Next};
+	///
+	/// let mut ad = AccumulationDistribution::new();
+	///
+	/// let di = DataItem::builder()
+	///             .high(11.0)
+	///             .low(9.0)
+	///             .close(10.5)
+	///             .open(10.0)
+	///             .volume(200.0)
+	///             .build().unwrap();
+	///
+	/// assert_eq!(ad.next(&di), 100.0);
+	/// assert_eq!(ad.next(&di), 200.0);
+	/// ```
+	///
+	/// # Links
+	///
+	/// * [Accumulation/distribution index, Wikipedia](https://en.wikipedia.org/wiki/Accumulation/distribution_index)
+	#[doc(alias = "AD")]
+	#[doc(alias = "ADL")]
+	pub struct AccumulationDistribution {
+		ad: f64 = 0.0,
+	}
+	display(indicator) = ("AD");
+	default = Self::new();
+}
+
+impl AccumulationDistribution {
+	pub fn new() -> Self {
+		Self { ad: 0.0 }
+	}
+}
+
+impl<T: High + Low + Close + Volume> Next<&T> for AccumulationDistribution {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		let (high, low, close) = (input.high(), input.low(), input.close());
+		let clv = if high == low {
+			0.0
+		} else {
+			((close - low) - (high - close)) / (high - low)
+		};
+		self.ad += clv * input.volume();
+		self.ad
+	}
+}
+
+impl Period for AccumulationDistribution {
+	fn period(&self) -> usize {
+		1
+	}
+}
+
+impl Ready for AccumulationDistribution {
+	fn is_ready(&self) -> bool {
+		true
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	#[test]
+	fn test_next_bar() {
+		let mut ad = AccumulationDistribution::new();
+
+		let bar1 = Bar::new().high(10).low(8).close(9).volume(100.0);
+		let bar2 = Bar::new().high(11).low(9).close(10.5).volume(200.0);
+		let bar3 = Bar::new().high(12).low(8).close(8).volume(300.0);
+		let bar4 = Bar::new().high(10).low(10).close(10).volume(100.0);
+
+		assert_eq!(ad.next(&bar1), 0.0);
+		assert_eq!(ad.next(&bar2), 100.0);
+		assert_eq!(ad.next(&bar3), -200.0);
+		assert_eq!(ad.next(&bar4), -200.0);
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut ad = AccumulationDistribution::new();
+
+		let bar = Bar::new().high(11).low(9).close(10.5).volume(200.0);
+
+		assert_eq!(ad.next(&bar), 100.0);
+		assert_eq!(ad.next(&bar), 200.0);
+
+		ad.reset();
+
+		assert_eq!(ad.next(&bar), 100.0);
+	}
+
+	#[test]
+	fn test_default() {
+		AccumulationDistribution::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let ad = AccumulationDistribution::new();
+		assert_eq!(format!("{}", ad), "AD");
+	}
+}
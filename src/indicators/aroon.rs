@@ -0,0 +1,262 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Aroon indicator.
+///
+/// Aroon Up and Aroon Down measure how many periods have passed since the highest high and
+/// the lowest low within the last _n_ periods. The Aroon Oscillator is their difference.
+///
+/// # Formula
+///
+/// Aroon Up = (n - periods since the highest high) / n × 100
+///
+/// Aroon Down = (n - periods since the lowest low) / n × 100
+///
+/// Aroon Oscillator = Aroon Up - Aroon Down
+///
+/// The highest high and the lowest low are searched in the last _n + 1_ periods (the current
+/// period included), so Aroon Up and Aroon Down range from 0 to 100. If the extreme value
+/// occurs more than once, the most recent one is used.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 25.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Aroon;
+/// use ta::Next;
+///
+/// let mut aroon = Aroon::new(2).unwrap();
+///
+/// let out = aroon.next(10.0);
+/// assert_eq!((out.up, out.down, out.oscillator), (100.0, 100.0, 0.0));
+///
+/// let out = aroon.next(8.0);
+/// assert_eq!((out.up, out.down, out.oscillator), (50.0, 100.0, -50.0));
+/// ```
+///
+/// # Links
+///
+/// * [Aroon indicator, stockcharts](https://school.stockcharts.com/doku.php?id=technical_indicators:aroon)
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Aroon {
+	period: usize,
+	index: usize,
+	count: usize,
+	highs: Box<[f64]>,
+	lows: Box<[f64]>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AroonOutput {
+	pub up: f64,
+	pub down: f64,
+	pub oscillator: f64,
+}
+
+impl From<AroonOutput> for (f64, f64, f64) {
+	fn from(ao: AroonOutput) -> Self {
+		(ao.up, ao.down, ao.oscillator)
+	}
+}
+
+impl Aroon {
+	pub fn new(period: usize) -> Result<Self> {
+		match period {
+			0 => Err(TaError::InvalidParameter),
+			_ => Ok(Self {
+				period,
+				index: 0,
+				count: 0,
+				highs: vec![0.0; period + 1].into_boxed_slice(),
+				lows: vec![0.0; period + 1].into_boxed_slice(),
+			}),
+		}
+	}
+
+	fn calc(&mut self, high: f64, low: f64) -> AroonOutput {
+		let len = self.highs.len();
+		self.highs[self.index] = high;
+		self.lows[self.index] = low;
+		if self.count < len {
+			self.count += 1;
+		}
+
+		// Walk back from the current period, so on ties the most recent extreme wins
+		let (mut high_age, mut low_age) = (0, 0);
+		let (mut highest, mut lowest) = (high, low);
+		for age in 1..self.count {
+			let i = (self.index + len - age) % len;
+			if self.highs[i] > highest {
+				highest = self.highs[i];
+				high_age = age;
+			}
+			if self.lows[i] < lowest {
+				lowest = self.lows[i];
+				low_age = age;
+			}
+		}
+
+		self.index = if self.index + 1 < len {
+			self.index + 1
+		} else {
+			0
+		};
+
+		let period = self.period as f64;
+		let up = (period - high_age as f64) / period * 100.0;
+		let down = (period - low_age as f64) / period * 100.0;
+
+		AroonOutput {
+			up,
+			down,
+			oscillator: up - down,
+		}
+	}
+}
+
+impl Period for Aroon {
+	fn period(&self) -> usize {
+		self.period
+	}
+}
+
+impl Ready for Aroon {
+	fn is_ready(&self) -> bool {
+		self.count == self.highs.len()
+	}
+}
+
+impl Next<f64> for Aroon {
+	type Output = AroonOutput;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		self.calc(input, input)
+	}
+}
+
+impl<T: High + Low> Next<&T> for Aroon {
+	type Output = AroonOutput;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.calc(input.high(), input.low())
+	}
+}
+
+impl Reset for Aroon {
+	fn reset(&mut self) {
+		self.index = 0;
+		self.count = 0;
+		for i in 0..self.highs.len() {
+			self.highs[i] = 0.0;
+			self.lows[i] = 0.0;
+		}
+	}
+}
+
+impl Default for Aroon {
+	fn default() -> Self {
+		Self::new(25).unwrap()
+	}
+}
+
+impl fmt::Display for Aroon {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "AROON({})", self.period)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	test_indicator!(Aroon);
+
+	fn round(nums: (f64, f64, f64)) -> (f64, f64, f64) {
+		let n0 = (nums.0 * 100.0).round() / 100.0;
+		let n1 = (nums.1 * 100.0).round() / 100.0;
+		let n2 = (nums.2 * 100.0).round() / 100.0;
+		(n0, n1, n2)
+	}
+
+	#[test]
+	fn test_new() {
+		assert!(Aroon::new(0).is_err());
+		assert!(Aroon::new(1).is_ok());
+	}
+
+	#[test]
+	fn test_next_with_bars() {
+		let test_data = vec![
+			// high, low, (up, down, oscillator)
+			(10.0, 5.0, (100.0, 100.0, 0.0)),
+			(12.0, 6.0, (100.0, 66.67, 33.33)),
+			(11.0, 4.0, (66.67, 100.0, -33.33)),
+			(9.0, 7.0, (33.33, 66.67, -33.33)),
+			(8.0, 8.0, (0.0, 33.33, -33.33)),
+		];
+
+		let mut aroon = Aroon::new(3).unwrap();
+
+		for (high, low, expected) in test_data {
+			let bar = Bar::new().high(high).low(low);
+			assert_eq!(round(aroon.next(&bar).into()), expected);
+		}
+	}
+
+	#[test]
+	fn test_next_ties() {
+		let mut aroon = Aroon::new(4).unwrap();
+		aroon.next(5.0);
+		aroon.next(3.0);
+		let out = aroon.next(5.0);
+		assert_eq!(out.up, 100.0);
+		assert_eq!(out.down, 75.0);
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut aroon = Aroon::new(3).unwrap();
+		for _ in 0..3 {
+			aroon.next(1.0);
+			assert!(!aroon.is_ready());
+		}
+		aroon.next(1.0);
+		assert!(aroon.is_ready());
+
+		aroon.reset();
+		assert!(!aroon.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut aroon = Aroon::new(2).unwrap();
+		aroon.next(10.0);
+		aroon.next(8.0);
+
+		aroon.reset();
+		let out = aroon.next(8.0);
+		assert_eq!((out.up, out.down), (100.0, 100.0));
+	}
+
+	#[test]
+	fn test_default() {
+		Aroon::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let indicator = Aroon::new(14).unwrap();
+		assert_eq!(format!("{}", indicator), "AROON(14)");
+	}
+}
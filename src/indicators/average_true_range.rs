@@ -1,8 +1,8 @@
-use std::fmt;
+use core::fmt;
 
 use crate::errors::Result;
 use crate::indicators::{ExponentialMovingAverage, TrueRange};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -58,7 +58,7 @@ use serde::{Deserialize, Serialize};
 /// }
 #[doc(alias = "ATR")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AverageTrueRange {
 	true_range: TrueRange,
 	ema: ExponentialMovingAverage,
@@ -79,6 +79,12 @@ impl Period for AverageTrueRange {
 	}
 }
 
+impl Ready for AverageTrueRange {
+	fn is_ready(&self) -> bool {
+		self.ema.is_ready()
+	}
+}
+
 impl Next<f64> for AverageTrueRange {
 	type Output = f64;
 
@@ -0,0 +1,181 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::AverageTrueRange;
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Average true range percent (ATRP).
+///
+/// The [average true range](struct.AverageTrueRange.html) expressed as a percentage of the
+/// close price. Normalizing by the price makes the volatility of securities with different
+/// price levels comparable.
+///
+/// # Formula
+///
+/// ATRP = ATR(n) / C × 100
+///
+/// Where:
+///
+/// * _ATR(n)_ - [average true range](struct.AverageTrueRange.html) of period _n_
+/// * _C_ - close price of the current period
+///
+/// If the close price is 0, `f64::NAN` is returned.
+///
+/// # Parameters
+///
+/// * _period_ - smoothing period of the ATR (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::AverageTrueRangePercent;
+/// use ta::{DataItem, Next};
+///
+/// let mut atrp = AverageTrueRangePercent::new(3).unwrap();
+///
+/// let di = DataItem::builder()
+///             .high(11.0)
+///             .low(9.0)
+///             .close(10.0)
+///             .open(10.0)
+///             .volume(1000.0)
+///             .build().unwrap();
+///
+/// assert_eq!(atrp.next(&di), 20.0);
+/// ```
+///
+/// # Links
+///
+/// * [Average true range, Wikipedia](https://en.wikipedia.org/wiki/Average_true_range)
+#[doc(alias = "ATRP")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct AverageTrueRangePercent {
+	atr: AverageTrueRange,
+}
+
+impl AverageTrueRangePercent {
+	pub fn new(period: usize) -> Result<Self> {
+		Ok(Self {
+			atr: AverageTrueRange::new(period)?,
+		})
+	}
+}
+
+impl Period for AverageTrueRangePercent {
+	fn period(&self) -> usize {
+		self.atr.period()
+	}
+}
+
+impl Ready for AverageTrueRangePercent {
+	fn is_ready(&self) -> bool {
+		self.atr.is_ready()
+	}
+}
+
+impl<T: High + Low + Close> Next<&T> for AverageTrueRangePercent {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		let atr = self.atr.next(input);
+		let close = input.close();
+
+		if close == 0.0 {
+			return f64::NAN;
+		}
+
+		atr / close * 100.0
+	}
+}
+
+impl Reset for AverageTrueRangePercent {
+	fn reset(&mut self) {
+		self.atr.reset();
+	}
+}
+
+impl Default for AverageTrueRangePercent {
+	fn default() -> Self {
+		Self::new(14).unwrap()
+	}
+}
+
+impl fmt::Display for AverageTrueRangePercent {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "ATRP({})", self.atr.period())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	#[test]
+	fn test_new() {
+		assert!(AverageTrueRangePercent::new(0).is_err());
+		assert!(AverageTrueRangePercent::new(1).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let mut atrp = AverageTrueRangePercent::new(3).unwrap();
+
+		let bar1 = Bar::new().high(10).low(7.5).close(9);
+		let bar2 = Bar::new().high(11).low(9).close(9.5);
+		let bar3 = Bar::new().high(9).low(5).close(8);
+
+		assert_eq!(round(atrp.next(&bar1)), 27.778);
+		assert_eq!(round(atrp.next(&bar2)), 23.684);
+		assert_eq!(round(atrp.next(&bar3)), 42.188);
+	}
+
+	#[test]
+	fn test_next_zero_close() {
+		let mut atrp = AverageTrueRangePercent::new(3).unwrap();
+		let bar = Bar::new().high(1).low(0).close(0);
+		assert!(atrp.next(&bar).is_nan());
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut atrp = AverageTrueRangePercent::new(2).unwrap();
+		let bar = Bar::new().high(10).low(8).close(9);
+		atrp.next(&bar);
+		assert!(!atrp.is_ready());
+		atrp.next(&bar);
+		assert!(atrp.is_ready());
+
+		atrp.reset();
+		assert!(!atrp.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut atrp = AverageTrueRangePercent::new(9).unwrap();
+
+		let bar1 = Bar::new().high(10).low(7.5).close(9);
+		let bar2 = Bar::new().high(11).low(9).close(9.5);
+
+		atrp.next(&bar1);
+		atrp.next(&bar2);
+
+		atrp.reset();
+		let bar3 = Bar::new().high(60).low(15).close(50);
+		assert_eq!(atrp.next(&bar3), 90.0);
+	}
+
+	#[test]
+	fn test_default() {
+		AverageTrueRangePercent::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let indicator = AverageTrueRangePercent::new(14).unwrap();
+		assert_eq!(format!("{}", indicator), "ATRP(14)");
+	}
+}
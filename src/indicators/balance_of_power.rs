@@ -0,0 +1,184 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Close, High, Low, Next, Open, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Balance of Power (BOP).
+///
+/// Measures the strength of the buyers against the sellers by comparing the change of the
+/// price within a period to its range. It ranges from -1 to 1.
+///
+/// # Formula
+///
+/// BOP = EMA((C - O) / (H - L), n)
+///
+/// Where:
+///
+/// * _O_, _H_, _L_, _C_ - open, high, low and close prices
+///
+/// If the high and the low are equal, the raw value is 0. With a period of 1 the EMA is an
+/// identity and the raw BOP is returned.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods of the smoothing EMA (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::BalanceOfPower;
+/// use ta::{DataItem, Next};
+///
+/// let mut bop = BalanceOfPower::new(1).unwrap();
+///
+/// let di = DataItem::builder()
+///             .open(9.0)
+///             .high(10.0)
+///             .low(8.0)
+///             .close(9.5)
+///             .volume(100.0)
+///             .build().unwrap();
+///
+/// assert_eq!(bop.next(&di), 0.25);
+/// ```
+///
+/// # Links
+///
+/// * [Balance of Power, tradingview](https://www.tradingview.com/support/solutions/43000589100-balance-of-power-bop/)
+#[doc(alias = "BOP")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalanceOfPower {
+	ema: Ema,
+}
+
+impl BalanceOfPower {
+	pub fn new(period: usize) -> Result<Self> {
+		Ok(Self {
+			ema: Ema::new(period)?,
+		})
+	}
+}
+
+impl Period for BalanceOfPower {
+	fn period(&self) -> usize {
+		self.ema.period()
+	}
+}
+
+impl Ready for BalanceOfPower {
+	fn is_ready(&self) -> bool {
+		self.ema.is_ready()
+	}
+}
+
+impl<T: Open + High + Low + Close> Next<&T> for BalanceOfPower {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		let range = input.high() - input.low();
+		let bop = if range == 0.0 {
+			0.0
+		} else {
+			(input.close() - input.open()) / range
+		};
+
+		self.ema.next(bop)
+	}
+}
+
+impl Reset for BalanceOfPower {
+	fn reset(&mut self) {
+		self.ema.reset();
+	}
+}
+
+impl Default for BalanceOfPower {
+	fn default() -> Self {
+		Self::new(14).unwrap()
+	}
+}
+
+impl fmt::Display for BalanceOfPower {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self.ema.period() {
+			1 => write!(f, "BOP"),
+			period => write!(f, "BOP({})", period),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	#[test]
+	fn test_new() {
+		assert!(BalanceOfPower::new(0).is_err());
+		assert!(BalanceOfPower::new(1).is_ok());
+	}
+
+	#[test]
+	fn test_next_bar() {
+		let test_data = vec![
+			// open, high, low, close, raw, smoothed
+			(9.0, 10.0, 8.0, 9.5, 0.25, 0.25),
+			(10.0, 12.0, 10.0, 10.5, 0.25, 0.25),
+			(11.0, 11.0, 11.0, 11.0, 0.0, 0.083),
+			(12.0, 13.0, 9.0, 10.0, -0.5, -0.306),
+		];
+
+		let mut raw = BalanceOfPower::new(1).unwrap();
+		let mut smoothed = BalanceOfPower::new(2).unwrap();
+
+		for (open, high, low, close, expected_raw, expected_smoothed) in test_data {
+			let bar = Bar::new().open(open).high(high).low(low).close(close);
+			assert_eq!(raw.next(&bar), expected_raw);
+			assert_eq!(round(smoothed.next(&bar)), expected_smoothed);
+		}
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut bop = BalanceOfPower::new(2).unwrap();
+		let bar = Bar::new().open(4).high(5).low(4).close(5);
+		bop.next(&bar);
+		assert!(!bop.is_ready());
+		bop.next(&bar);
+		assert!(bop.is_ready());
+
+		bop.reset();
+		assert!(!bop.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut bop = BalanceOfPower::new(2).unwrap();
+		let bar1 = Bar::new().open(9).high(10).low(8).close(9.5);
+		let bar2 = Bar::new().open(12).high(13).low(9).close(10);
+
+		assert_eq!(bop.next(&bar1), 0.25);
+		assert_eq!(round(bop.next(&bar2)), -0.25);
+
+		bop.reset();
+		assert_eq!(bop.next(&bar2), -0.5);
+	}
+
+	#[test]
+	fn test_default() {
+		BalanceOfPower::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let indicator = BalanceOfPower::new(14).unwrap();
+		assert_eq!(format!("{}", indicator), "BOP(14)");
+
+		let indicator = BalanceOfPower::new(1).unwrap();
+		assert_eq!(format!("{}", indicator), "BOP");
+	}
+}
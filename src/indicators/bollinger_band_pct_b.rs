@@ -0,0 +1,202 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::BollingerBands;
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Bollinger Band %B (BB%B).
+///
+/// Shows where the price sits within the [Bollinger Bands](struct.BollingerBands.html): 1 at
+/// the upper band, 0.5 at the middle band and 0 at the lower band. Values above 1 or below 0
+/// mean that the price is outside of the bands.
+///
+/// # Formula
+///
+/// %B = (P - BB<sub>lower</sub>) / (BB<sub>upper</sub> - BB<sub>lower</sub>)
+///
+/// Where:
+///
+/// * _P_ - current price
+/// * _BB<sub>upper</sub>_, _BB<sub>lower</sub>_ - upper and lower Bollinger Bands
+///
+/// If the bands are equal, i.e. the prices of the window are all the same, 0.5 is returned.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+/// * _multiplier_ - number of standard deviations between the bands and the middle band
+///   (greater than 0). Default is 2.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::BollingerBandPctB;
+/// use ta::Next;
+///
+/// let mut pct_b = BollingerBandPctB::new(3, 2.0).unwrap();
+/// assert_eq!(pct_b.next(2.0), 0.5);
+/// assert_eq!(pct_b.next(5.0), 0.75);
+/// ```
+///
+/// # Links
+///
+/// * [Bollinger Bands %B, stockcharts](https://chartschool.stockcharts.com/table-of-contents/technical-indicators-and-overlays/technical-indicators/bollinger-band-percent-b)
+#[doc(alias = "BB%B")]
+#[doc(alias = "%B")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BollingerBandPctB {
+	bb: BollingerBands,
+}
+
+impl BollingerBandPctB {
+	pub fn new(period: usize, multiplier: f64) -> Result<Self> {
+		if multiplier.is_nan() || multiplier <= 0.0 {
+			return Err(TaError::InvalidParameter);
+		}
+
+		Ok(Self {
+			bb: BollingerBands::new(period, multiplier)?,
+		})
+	}
+
+	pub fn multiplier(&self) -> f64 {
+		self.bb.multiplier()
+	}
+}
+
+impl Period for BollingerBandPctB {
+	fn period(&self) -> usize {
+		self.bb.period()
+	}
+}
+
+impl Ready for BollingerBandPctB {
+	fn is_ready(&self) -> bool {
+		self.bb.is_ready()
+	}
+}
+
+impl Next<f64> for BollingerBandPctB {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		let bands = self.bb.next(input);
+		let width = bands.upper - bands.lower;
+
+		if width == 0.0 {
+			return 0.5;
+		}
+
+		(input - bands.lower) / width
+	}
+}
+
+impl<T: Close> Next<&T> for BollingerBandPctB {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl Reset for BollingerBandPctB {
+	fn reset(&mut self) {
+		self.bb.reset();
+	}
+}
+
+impl Default for BollingerBandPctB {
+	fn default() -> Self {
+		Self::new(20, 2.0).unwrap()
+	}
+}
+
+impl fmt::Display for BollingerBandPctB {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "BB%B({}, {})", self.bb.period(), self.bb.multiplier())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	test_indicator!(BollingerBandPctB);
+
+	#[test]
+	fn test_new() {
+		assert!(BollingerBandPctB::new(0, 2.0).is_err());
+		assert!(BollingerBandPctB::new(3, 0.0).is_err());
+		assert!(BollingerBandPctB::new(3, -1.0).is_err());
+		assert!(BollingerBandPctB::new(3, f64::NAN).is_err());
+		assert!(BollingerBandPctB::new(1, 2.0).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let mut pct_b = BollingerBandPctB::new(3, 2.0).unwrap();
+		assert_eq!(pct_b.next(2.0), 0.5);
+		assert_eq!(pct_b.next(5.0), 0.75);
+		assert_eq!(round(pct_b.next(1.0)), 0.255);
+		assert_eq!(round(pct_b.next(6.0)), 0.731);
+		assert_eq!(round(pct_b.next(6.0)), 0.677);
+		assert_eq!(round(pct_b.next(3.0)), 0.146);
+	}
+
+	#[test]
+	fn test_next_outside_bands() {
+		// with a narrow band, the prices are not clamped to [0, 1]
+		let mut pct_b = BollingerBandPctB::new(2, 0.5).unwrap();
+		pct_b.next(1.0);
+		assert_eq!(pct_b.next(3.0), 1.5);
+		assert_eq!(pct_b.next(1.0), -0.5);
+	}
+
+	#[test]
+	fn test_next_flat() {
+		let mut pct_b = BollingerBandPctB::new(3, 2.0).unwrap();
+		for _ in 0..5 {
+			assert_eq!(pct_b.next(4.0), 0.5);
+		}
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut pct_b = BollingerBandPctB::new(2, 2.0).unwrap();
+		pct_b.next(1.0);
+		assert!(!pct_b.is_ready());
+		pct_b.next(2.0);
+		assert!(pct_b.is_ready());
+
+		pct_b.reset();
+		assert!(!pct_b.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut pct_b = BollingerBandPctB::new(3, 2.0).unwrap();
+		pct_b.next(2.0);
+		assert_eq!(pct_b.next(5.0), 0.75);
+
+		pct_b.reset();
+		assert_eq!(pct_b.next(5.0), 0.5);
+		assert_eq!(pct_b.next(2.0), 0.25);
+	}
+
+	#[test]
+	fn test_default() {
+		let pct_b = BollingerBandPctB::default();
+		assert_eq!(pct_b.period(), 20);
+		assert_eq!(pct_b.multiplier(), 2.0);
+	}
+
+	#[test]
+	fn test_display() {
+		let pct_b = BollingerBandPctB::new(20, 2.0).unwrap();
+		assert_eq!(format!("{}", pct_b), "BB%B(20, 2)");
+	}
+}
@@ -0,0 +1,190 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::BollingerBands;
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Bollinger Band Width (BBW).
+///
+/// The distance between the [Bollinger Bands](struct.BollingerBands.html) as a percentage of
+/// the middle band, a direct measure of volatility. A low width that starts expanding, the
+/// "Bollinger Squeeze", often precedes a strong move.
+///
+/// # Formula
+///
+/// BBW = (BB<sub>upper</sub> - BB<sub>lower</sub>) / BB<sub>middle</sub> × 100
+///
+/// Where:
+///
+/// * _BB<sub>upper</sub>_, _BB<sub>middle</sub>_, _BB<sub>lower</sub>_ - upper, middle and
+///   lower Bollinger Bands
+///
+/// If the middle band is 0, 0 is returned.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+/// * _multiplier_ - number of standard deviations between the bands and the middle band
+///   (greater than 0). Default is 2.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::BollingerBandWidth;
+/// use ta::Next;
+///
+/// let mut bbw = BollingerBandWidth::new(3, 2.0).unwrap();
+/// assert_eq!(bbw.next(2.0), 0.0);
+/// assert_eq!(bbw.next(5.0), 6.0 / 3.5 * 100.0);
+/// ```
+///
+/// # Links
+///
+/// * [Bollinger BandWidth, stockcharts](https://chartschool.stockcharts.com/table-of-contents/technical-indicators-and-overlays/technical-indicators/bollinger-bandwidth)
+#[doc(alias = "BBW")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BollingerBandWidth {
+	bb: BollingerBands,
+}
+
+impl BollingerBandWidth {
+	pub fn new(period: usize, multiplier: f64) -> Result<Self> {
+		if multiplier.is_nan() || multiplier <= 0.0 {
+			return Err(TaError::InvalidParameter);
+		}
+
+		Ok(Self {
+			bb: BollingerBands::new(period, multiplier)?,
+		})
+	}
+
+	pub fn multiplier(&self) -> f64 {
+		self.bb.multiplier()
+	}
+}
+
+impl Period for BollingerBandWidth {
+	fn period(&self) -> usize {
+		self.bb.period()
+	}
+}
+
+impl Ready for BollingerBandWidth {
+	fn is_ready(&self) -> bool {
+		self.bb.is_ready()
+	}
+}
+
+impl Next<f64> for BollingerBandWidth {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		let bands = self.bb.next(input);
+
+		if bands.average == 0.0 {
+			return 0.0;
+		}
+
+		(bands.upper - bands.lower) / bands.average * 100.0
+	}
+}
+
+impl<T: Close> Next<&T> for BollingerBandWidth {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl Reset for BollingerBandWidth {
+	fn reset(&mut self) {
+		self.bb.reset();
+	}
+}
+
+impl Default for BollingerBandWidth {
+	fn default() -> Self {
+		Self::new(20, 2.0).unwrap()
+	}
+}
+
+impl fmt::Display for BollingerBandWidth {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "BBW({}, {})", self.bb.period(), self.bb.multiplier())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	test_indicator!(BollingerBandWidth);
+
+	#[test]
+	fn test_new() {
+		assert!(BollingerBandWidth::new(0, 2.0).is_err());
+		assert!(BollingerBandWidth::new(3, 0.0).is_err());
+		assert!(BollingerBandWidth::new(3, -1.0).is_err());
+		assert!(BollingerBandWidth::new(3, f64::NAN).is_err());
+		assert!(BollingerBandWidth::new(1, 2.0).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let mut bbw = BollingerBandWidth::new(3, 2.0).unwrap();
+		assert_eq!(bbw.next(2.0), 0.0);
+		assert_eq!(round(bbw.next(5.0)), 171.429);
+		assert_eq!(round(bbw.next(1.0)), 254.951);
+		assert_eq!(round(bbw.next(6.0)), 216.025);
+		assert_eq!(round(bbw.next(6.0)), 217.571);
+		assert_eq!(round(bbw.next(3.0)), 113.137);
+	}
+
+	#[test]
+	fn test_next_zero_middle() {
+		let mut bbw = BollingerBandWidth::new(2, 2.0).unwrap();
+		bbw.next(-1.0);
+		assert_eq!(bbw.next(1.0), 0.0);
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut bbw = BollingerBandWidth::new(2, 2.0).unwrap();
+		bbw.next(1.0);
+		assert!(!bbw.is_ready());
+		bbw.next(2.0);
+		assert!(bbw.is_ready());
+
+		bbw.reset();
+		assert!(!bbw.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut bbw = BollingerBandWidth::new(3, 2.0).unwrap();
+		bbw.next(2.0);
+		assert_eq!(round(bbw.next(5.0)), 171.429);
+
+		bbw.reset();
+		assert_eq!(bbw.next(5.0), 0.0);
+		assert_eq!(round(bbw.next(2.0)), 171.429);
+	}
+
+	#[test]
+	fn test_default() {
+		let bbw = BollingerBandWidth::default();
+		assert_eq!(bbw.period(), 20);
+		assert_eq!(bbw.multiplier(), 2.0);
+	}
+
+	#[test]
+	fn test_display() {
+		let bbw = BollingerBandWidth::new(20, 2.0).unwrap();
+		assert_eq!(format!("{}", bbw), "BBW(20, 2)");
+	}
+}
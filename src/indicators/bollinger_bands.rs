@@ -1,8 +1,8 @@
-use std::fmt;
+use core::fmt;
 
 use crate::errors::Result;
 use crate::indicators::StandardDeviation as Sd;
-use crate::{Close, Next, Period, Reset};
+use crate::{Close, Next, Period, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -47,7 +47,7 @@ use serde::{Deserialize, Serialize};
 /// * [Bollinger Bands, Wikipedia](https://en.wikipedia.org/wiki/Bollinger_Bands)
 #[doc(alias = "BB")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct BollingerBands {
 	period: usize,
 	multiplier: f64,
@@ -56,11 +56,20 @@ pub struct BollingerBands {
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct BollingerBandsOutput {
+	/// Middle band.
 	pub average: f64,
 	pub upper: f64,
 	pub lower: f64,
 }
 
+/// Converts the bands into an `(average, upper, lower)` tuple, in the order of the fields,
+/// e.g. for code written against a tuple output.
+impl From<BollingerBandsOutput> for (f64, f64, f64) {
+	fn from(output: BollingerBandsOutput) -> Self {
+		(output.average, output.upper, output.lower)
+	}
+}
+
 impl BollingerBands {
 	pub fn new(period: usize, multiplier: f64) -> Result<Self> {
 		Ok(Self {
@@ -73,6 +82,10 @@ impl BollingerBands {
 	pub fn multiplier(&self) -> f64 {
 		self.multiplier
 	}
+
+	pub fn builder() -> BollingerBandsBuilder {
+		BollingerBandsBuilder::default()
+	}
 }
 
 impl Period for BollingerBands {
@@ -81,6 +94,12 @@ impl Period for BollingerBands {
 	}
 }
 
+impl Ready for BollingerBands {
+	fn is_ready(&self) -> bool {
+		self.sd.is_ready()
+	}
+}
+
 impl Next<f64> for BollingerBands {
 	type Output = BollingerBandsOutput;
 
@@ -122,6 +141,49 @@ impl fmt::Display for BollingerBands {
 	}
 }
 
+/// Builder of [BollingerBands](struct.BollingerBands.html) with named parameters.
+///
+/// The parameters start at the canonical period of 20 and multiplier of 2.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::BollingerBands;
+///
+/// let bb = BollingerBands::builder().multiplier(2.5).build().unwrap();
+/// assert_eq!(format!("{}", bb), "BB(20, 2.5)");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BollingerBandsBuilder {
+	period: usize,
+	multiplier: f64,
+}
+
+impl BollingerBandsBuilder {
+	pub fn period(mut self, period: usize) -> Self {
+		self.period = period;
+		self
+	}
+
+	pub fn multiplier(mut self, multiplier: f64) -> Self {
+		self.multiplier = multiplier;
+		self
+	}
+
+	pub fn build(self) -> Result<BollingerBands> {
+		BollingerBands::new(self.period, self.multiplier)
+	}
+}
+
+impl Default for BollingerBandsBuilder {
+	fn default() -> Self {
+		Self {
+			period: 20,
+			multiplier: 2.0,
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -161,6 +223,14 @@ mod tests {
 		assert_eq!(round(d.lower), -0.395);
 	}
 
+	#[test]
+	fn test_next_into_tuple() {
+		let mut bb = BollingerBands::new(3, 2.0_f64).unwrap();
+		bb.next(2.0);
+		let (average, upper, lower) = bb.next(5.0).into();
+		assert_eq!((average, upper, lower), (3.5, 6.5, 0.5));
+	}
+
 	#[test]
 	fn test_reset() {
 		let mut bb = BollingerBands::new(5, 2.0_f64).unwrap();
@@ -198,4 +268,20 @@ mod tests {
 		let bb = BollingerBands::new(10, 3.0_f64).unwrap();
 		assert_eq!(format!("{}", bb), "BB(10, 3)");
 	}
+
+	#[test]
+	fn test_builder() {
+		let bb = BollingerBands::builder().build().unwrap();
+		assert_eq!(bb.period(), 20);
+		assert_eq!(bb.multiplier(), 2.0);
+
+		let bb = BollingerBandsBuilder::default()
+			.period(3)
+			.multiplier(1.5)
+			.build()
+			.unwrap();
+		assert_eq!(format!("{}", bb), "BB(3, 1.5)");
+
+		assert!(BollingerBands::builder().period(0).build().is_err());
+	}
 }
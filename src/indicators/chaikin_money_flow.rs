@@ -0,0 +1,184 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::RollingSum;
+use crate::{Close, High, Low, Next, Period, Ready, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Chaikin Money Flow (CMF).
+///
+/// Measures the amount of money flow volume over the last _n_ periods. It ranges from -1 to 1.
+///
+/// # Formula
+///
+/// CLV = ((close - low) - (high - close)) / (high - low)
+///
+/// CMF = Σ(CLV × volume) / Σ(volume)
+///
+/// Where:
+///
+/// * _CLV_ - close location value. If high is equal to low, CLV is 0.
+/// * the sums are taken over the last _n_ periods
+///
+/// If the total volume of the last _n_ periods is zero, 0 is returned.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ChaikinMoneyFlow;
+/// use ta::{DataItem, Next};
+///
+/// let mut cmf = ChaikinMoneyFlow::new(3).unwrap();
+///
+/// let di = DataItem::builder()
+///             .high(12.0)
+///             .low(8.0)
+///             .close(11.0)
+///             .open(9.0)
+///             .volume(200.0)
+///             .build().unwrap();
+///
+/// assert_eq!(cmf.next(&di), 0.5);
+/// ```
+///
+/// # Links
+///
+/// * [Chaikin Money Flow, stockcharts](https://school.stockcharts.com/doku.php?id=technical_indicators:chaikin_money_flow_cmf)
+#[doc(alias = "CMF")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChaikinMoneyFlow {
+	money_flow_volume_sum: RollingSum,
+	volume_sum: RollingSum,
+}
+
+impl ChaikinMoneyFlow {
+	pub fn new(period: usize) -> Result<Self> {
+		Ok(Self {
+			money_flow_volume_sum: RollingSum::new(period)?,
+			volume_sum: RollingSum::new(period)?,
+		})
+	}
+}
+
+impl Period for ChaikinMoneyFlow {
+	fn period(&self) -> usize {
+		self.volume_sum.period()
+	}
+}
+
+impl Ready for ChaikinMoneyFlow {
+	fn is_ready(&self) -> bool {
+		self.volume_sum.is_ready()
+	}
+}
+
+impl<T: High + Low + Close + Volume> Next<&T> for ChaikinMoneyFlow {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		let (high, low, close) = (input.high(), input.low(), input.close());
+		let clv = if high == low {
+			0.0
+		} else {
+			((close - low) - (high - close)) / (high - low)
+		};
+		let volume = input.volume();
+		let money_flow_volume = clv * volume;
+
+		let money_flow_volume_sum = self.money_flow_volume_sum.next(money_flow_volume);
+		let volume_sum = self.volume_sum.next(volume);
+
+		if volume_sum == 0.0 {
+			0.0
+		} else {
+			money_flow_volume_sum / volume_sum
+		}
+	}
+}
+
+impl Reset for ChaikinMoneyFlow {
+	fn reset(&mut self) {
+		self.money_flow_volume_sum.reset();
+		self.volume_sum.reset();
+	}
+}
+
+impl Default for ChaikinMoneyFlow {
+	fn default() -> Self {
+		Self::new(20).unwrap()
+	}
+}
+
+impl fmt::Display for ChaikinMoneyFlow {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "CMF({})", self.volume_sum.period())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	#[test]
+	fn test_new() {
+		assert!(ChaikinMoneyFlow::new(0).is_err());
+		assert!(ChaikinMoneyFlow::new(1).is_ok());
+	}
+
+	#[test]
+	fn test_next_bar() {
+		let test_data = vec![
+			// high, low, close, volume, expected
+			(10.0, 8.0, 9.0, 100.0, 0.0),
+			(12.0, 8.0, 11.0, 200.0, 0.333),
+			(10.0, 10.0, 10.0, 50.0, 0.286), // high == low, CLV is 0
+			(14.0, 10.0, 10.0, 150.0, -0.125),
+		];
+
+		let mut cmf = ChaikinMoneyFlow::new(3).unwrap();
+
+		for (high, low, close, volume, expected) in test_data {
+			let bar = Bar::new().high(high).low(low).close(close).volume(volume);
+			assert_eq!(round(cmf.next(&bar)), expected);
+		}
+	}
+
+	#[test]
+	fn test_zero_volume() {
+		let mut cmf = ChaikinMoneyFlow::new(2).unwrap();
+		let bar = Bar::new().high(12).low(8).close(11).volume(0.0);
+		assert_eq!(cmf.next(&bar), 0.0);
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut cmf = ChaikinMoneyFlow::new(3).unwrap();
+		let bar1 = Bar::new().high(12).low(8).close(11).volume(200.0);
+		let bar2 = Bar::new().high(14).low(10).close(10).volume(150.0);
+
+		assert_eq!(cmf.next(&bar1), 0.5);
+		assert_eq!(round(cmf.next(&bar2)), -0.143);
+
+		cmf.reset();
+		assert!(!cmf.is_ready());
+		assert_eq!(cmf.next(&bar2), -1.0);
+	}
+
+	#[test]
+	fn test_default() {
+		ChaikinMoneyFlow::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let indicator = ChaikinMoneyFlow::new(20).unwrap();
+		assert_eq!(format!("{}", indicator), "CMF(20)");
+	}
+}
@@ -0,0 +1,205 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{AccumulationDistribution, ExponentialMovingAverage as Ema};
+use crate::{Close, High, Low, Next, Period, Ready, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Chaikin Oscillator.
+///
+/// The difference between a fast and a slow EMA of the
+/// [Accumulation/Distribution line](struct.AccumulationDistribution.html).
+/// Unlike the [Chaikin Money Flow](struct.ChaikinMoneyFlow.html), which is a ratio over a
+/// window, it measures the momentum of the cumulative A/D line.
+///
+/// # Formula
+///
+/// CLV = ((C - L) - (H - C)) / (H - L)
+///
+/// A/D<sub>t</sub> = A/D<sub>t-1</sub> + CLV × V
+///
+/// Chaikin Oscillator = EMA(A/D, fast) - EMA(A/D, slow)
+///
+/// Where:
+///
+/// * _CLV_ - close location value, 0 if the high and the low are equal
+/// * _H_, _L_, _C_ - high, low and close prices
+/// * _V_ - volume
+///
+/// # Parameters
+///
+/// * _fast_period_ - period of the fast EMA (integer greater than 0). Default is 3.
+/// * _slow_period_ - period of the slow EMA (integer greater than _fast_period_). Default is 10.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ChaikinOscillator;
+/// use ta::{DataItem, Next};
+///
+/// let mut co = ChaikinOscillator::new(2, 3).unwrap();
+///
+/// let di1 = DataItem::builder()
+///             .high(10.0)
+///             .low(8.0)
+///             .close(9.0)
+///             .open(9.0)
+///             .volume(100.0)
+///             .build().unwrap();
+///
+/// let di2 = DataItem::builder()
+///             .high(11.0)
+///             .low(9.0)
+///             .close(10.5)
+///             .open(9.0)
+///             .volume(200.0)
+///             .build().unwrap();
+///
+/// assert_eq!(co.next(&di1), 0.0);
+/// assert_eq!(co.next(&di2).round(), 17.0);
+/// ```
+///
+/// # Links
+///
+/// * [Chaikin Oscillator, stockcharts](https://school.stockcharts.com/doku.php?id=technical_indicators:chaikin_oscillator)
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChaikinOscillator {
+	ad: AccumulationDistribution,
+	fast_ema: Ema,
+	slow_ema: Ema,
+}
+
+impl ChaikinOscillator {
+	pub fn new(fast_period: usize, slow_period: usize) -> Result<Self> {
+		if fast_period >= slow_period {
+			return Err(TaError::InvalidParameter);
+		}
+
+		Ok(Self {
+			ad: AccumulationDistribution::new(),
+			fast_ema: Ema::new(fast_period)?,
+			slow_ema: Ema::new(slow_period)?,
+		})
+	}
+}
+
+impl Period for ChaikinOscillator {
+	fn period(&self) -> usize {
+		self.slow_ema.period()
+	}
+}
+
+impl Ready for ChaikinOscillator {
+	fn is_ready(&self) -> bool {
+		self.slow_ema.is_ready()
+	}
+}
+
+impl<T: High + Low + Close + Volume> Next<&T> for ChaikinOscillator {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		let ad = self.ad.next(input);
+		self.fast_ema.next(ad) - self.slow_ema.next(ad)
+	}
+}
+
+impl Reset for ChaikinOscillator {
+	fn reset(&mut self) {
+		self.ad.reset();
+		self.fast_ema.reset();
+		self.slow_ema.reset();
+	}
+}
+
+impl Default for ChaikinOscillator {
+	fn default() -> Self {
+		Self::new(3, 10).unwrap()
+	}
+}
+
+impl fmt::Display for ChaikinOscillator {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"CHAIKIN_OSC({}, {})",
+			self.fast_ema.period(),
+			self.slow_ema.period()
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	#[test]
+	fn test_new() {
+		assert!(ChaikinOscillator::new(0, 10).is_err());
+		assert!(ChaikinOscillator::new(10, 10).is_err());
+		assert!(ChaikinOscillator::new(10, 3).is_err());
+		assert!(ChaikinOscillator::new(3, 10).is_ok());
+	}
+
+	#[test]
+	fn test_next_bar() {
+		let test_data = vec![
+			// high, low, close, volume, expected
+			(10.0, 8.0, 9.0, 100.0, 0.0),
+			(11.0, 9.0, 10.5, 200.0, 16.667),
+			(12.0, 10.0, 11.0, 150.0, 13.889),
+			(11.0, 8.0, 9.0, 300.0, -7.87),
+			(10.0, 10.0, 10.0, 100.0, -8.873),
+			(9.0, 7.0, 8.5, 50.0, -1.916),
+		];
+
+		let mut co = ChaikinOscillator::new(2, 3).unwrap();
+
+		for (high, low, close, volume, expected) in test_data {
+			let bar = Bar::new().high(high).low(low).close(close).volume(volume);
+			assert_eq!(round(co.next(&bar)), expected);
+		}
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut co = ChaikinOscillator::new(2, 3).unwrap();
+		let bar = Bar::new().high(5).low(4).close(5).volume(100.0);
+		co.next(&bar);
+		co.next(&bar);
+		assert!(!co.is_ready());
+		co.next(&bar);
+		assert!(co.is_ready());
+
+		co.reset();
+		assert!(!co.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut co = ChaikinOscillator::new(2, 3).unwrap();
+		let bar1 = Bar::new().high(10).low(8).close(9).volume(100.0);
+		let bar2 = Bar::new().high(11).low(9).close(10.5).volume(200.0);
+
+		assert_eq!(co.next(&bar1), 0.0);
+		assert_eq!(round(co.next(&bar2)), 16.667);
+
+		co.reset();
+		assert_eq!(co.next(&bar1), 0.0);
+		assert_eq!(round(co.next(&bar2)), 16.667);
+	}
+
+	#[test]
+	fn test_default() {
+		ChaikinOscillator::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let indicator = ChaikinOscillator::new(3, 10).unwrap();
+		assert_eq!(format!("{}", indicator), "CHAIKIN_OSC(3, 10)");
+	}
+}
@@ -0,0 +1,217 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::RollingSum;
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Chande Momentum Oscillator (CMO).
+///
+/// A momentum oscillator similar to the RSI, but calculated from raw sums of the price
+/// changes instead of their averages. It ranges from -100 to 100.
+///
+/// # Formula
+///
+/// CMO = (S<sub>up</sub> - S<sub>down</sub>) / (S<sub>up</sub> + S<sub>down</sub>) × 100
+///
+/// Where:
+///
+/// * _S<sub>up</sub>_ - sum of the positive price changes over the last _n_ periods
+/// * _S<sub>down</sub>_ - sum of the absolute negative price changes over the last _n_ periods
+///
+/// If both sums are 0 (flat market), 0 is returned. The CMO is ready once _n + 1_ values
+/// have been consumed.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ChandeMomentumOscillator;
+/// use ta::Next;
+///
+/// let mut cmo = ChandeMomentumOscillator::new(3).unwrap();
+/// assert_eq!(cmo.next(10.0), 0.0);
+/// assert_eq!(cmo.next(12.0), 100.0);
+/// assert_eq!(cmo.next(14.0), 100.0);
+/// assert_eq!(cmo.next(13.0), 60.0);
+/// ```
+///
+/// # Links
+///
+/// * [Chande momentum oscillator, Wikipedia](https://en.wikipedia.org/wiki/Chande_momentum_oscillator)
+#[doc(alias = "CMO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChandeMomentumOscillator {
+	prev: Option<f64>,
+	sum_up: RollingSum,
+	sum_down: RollingSum,
+}
+
+impl ChandeMomentumOscillator {
+	pub fn new(period: usize) -> Result<Self> {
+		Ok(Self {
+			prev: None,
+			sum_up: RollingSum::new(period)?,
+			sum_down: RollingSum::new(period)?,
+		})
+	}
+}
+
+impl Period for ChandeMomentumOscillator {
+	fn period(&self) -> usize {
+		self.sum_up.period()
+	}
+}
+
+impl Ready for ChandeMomentumOscillator {
+	fn is_ready(&self) -> bool {
+		self.sum_up.is_ready()
+	}
+}
+
+impl Next<f64> for ChandeMomentumOscillator {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		let prev = match self.prev.replace(input) {
+			Some(prev) => prev,
+			None => return 0.0,
+		};
+
+		let change = input - prev;
+		let sum_up = self.sum_up.next(change.max(0.0));
+		let sum_down = self.sum_down.next((-change).max(0.0));
+
+		let total = sum_up + sum_down;
+		if total == 0.0 {
+			0.0
+		} else {
+			(sum_up - sum_down) / total * 100.0
+		}
+	}
+}
+
+impl<T: Close> Next<&T> for ChandeMomentumOscillator {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl Reset for ChandeMomentumOscillator {
+	fn reset(&mut self) {
+		self.prev = None;
+		self.sum_up.reset();
+		self.sum_down.reset();
+	}
+}
+
+impl Default for ChandeMomentumOscillator {
+	fn default() -> Self {
+		Self::new(14).unwrap()
+	}
+}
+
+impl fmt::Display for ChandeMomentumOscillator {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "CMO({})", self.sum_up.period())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	type Cmo = ChandeMomentumOscillator;
+
+	test_indicator!(Cmo);
+
+	#[test]
+	fn test_new() {
+		assert!(Cmo::new(0).is_err());
+		assert!(Cmo::new(1).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let mut cmo = Cmo::new(3).unwrap();
+
+		assert_eq!(cmo.next(10.0), 0.0);
+		assert_eq!(cmo.next(12.0), 100.0);
+		assert_eq!(round(cmo.next(11.0)), 33.333);
+		assert_eq!(round(cmo.next(14.0)), 66.667);
+		assert_eq!(cmo.next(13.0), 20.0);
+		assert_eq!(cmo.next(13.0), 50.0);
+
+		let mut cmo = Cmo::new(3).unwrap();
+		let bar1 = Bar::new().close(10);
+		let bar2 = Bar::new().close(8);
+		assert_eq!(cmo.next(&bar1), 0.0);
+		assert_eq!(cmo.next(&bar2), -100.0);
+	}
+
+	#[test]
+	fn test_flat_market() {
+		let mut cmo = Cmo::new(2).unwrap();
+		for _ in 0..4 {
+			assert_eq!(cmo.next(5.0), 0.0);
+		}
+	}
+
+	#[test]
+	fn test_flat_after_movement() {
+		// the sums of the changes do not cancel out exactly
+		let mut cmo = Cmo::new(3).unwrap();
+		for &price in &[1.3, 0.8, 0.1, 0.1, 1.4, 1.0] {
+			cmo.next(price);
+		}
+		cmo.next(1.0);
+		cmo.next(1.0);
+		for _ in 0..3 {
+			assert_eq!(cmo.next(1.0), 0.0);
+		}
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut cmo = Cmo::new(3).unwrap();
+		for _ in 0..3 {
+			cmo.next(1.0);
+			assert!(!cmo.is_ready());
+		}
+		cmo.next(1.0);
+		assert!(cmo.is_ready());
+
+		cmo.reset();
+		assert!(!cmo.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut cmo = Cmo::new(3).unwrap();
+		assert_eq!(cmo.next(10.0), 0.0);
+		assert_eq!(cmo.next(12.0), 100.0);
+
+		cmo.reset();
+		assert_eq!(cmo.next(12.0), 0.0);
+		assert_eq!(cmo.next(10.0), -100.0);
+	}
+
+	#[test]
+	fn test_default() {
+		Cmo::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let indicator = Cmo::new(9).unwrap();
+		assert_eq!(format!("{}", indicator), "CMO(9)");
+	}
+}
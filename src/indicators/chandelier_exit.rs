@@ -1,11 +1,11 @@
-use std::fmt;
+use core::fmt;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use crate::errors::Result;
 use crate::indicators::{AverageTrueRange, Maximum, Minimum};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
 
 /// Chandelier Exit (CE).
 ///
@@ -52,7 +52,7 @@ use crate::{Close, High, Low, Next, Period, Reset};
 ///
 #[doc(alias = "CE")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ChandelierExit {
 	atr: AverageTrueRange,
 	min: Minimum,
@@ -93,6 +93,12 @@ impl Period for ChandelierExit {
 	}
 }
 
+impl Ready for ChandelierExit {
+	fn is_ready(&self) -> bool {
+		self.atr.is_ready() && self.max.is_ready()
+	}
+}
+
 impl<T: Low + High + Close> Next<&T> for ChandelierExit {
 	type Output = ChandelierExitOutput;
 
@@ -0,0 +1,207 @@
+use core::fmt;
+
+use num_traits::Float;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{Maximum, Minimum, RollingSum, TrueRange};
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Choppiness Index (CHOP).
+///
+/// Shows whether the market is trending or ranging. Values near 100 indicate a choppy,
+/// sideways market, values near 0 a strong trend. Common thresholds are 38.2 (trending)
+/// and 61.8 (choppy). It ranges from 0 to 100.
+///
+/// # Formula
+///
+/// CHOP = 100 × log<sub>10</sub>(Σ TR / (H<sub>n</sub> - L<sub>n</sub>)) / log<sub>10</sub>(n)
+///
+/// Where:
+///
+/// * _TR_ - [true range](struct.TrueRange.html), summed over the last _n_ periods
+/// * _H<sub>n</sub>_ - highest price for the last _n_ periods
+/// * _L<sub>n</sub>_ - lowest price for the last _n_ periods
+///
+/// If the highest and the lowest prices are equal, 100 is returned.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 1). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ChoppinessIndex;
+/// use ta::{DataItem, Next};
+///
+/// let mut chop = ChoppinessIndex::new(3).unwrap();
+///
+/// let di = DataItem::builder()
+///             .high(10.0)
+///             .low(8.0)
+///             .close(9.0)
+///             .open(9.0)
+///             .volume(100.0)
+///             .build().unwrap();
+///
+/// assert_eq!(chop.next(&di), 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Choppiness Index, tradingview](https://www.tradingview.com/support/solutions/43000501980-choppiness-index-chop/)
+#[doc(alias = "CHOP")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChoppinessIndex {
+	true_range: TrueRange,
+	maximum: Maximum,
+	minimum: Minimum,
+	sum: RollingSum,
+}
+
+impl ChoppinessIndex {
+	pub fn new(period: usize) -> Result<Self> {
+		match period {
+			0 | 1 => Err(TaError::InvalidParameter),
+			_ => Ok(Self {
+				true_range: TrueRange::new(),
+				maximum: Maximum::new(period)?,
+				minimum: Minimum::new(period)?,
+				sum: RollingSum::new(period)?,
+			}),
+		}
+	}
+}
+
+impl Period for ChoppinessIndex {
+	fn period(&self) -> usize {
+		self.sum.period()
+	}
+}
+
+impl Ready for ChoppinessIndex {
+	fn is_ready(&self) -> bool {
+		self.maximum.is_ready()
+	}
+}
+
+impl<T: High + Low + Close> Next<&T> for ChoppinessIndex {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		let sum = self.sum.next(self.true_range.next(input));
+
+		let highest = self.maximum.next(input.high());
+		let lowest = self.minimum.next(input.low());
+
+		if highest == lowest {
+			return 100.0;
+		}
+
+		100.0 * Float::log10(sum / (highest - lowest)) / Float::log10(self.sum.period() as f64)
+	}
+}
+
+impl Reset for ChoppinessIndex {
+	fn reset(&mut self) {
+		self.true_range.reset();
+		self.maximum.reset();
+		self.minimum.reset();
+		self.sum.reset();
+	}
+}
+
+impl Default for ChoppinessIndex {
+	fn default() -> Self {
+		Self::new(14).unwrap()
+	}
+}
+
+impl fmt::Display for ChoppinessIndex {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "CHOP({})", self.sum.period())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	#[test]
+	fn test_new() {
+		assert!(ChoppinessIndex::new(0).is_err());
+		assert!(ChoppinessIndex::new(1).is_err());
+		assert!(ChoppinessIndex::new(2).is_ok());
+	}
+
+	#[test]
+	fn test_next_bar() {
+		let test_data = vec![
+			// high, low, close, expected
+			(10.0, 8.0, 9.0, 0.0),
+			(11.0, 9.0, 10.5, 26.186),
+			(12.0, 10.0, 11.0, 36.907),
+			(11.0, 8.0, 9.0, 50.938),
+			(10.0, 7.0, 7.5, 42.782),
+			(9.0, 7.0, 8.5, 63.093),
+		];
+
+		let mut chop = ChoppinessIndex::new(3).unwrap();
+
+		for (high, low, close, expected) in test_data {
+			let bar = Bar::new().high(high).low(low).close(close);
+			assert_eq!(round(chop.next(&bar)), expected);
+		}
+	}
+
+	#[test]
+	fn test_flat_market() {
+		let mut chop = ChoppinessIndex::new(3).unwrap();
+		let bar = Bar::new().high(5).low(5).close(5);
+		assert_eq!(chop.next(&bar), 100.0);
+		assert_eq!(chop.next(&bar), 100.0);
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut chop = ChoppinessIndex::new(3).unwrap();
+		let bar = Bar::new().high(5).low(4).close(5);
+		chop.next(&bar);
+		chop.next(&bar);
+		assert!(!chop.is_ready());
+		chop.next(&bar);
+		assert!(chop.is_ready());
+
+		chop.reset();
+		assert!(!chop.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut chop = ChoppinessIndex::new(3).unwrap();
+		let bar1 = Bar::new().high(10).low(8).close(9);
+		let bar2 = Bar::new().high(11).low(9).close(10.5);
+
+		assert_eq!(chop.next(&bar1), 0.0);
+		assert_eq!(round(chop.next(&bar2)), 26.186);
+
+		chop.reset();
+		assert_eq!(chop.next(&bar1), 0.0);
+		assert_eq!(round(chop.next(&bar2)), 26.186);
+	}
+
+	#[test]
+	fn test_default() {
+		ChoppinessIndex::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let indicator = ChoppinessIndex::new(14).unwrap();
+		assert_eq!(format!("{}", indicator), "CHOP(14)");
+	}
+}
@@ -1,11 +1,11 @@
-use std::fmt;
+use core::fmt;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::errors::Result;
+use crate::errors::{Result, TaError};
 use crate::indicators::{MeanAbsoluteDeviation, SimpleMovingAverage};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
 
 /// Commodity Channel Index (CCI)
 ///
@@ -19,9 +19,19 @@ use crate::{Close, High, Low, Next, Period, Reset};
 ///
 /// CCI(_period_) = (TP - SMA(_period_) of TP) / (MAD(_period_) * 0.015)
 ///
+/// Where:
+///
+/// * _TP_ - typical price, (high + low + close) / 3
+/// * _MAD_ - mean absolute deviation of the typical price
+///
+/// If the MAD is 0, the CCI is 0.
+///
 /// # Parameters
 ///
 /// * _period_ - number of periods (integer greater than 0). Default is 20.
+/// * _constant_ - Lambert's constant (greater than 0). Default is 0.015.
+///
+/// Use [new_with_constant](#method.new_with_constant) to override the constant.
 ///
 /// # Links
 ///
@@ -29,19 +39,34 @@ use crate::{Close, High, Low, Next, Period, Reset};
 /// * [Commodity Channel Index, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:commodity_channel_index_cci)
 ///
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CommodityChannelIndex {
+	constant: f64,
 	sma: SimpleMovingAverage,
 	mad: MeanAbsoluteDeviation,
 }
 
 impl CommodityChannelIndex {
 	pub fn new(period: usize) -> Result<Self> {
+		Self::new_with_constant(period, 0.015)
+	}
+
+	/// Creates a CCI which uses `constant` instead of Lambert's 0.015.
+	pub fn new_with_constant(period: usize, constant: f64) -> Result<Self> {
+		if constant.is_nan() || constant <= 0.0 {
+			return Err(TaError::InvalidParameter);
+		}
+
 		Ok(Self {
+			constant,
 			sma: SimpleMovingAverage::new(period)?,
 			mad: MeanAbsoluteDeviation::new(period)?,
 		})
 	}
+
+	pub fn constant(&self) -> f64 {
+		self.constant
+	}
 }
 
 impl Period for CommodityChannelIndex {
@@ -50,6 +75,12 @@ impl Period for CommodityChannelIndex {
 	}
 }
 
+impl Ready for CommodityChannelIndex {
+	fn is_ready(&self) -> bool {
+		self.sma.is_ready()
+	}
+}
+
 impl<T: Close + High + Low> Next<&T> for CommodityChannelIndex {
 	type Output = f64;
 
@@ -62,7 +93,7 @@ impl<T: Close + High + Low> Next<&T> for CommodityChannelIndex {
 			return 0.0;
 		}
 
-		(tp - sma) / (mad * 0.015)
+		(tp - sma) / (mad * self.constant)
 	}
 }
 
@@ -81,7 +112,11 @@ impl Default for CommodityChannelIndex {
 
 impl fmt::Display for CommodityChannelIndex {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		write!(f, "CCI({})", self.sma.period())
+		if self.constant == 0.015 {
+			write!(f, "CCI({})", self.sma.period())
+		} else {
+			write!(f, "CCI({}, {})", self.sma.period(), self.constant)
+		}
 	}
 }
 
@@ -94,6 +129,12 @@ mod tests {
 	fn test_new() {
 		assert!(CommodityChannelIndex::new(0).is_err());
 		assert!(CommodityChannelIndex::new(1).is_ok());
+
+		assert!(CommodityChannelIndex::new_with_constant(0, 0.015).is_err());
+		assert!(CommodityChannelIndex::new_with_constant(20, 0.0).is_err());
+		assert!(CommodityChannelIndex::new_with_constant(20, -0.015).is_err());
+		assert!(CommodityChannelIndex::new_with_constant(20, f64::NAN).is_err());
+		assert!(CommodityChannelIndex::new_with_constant(20, 0.03).is_ok());
 	}
 
 	#[test]
@@ -119,6 +160,30 @@ mod tests {
 		assert_eq!(round(cci.next(&bar6)), -126.126);
 	}
 
+	#[test]
+	fn test_next_with_constant() {
+		// doubling the constant halves the CCI
+		let mut cci = CommodityChannelIndex::new_with_constant(5, 0.03).unwrap();
+
+		let bar1 = Bar::new().high(2).low(1).close(1.5);
+		assert_eq!(round(cci.next(&bar1)), 0.0);
+
+		let bar2 = Bar::new().high(5).low(3).close(4);
+		assert_eq!(round(cci.next(&bar2)), 33.333);
+
+		let bar3 = Bar::new().high(9).low(7).close(8);
+		assert_eq!(round(cci.next(&bar3)), 50.0);
+	}
+
+	#[test]
+	fn test_next_flat() {
+		let mut cci = CommodityChannelIndex::new(3).unwrap();
+		let bar = Bar::new().high(5).low(3).close(4);
+		for _ in 0..4 {
+			assert_eq!(cci.next(&bar), 0.0);
+		}
+	}
+
 	#[test]
 	fn test_reset() {
 		let mut cci = CommodityChannelIndex::new(5).unwrap();
@@ -144,5 +209,8 @@ mod tests {
 	fn test_display() {
 		let indicator = CommodityChannelIndex::new(10).unwrap();
 		assert_eq!(format!("{}", indicator), "CCI(10)");
+
+		let indicator = CommodityChannelIndex::new_with_constant(10, 0.03).unwrap();
+		assert_eq!(format!("{}", indicator), "CCI(10, 0.03)");
 	}
 }
@@ -0,0 +1,210 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{RateOfChange as Roc, WeightedMovingAverage as Wma};
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Coppock Curve.
+///
+/// A long term momentum indicator, the weighted moving average of the sum of two rates
+/// of change. A buy signal occurs when the curve crosses from negative to positive.
+///
+/// # Formula
+///
+/// Coppock = WMA(ROC(p, n<sub>1</sub>) + ROC(p, n<sub>2</sub>), m)
+///
+/// Where:
+///
+/// * _p_ - input value
+/// * _ROC_ - [rate of change](struct.RateOfChange.html)
+/// * _WMA_ - [weighted moving average](struct.WeightedMovingAverage.html)
+///
+/// The curve is ready once _max(n<sub>1</sub>, n<sub>2</sub>) + m_ values have been consumed.
+///
+/// # Parameters
+///
+/// * _wma_period_ - period of the WMA (integer greater than 0). Default is 10.
+/// * _roc1_period_ - period of the first ROC (integer greater than 0). Default is 14.
+/// * _roc2_period_ - period of the second ROC (integer greater than 0). Default is 11.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::CoppockCurve;
+/// use ta::Next;
+///
+/// let mut coppock = CoppockCurve::new(1, 1, 1).unwrap();
+/// assert_eq!(coppock.next(10.0), 0.0);
+/// assert_eq!(coppock.next(12.0), 40.0);
+/// ```
+///
+/// # Links
+///
+/// * [Coppock curve, Wikipedia](https://en.wikipedia.org/wiki/Coppock_curve)
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoppockCurve {
+	count: usize,
+	roc1: Roc,
+	roc2: Roc,
+	wma: Wma,
+}
+
+impl CoppockCurve {
+	pub fn new(wma_period: usize, roc1_period: usize, roc2_period: usize) -> Result<Self> {
+		Ok(Self {
+			count: 0,
+			roc1: Roc::new(roc1_period)?,
+			roc2: Roc::new(roc2_period)?,
+			wma: Wma::new(wma_period)?,
+		})
+	}
+
+	fn ready_count(&self) -> usize {
+		self.roc1.period().max(self.roc2.period()) + self.wma.period()
+	}
+}
+
+impl Period for CoppockCurve {
+	fn period(&self) -> usize {
+		self.wma.period()
+	}
+}
+
+impl Ready for CoppockCurve {
+	fn is_ready(&self) -> bool {
+		self.count == self.ready_count()
+	}
+}
+
+impl Next<f64> for CoppockCurve {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		if self.count < self.ready_count() {
+			self.count += 1;
+		}
+
+		let roc = self.roc1.next(input) + self.roc2.next(input);
+		self.wma.next(roc)
+	}
+}
+
+impl<T: Close> Next<&T> for CoppockCurve {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl Reset for CoppockCurve {
+	fn reset(&mut self) {
+		self.count = 0;
+		self.roc1.reset();
+		self.roc2.reset();
+		self.wma.reset();
+	}
+}
+
+impl Default for CoppockCurve {
+	fn default() -> Self {
+		Self::new(10, 14, 11).unwrap()
+	}
+}
+
+impl fmt::Display for CoppockCurve {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"COPPOCK({}, {}, {})",
+			self.wma.period(),
+			self.roc1.period(),
+			self.roc2.period()
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	test_indicator!(CoppockCurve);
+
+	#[test]
+	fn test_new() {
+		assert!(CoppockCurve::new(0, 14, 11).is_err());
+		assert!(CoppockCurve::new(10, 0, 11).is_err());
+		assert!(CoppockCurve::new(10, 14, 0).is_err());
+		assert!(CoppockCurve::new(10, 14, 11).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let mut coppock = CoppockCurve::new(3, 2, 1).unwrap();
+
+		assert_eq!(coppock.next(10.0), 0.0);
+		assert_eq!(round(coppock.next(11.0)), 13.333);
+		assert_eq!(round(coppock.next(10.5)), 6.894);
+		assert_eq!(round(coppock.next(12.0)), 15.173);
+		assert_eq!(round(coppock.next(11.0)), 6.082);
+		assert_eq!(round(coppock.next(13.0)), 15.963);
+
+		let mut coppock = CoppockCurve::new(3, 2, 1).unwrap();
+		let bar1 = Bar::new().close(10);
+		let bar2 = Bar::new().close(11);
+		assert_eq!(coppock.next(&bar1), 0.0);
+		assert_eq!(round(coppock.next(&bar2)), 13.333);
+	}
+
+	#[test]
+	fn test_next_rising() {
+		// a steadily rising price turns the curve positive
+		let mut coppock = CoppockCurve::default();
+		let mut value = 0.0;
+		for i in 0..50 {
+			value = coppock.next(100.0 + i as f64);
+		}
+		assert!(coppock.is_ready());
+		assert!(value > 0.0);
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut coppock = CoppockCurve::new(3, 2, 1).unwrap();
+		for _ in 0..4 {
+			coppock.next(1.0);
+			assert!(!coppock.is_ready());
+		}
+		coppock.next(1.0);
+		assert!(coppock.is_ready());
+
+		coppock.reset();
+		assert!(!coppock.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut coppock = CoppockCurve::new(3, 2, 1).unwrap();
+		assert_eq!(coppock.next(10.0), 0.0);
+		assert_eq!(round(coppock.next(11.0)), 13.333);
+
+		coppock.reset();
+		assert_eq!(coppock.next(10.0), 0.0);
+		assert_eq!(round(coppock.next(11.0)), 13.333);
+	}
+
+	#[test]
+	fn test_default() {
+		CoppockCurve::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let indicator = CoppockCurve::new(10, 14, 11).unwrap();
+		assert_eq!(format!("{}", indicator), "COPPOCK(10, 14, 11)");
+	}
+}
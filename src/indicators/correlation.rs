@@ -0,0 +1,196 @@
+use core::fmt;
+
+use num_traits::Float;
+
+use crate::errors::Result;
+use crate::indicators::{Covariance, Variance};
+use crate::{Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Pearson correlation (CORR).
+///
+/// Returns the correlation coefficient of the last n `(x, y)` pairs of two synchronized
+/// series. A value near ±1 means the series move together (or inversely), a value near 0
+/// that they are decorrelated. It ranges from -1 to 1.
+///
+/// # Formula
+///
+/// CORR = COV(x, y) / (SD(x) × SD(y))
+///
+/// Where:
+///
+/// * _COV_ - sample [covariance](struct.Covariance.html) of the last _n_ pairs
+/// * _SD_ - square root of the sample [variance](struct.Variance.html) of the last _n_ values
+///
+/// If either series has a variance of 0 over the window (this includes the first pair),
+/// the correlation is undefined and `f64::NAN` is returned.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 1). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Correlation;
+/// use ta::Next;
+///
+/// let mut corr = Correlation::new(3).unwrap();
+/// assert!(corr.next((1.0, 2.0)).is_nan());
+/// assert_eq!(corr.next((2.0, 1.0)), -1.0);
+/// ```
+///
+/// # Links
+///
+/// * [Pearson correlation coefficient, Wikipedia](https://en.wikipedia.org/wiki/Pearson_correlation_coefficient)
+#[doc(alias = "CORR")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Correlation {
+	covariance: Covariance,
+	variance_x: Variance,
+	variance_y: Variance,
+}
+
+impl Correlation {
+	pub fn new(period: usize) -> Result<Self> {
+		Ok(Self {
+			covariance: Covariance::new(period)?,
+			variance_x: Variance::new(period, 1)?,
+			variance_y: Variance::new(period, 1)?,
+		})
+	}
+}
+
+impl Period for Correlation {
+	fn period(&self) -> usize {
+		self.covariance.period()
+	}
+}
+
+impl Ready for Correlation {
+	fn is_ready(&self) -> bool {
+		self.covariance.is_ready()
+	}
+}
+
+impl Next<(f64, f64)> for Correlation {
+	type Output = f64;
+
+	fn next(&mut self, (x, y): (f64, f64)) -> Self::Output {
+		let covariance = self.covariance.next((x, y));
+		let variance_x = self.variance_x.next(x);
+		let variance_y = self.variance_y.next(y);
+
+		if variance_x == 0.0 || variance_y == 0.0 {
+			return f64::NAN;
+		}
+
+		(covariance / Float::sqrt(variance_x * variance_y)).clamp(-1.0, 1.0)
+	}
+}
+
+impl Reset for Correlation {
+	fn reset(&mut self) {
+		self.covariance.reset();
+		self.variance_x.reset();
+		self.variance_y.reset();
+	}
+}
+
+impl Default for Correlation {
+	fn default() -> Self {
+		Self::new(20).unwrap()
+	}
+}
+
+impl fmt::Display for Correlation {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "CORR({})", self.covariance.period())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	#[test]
+	fn test_new() {
+		assert!(Correlation::new(0).is_err());
+		assert!(Correlation::new(1).is_err());
+		assert!(Correlation::new(2).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let mut corr = Correlation::new(3).unwrap();
+		assert!(corr.next((1.0, 2.0)).is_nan());
+		assert_eq!(corr.next((2.0, 1.0)), -1.0);
+		assert_eq!(round(corr.next((4.0, 5.0))), 0.839);
+		assert_eq!(round(corr.next((3.0, 4.0))), 0.961);
+		assert_eq!(round(corr.next((5.0, 4.0))), 0.0);
+		assert_eq!(round(corr.next((8.0, 10.0))), 0.918);
+	}
+
+	#[test]
+	fn test_next_linear() {
+		let mut corr = Correlation::new(4).unwrap();
+		corr.next((1.0, 3.0));
+		for x in 2..10 {
+			let x = x as f64;
+			assert_eq!(round(corr.next((x, 2.0 * x + 1.0))), 1.0);
+		}
+
+		let mut corr = Correlation::new(4).unwrap();
+		corr.next((1.0, -3.0));
+		for x in 2..10 {
+			let x = x as f64;
+			assert_eq!(round(corr.next((x, -3.0 * x))), -1.0);
+		}
+	}
+
+	#[test]
+	fn test_next_zero_variance() {
+		let mut corr = Correlation::new(3).unwrap();
+		corr.next((1.0, 5.0));
+		assert!(corr.next((2.0, 5.0)).is_nan());
+		assert!(corr.next((3.0, 5.0)).is_nan());
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut corr = Correlation::new(3).unwrap();
+		corr.next((1.0, 1.0));
+		corr.next((2.0, 3.0));
+		assert!(!corr.is_ready());
+		corr.next((3.0, 2.0));
+		assert!(corr.is_ready());
+
+		corr.reset();
+		assert!(!corr.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut corr = Correlation::new(3).unwrap();
+		corr.next((1.0, 2.0));
+		assert_eq!(corr.next((2.0, 1.0)), -1.0);
+
+		corr.reset();
+		assert!(corr.next((2.0, 1.0)).is_nan());
+		assert_eq!(corr.next((3.0, 2.0)), 1.0);
+	}
+
+	#[test]
+	fn test_default() {
+		Correlation::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let corr = Correlation::new(20).unwrap();
+		assert_eq!(format!("{}", corr), "CORR(20)");
+	}
+}
@@ -0,0 +1,216 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Covariance (COV).
+///
+/// Returns the sample covariance of the last n `(x, y)` pairs of two synchronized series,
+/// for example a stock and an index to derive its beta. It is updated with the online
+/// extension of Welford's algorithm.
+///
+/// # Formula
+///
+/// COV = Σ (x<sub>i</sub> - μ<sub>x</sub>)(y<sub>i</sub> - μ<sub>y</sub>) / (N - 1)
+///
+/// Where:
+///
+/// * _μ<sub>x</sub>_, _μ<sub>y</sub>_ - means of the last _N_ values of each series
+/// * _N_ - number of pairs in the observation
+///
+/// For the first pair, 0 is returned.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 1). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Covariance;
+/// use ta::Next;
+///
+/// let mut cov = Covariance::new(3).unwrap();
+/// assert_eq!(cov.next((1.0, 2.0)), 0.0);
+/// assert_eq!(cov.next((2.0, 1.0)), -0.5);
+/// ```
+///
+/// # Links
+///
+/// * [Covariance, Wikipedia](https://en.wikipedia.org/wiki/Covariance)
+/// * [Online covariance, Wikipedia](https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Covariance)
+#[doc(alias = "COV")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Covariance {
+	period: usize,
+	index: usize,
+	count: usize,
+	mean_x: f64,
+	mean_y: f64,
+	m2_xy: f64,
+	deque: Box<[(f64, f64)]>,
+}
+
+impl Covariance {
+	pub fn new(period: usize) -> Result<Self> {
+		match period {
+			0 | 1 => Err(TaError::InvalidParameter),
+			_ => Ok(Self {
+				period,
+				index: 0,
+				count: 0,
+				mean_x: 0.0,
+				mean_y: 0.0,
+				m2_xy: 0.0,
+				deque: vec![(0.0, 0.0); period].into_boxed_slice(),
+			}),
+		}
+	}
+}
+
+impl Period for Covariance {
+	fn period(&self) -> usize {
+		self.period
+	}
+}
+
+impl Ready for Covariance {
+	fn is_ready(&self) -> bool {
+		self.count == self.period
+	}
+}
+
+impl Next<(f64, f64)> for Covariance {
+	type Output = f64;
+
+	fn next(&mut self, (x, y): (f64, f64)) -> Self::Output {
+		let (old_x, old_y) = self.deque[self.index];
+		self.deque[self.index] = (x, y);
+
+		self.index = if self.index + 1 < self.period {
+			self.index + 1
+		} else {
+			0
+		};
+
+		if self.count < self.period {
+			self.count += 1;
+			let dx = x - self.mean_x;
+			self.mean_x += dx / self.count as f64;
+			self.mean_y += (y - self.mean_y) / self.count as f64;
+			self.m2_xy += dx * (y - self.mean_y);
+		} else {
+			let old_mean_x = self.mean_x;
+			self.mean_x += (x - old_x) / self.period as f64;
+			self.mean_y += (y - old_y) / self.period as f64;
+			self.m2_xy +=
+				(x - old_mean_x) * (y - self.mean_y) - (old_x - old_mean_x) * (old_y - self.mean_y);
+		}
+
+		if self.count < 2 {
+			0.0
+		} else {
+			self.m2_xy / (self.count - 1) as f64
+		}
+	}
+}
+
+impl Reset for Covariance {
+	fn reset(&mut self) {
+		self.index = 0;
+		self.count = 0;
+		self.mean_x = 0.0;
+		self.mean_y = 0.0;
+		self.m2_xy = 0.0;
+		for i in 0..self.period {
+			self.deque[i] = (0.0, 0.0);
+		}
+	}
+}
+
+impl Default for Covariance {
+	fn default() -> Self {
+		Self::new(20).unwrap()
+	}
+}
+
+impl fmt::Display for Covariance {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "COV({})", self.period)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::indicators::Variance;
+	use crate::test_helper::*;
+
+	#[test]
+	fn test_new() {
+		assert!(Covariance::new(0).is_err());
+		assert!(Covariance::new(1).is_err());
+		assert!(Covariance::new(2).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let mut cov = Covariance::new(3).unwrap();
+		assert_eq!(cov.next((1.0, 2.0)), 0.0);
+		assert_eq!(cov.next((2.0, 1.0)), -0.5);
+		assert_eq!(round(cov.next((4.0, 5.0))), 2.667);
+		assert_eq!(round(cov.next((3.0, 4.0))), 2.0);
+		assert_eq!(round(cov.next((5.0, 4.0))), 0.0);
+		assert_eq!(round(cov.next((8.0, 10.0))), 8.0);
+	}
+
+	#[test]
+	fn test_next_same_series() {
+		// the covariance of a series with itself is its sample variance
+		let mut cov = Covariance::new(4).unwrap();
+		let mut var = Variance::new(4, 1).unwrap();
+		for x in &[10.0, 20.0, 30.0, 20.0, 10.0, 100.0] {
+			assert_eq!(round(cov.next((*x, *x))), round(var.next(*x)));
+		}
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut cov = Covariance::new(3).unwrap();
+		cov.next((1.0, 1.0));
+		cov.next((1.0, 1.0));
+		assert!(!cov.is_ready());
+		cov.next((1.0, 1.0));
+		assert!(cov.is_ready());
+
+		cov.reset();
+		assert!(!cov.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut cov = Covariance::new(3).unwrap();
+		assert_eq!(cov.next((1.0, 2.0)), 0.0);
+		assert_eq!(cov.next((2.0, 1.0)), -0.5);
+
+		cov.reset();
+		assert_eq!(cov.next((1.0, 2.0)), 0.0);
+		assert_eq!(cov.next((2.0, 1.0)), -0.5);
+	}
+
+	#[test]
+	fn test_default() {
+		Covariance::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let cov = Covariance::new(20).unwrap();
+		assert_eq!(format!("{}", cov), "COV(20)");
+	}
+}
@@ -0,0 +1,171 @@
+use core::fmt;
+
+use num_traits::Float;
+
+use crate::indicators::LogReturn;
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Cumulative return.
+///
+/// Returns the compound growth factor of the price since the first period, or since the
+/// last reset: 1.15 means a return of +15%, 0.9 a return of -10%. It can be used to draw an
+/// equity curve or to compare a strategy with a benchmark.
+///
+/// # Formula
+///
+/// CR = Π (1 + r<sub>i</sub>) = exp(Σ LR<sub>i</sub>)
+///
+/// Where:
+///
+/// * _r<sub>i</sub>_ - simple return of the _i_-th period
+/// * _LR<sub>i</sub>_ - [log return](struct.LogReturn.html) of the _i_-th period
+///
+/// The first period has no change yet, so 1 is returned. A period whose current or
+/// previous price is 0 or negative has no log return; it is skipped and the factor is
+/// carried forward.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::CumulativeReturn;
+/// use ta::Next;
+///
+/// let mut cr = CumulativeReturn::new();
+/// assert_eq!(cr.next(100.0), 1.0);
+/// assert_eq!(cr.next(100.0), 1.0);
+/// assert_eq!(cr.next(200.0), 2.0);
+/// ```
+///
+/// # Links
+///
+/// * [Rate of return, Wikipedia](https://en.wikipedia.org/wiki/Rate_of_return#Compounding_or_reinvesting)
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CumulativeReturn {
+	log_return: LogReturn,
+	sum: f64,
+}
+
+impl CumulativeReturn {
+	pub fn new() -> Self {
+		Self {
+			log_return: LogReturn::new(),
+			sum: 0.0,
+		}
+	}
+}
+
+impl Period for CumulativeReturn {
+	fn period(&self) -> usize {
+		1
+	}
+}
+
+impl Ready for CumulativeReturn {
+	fn is_ready(&self) -> bool {
+		self.log_return.is_ready()
+	}
+}
+
+impl Next<f64> for CumulativeReturn {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		let log_return = self.log_return.next(input);
+		if !log_return.is_nan() {
+			self.sum += log_return;
+		}
+
+		Float::exp(self.sum)
+	}
+}
+
+impl<T: Close> Next<&T> for CumulativeReturn {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl Reset for CumulativeReturn {
+	fn reset(&mut self) {
+		self.log_return.reset();
+		self.sum = 0.0;
+	}
+}
+
+impl Default for CumulativeReturn {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl fmt::Display for CumulativeReturn {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "CUM_RETURN")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	test_indicator!(CumulativeReturn);
+
+	#[test]
+	fn test_next() {
+		let mut cr = CumulativeReturn::new();
+		assert_eq!(cr.next(100.0), 1.0);
+		assert_eq!(round(cr.next(110.0)), 1.1);
+		assert_eq!(round(cr.next(99.0)), 0.99);
+		assert_eq!(round(cr.next(115.0)), 1.15);
+		assert_eq!(round(cr.next(50.0)), 0.5);
+	}
+
+	#[test]
+	fn test_next_zero_price() {
+		let mut cr = CumulativeReturn::new();
+		assert_eq!(cr.next(100.0), 1.0);
+		assert_eq!(round(cr.next(120.0)), 1.2);
+		assert_eq!(round(cr.next(0.0)), 1.2);
+		assert_eq!(round(cr.next(60.0)), 1.2);
+		assert_eq!(round(cr.next(90.0)), 1.8);
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut cr = CumulativeReturn::new();
+		assert!(!cr.is_ready());
+		cr.next(100.0);
+		assert!(cr.is_ready());
+
+		cr.reset();
+		assert!(!cr.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut cr = CumulativeReturn::new();
+		cr.next(100.0);
+		assert_eq!(round(cr.next(150.0)), 1.5);
+
+		cr.reset();
+		assert_eq!(cr.next(150.0), 1.0);
+		assert_eq!(round(cr.next(165.0)), 1.1);
+	}
+
+	#[test]
+	fn test_default() {
+		CumulativeReturn::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let cr = CumulativeReturn::new();
+		assert_eq!(format!("{}", cr), "CUM_RETURN");
+	}
+}
@@ -0,0 +1,225 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SimpleMovingAverage as Sma;
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Detrended price oscillator (DPO).
+///
+/// Removes the trend from the price by comparing a past price with the current SMA, which
+/// makes it easier to identify cycles. On a linear trend the DPO is constant.
+///
+/// # Formula
+///
+/// DPO = p<sub>t - (n / 2 + 1)</sub> - SMA(p, n)
+///
+/// Where:
+///
+/// * _p_ - input value
+/// * _n_ - period, _n / 2_ is rounded down
+///
+/// Until _n / 2 + 1_ values have been seen, the oldest available value is used as the past price.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::DetrendedPriceOscillator;
+/// use ta::Next;
+///
+/// let mut dpo = DetrendedPriceOscillator::new(4).unwrap();
+/// assert_eq!(dpo.next(2.0), 0.0);
+/// assert_eq!(dpo.next(4.0), -1.0);
+/// assert_eq!(dpo.next(6.0), -2.0);
+/// assert_eq!(dpo.next(8.0), -3.0);
+/// assert_eq!(dpo.next(10.0), -3.0);
+/// ```
+///
+/// # Links
+///
+/// * [Detrended Price Oscillator, stockcharts](https://school.stockcharts.com/doku.php?id=technical_indicators:detrended_price_osci)
+#[doc(alias = "DPO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DetrendedPriceOscillator {
+	period: usize,
+	index: usize,
+	count: usize,
+	sma: Sma,
+	deque: Box<[f64]>,
+}
+
+impl DetrendedPriceOscillator {
+	pub fn new(period: usize) -> Result<Self> {
+		Ok(Self {
+			period,
+			index: 0,
+			count: 0,
+			sma: Sma::new(period)?,
+			deque: vec![0.0; period / 2 + 1].into_boxed_slice(),
+		})
+	}
+}
+
+impl Period for DetrendedPriceOscillator {
+	fn period(&self) -> usize {
+		self.period
+	}
+}
+
+impl Ready for DetrendedPriceOscillator {
+	fn is_ready(&self) -> bool {
+		self.count > self.deque.len() && self.sma.is_ready()
+	}
+}
+
+impl Next<f64> for DetrendedPriceOscillator {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		let lag = self.deque.len();
+		let past = if self.count >= lag {
+			self.deque[self.index]
+		} else if self.count == 0 {
+			input
+		} else {
+			self.deque[0]
+		};
+
+		self.deque[self.index] = input;
+		self.index = if self.index + 1 < lag {
+			self.index + 1
+		} else {
+			0
+		};
+
+		if self.count <= lag {
+			self.count += 1;
+		}
+
+		past - self.sma.next(input)
+	}
+}
+
+impl<T: Close> Next<&T> for DetrendedPriceOscillator {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl Reset for DetrendedPriceOscillator {
+	fn reset(&mut self) {
+		self.index = 0;
+		self.count = 0;
+		self.sma.reset();
+		for i in 0..self.deque.len() {
+			self.deque[i] = 0.0;
+		}
+	}
+}
+
+impl Default for DetrendedPriceOscillator {
+	fn default() -> Self {
+		Self::new(20).unwrap()
+	}
+}
+
+impl fmt::Display for DetrendedPriceOscillator {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "DPO({})", self.period)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	type Dpo = DetrendedPriceOscillator;
+
+	test_indicator!(Dpo);
+
+	#[test]
+	fn test_new() {
+		assert!(Dpo::new(0).is_err());
+		assert!(Dpo::new(1).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let mut dpo = Dpo::new(4).unwrap();
+
+		assert_eq!(dpo.next(5.0), 0.0);
+		assert_eq!(dpo.next(7.0), -1.0);
+		assert_eq!(round(dpo.next(4.0)), -0.333);
+		assert_eq!(dpo.next(9.0), -1.25);
+		assert_eq!(dpo.next(6.0), 0.5);
+		assert_eq!(dpo.next(3.0), -1.5);
+		assert_eq!(dpo.next(8.0), 2.5);
+
+		let mut dpo = Dpo::new(4).unwrap();
+		let bar1 = Bar::new().close(5);
+		let bar2 = Bar::new().close(7);
+		assert_eq!(dpo.next(&bar1), 0.0);
+		assert_eq!(dpo.next(&bar2), -1.0);
+	}
+
+	#[test]
+	fn test_next_linear() {
+		// once ready, a linear trend is removed completely
+		let mut dpo = Dpo::new(6).unwrap();
+		let mut values = Vec::new();
+		for i in 0..20 {
+			let value = dpo.next(100.0 + 2.5 * i as f64);
+			if dpo.is_ready() {
+				values.push(value);
+			}
+		}
+		assert!(values.iter().all(|v| round(*v) == round(values[0])));
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut dpo = Dpo::new(4).unwrap();
+		for _ in 0..3 {
+			dpo.next(1.0);
+			assert!(!dpo.is_ready());
+		}
+		dpo.next(1.0);
+		assert!(dpo.is_ready());
+
+		dpo.reset();
+		assert!(!dpo.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut dpo = Dpo::new(4).unwrap();
+		assert_eq!(dpo.next(5.0), 0.0);
+		assert_eq!(dpo.next(7.0), -1.0);
+
+		dpo.reset();
+		assert_eq!(dpo.next(5.0), 0.0);
+		assert_eq!(dpo.next(7.0), -1.0);
+	}
+
+	#[test]
+	fn test_default() {
+		Dpo::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let indicator = Dpo::new(20).unwrap();
+		assert_eq!(format!("{}", indicator), "DPO(20)");
+	}
+}
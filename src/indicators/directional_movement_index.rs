@@ -0,0 +1,349 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::TrueRange;
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Directional Movement Index (DMI).
+///
+/// Developed by J. Welles Wilder, the DMI is made of three lines. The positive and negative
+/// directional indicators (+DI and -DI) show the direction of the trend, while the average
+/// directional index (ADX) shows its strength regardless of the direction. All three range
+/// from 0 to 100.
+///
+/// # Formula
+///
+/// +DM = H<sub>t</sub> - H<sub>t-1</sub> if it is greater than L<sub>t-1</sub> - L<sub>t</sub> and 0, otherwise 0
+///
+/// -DM = L<sub>t-1</sub> - L<sub>t</sub> if it is greater than H<sub>t</sub> - H<sub>t-1</sub> and 0, otherwise 0
+///
+/// +DI = 100 × S(+DM) / S(TR)
+///
+/// -DI = 100 × S(-DM) / S(TR)
+///
+/// DX = 100 × |+DI - -DI| / (+DI + -DI)
+///
+/// ADX = S(DX)
+///
+/// Where:
+///
+/// * _S_ - Wilder's smoothing, an exponential moving average with α = 1 / n, seeded with the first value
+/// * _TR_ - [true range](struct.TrueRange.html)
+/// * _H<sub>t</sub>_, _L<sub>t</sub>_ - high and low prices of the current period
+///
+/// The first period has no previous prices, so 0 is returned for all three lines. If the
+/// smoothed true range is 0 both directional indicators are 0, and if they are both 0 the
+/// DX is 0.
+///
+/// # Parameters
+///
+/// * _period_ - smoothing period (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::DirectionalMovementIndex;
+/// use ta::{DataItem, Next};
+///
+/// let mut dmi = DirectionalMovementIndex::new(3).unwrap();
+///
+/// let di1 = DataItem::builder()
+///             .high(10.0)
+///             .low(8.0)
+///             .close(9.0)
+///             .open(9.0)
+///             .volume(1000.0)
+///             .build().unwrap();
+///
+/// let di2 = DataItem::builder()
+///             .high(11.0)
+///             .low(9.0)
+///             .close(10.5)
+///             .open(9.0)
+///             .volume(1000.0)
+///             .build().unwrap();
+///
+/// dmi.next(&di1);
+/// let out = dmi.next(&di2);
+/// assert_eq!(out.plus_di, 50.0);
+/// assert_eq!(out.minus_di, 0.0);
+/// assert_eq!(out.adx, 100.0);
+/// ```
+///
+/// # Links
+///
+/// * [Average directional movement index, Wikipedia](https://en.wikipedia.org/wiki/Average_directional_movement_index)
+#[doc(alias = "DMI")]
+#[doc(alias = "ADX")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DirectionalMovementIndex {
+	period: usize,
+	count: usize,
+	true_range: TrueRange,
+	prev: Option<(f64, f64)>,
+	plus_dm: f64,
+	minus_dm: f64,
+	tr: f64,
+	adx: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DirectionalMovementIndexOutput {
+	pub plus_di: f64,
+	pub minus_di: f64,
+	pub adx: f64,
+}
+
+impl From<DirectionalMovementIndexOutput> for (f64, f64, f64) {
+	fn from(dmi: DirectionalMovementIndexOutput) -> Self {
+		(dmi.plus_di, dmi.minus_di, dmi.adx)
+	}
+}
+
+impl DirectionalMovementIndex {
+	pub fn new(period: usize) -> Result<Self> {
+		match period {
+			0 => Err(TaError::InvalidParameter),
+			_ => Ok(Self {
+				period,
+				count: 0,
+				true_range: TrueRange::new(),
+				prev: None,
+				plus_dm: 0.0,
+				minus_dm: 0.0,
+				tr: 0.0,
+				adx: 0.0,
+			}),
+		}
+	}
+
+	fn ready_count(&self) -> usize {
+		// n periods of directional movement for the DIs, then n - 1 more for the ADX
+		2 * self.period - 1
+	}
+
+	fn smooth(&self, current: f64, input: f64) -> f64 {
+		if self.count == 1 {
+			input
+		} else {
+			current + (input - current) / self.period as f64
+		}
+	}
+}
+
+impl Period for DirectionalMovementIndex {
+	fn period(&self) -> usize {
+		self.period
+	}
+}
+
+impl Ready for DirectionalMovementIndex {
+	fn is_ready(&self) -> bool {
+		self.count >= self.ready_count()
+	}
+}
+
+impl<T: High + Low + Close> Next<&T> for DirectionalMovementIndex {
+	type Output = DirectionalMovementIndexOutput;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		let (high, low) = (input.high(), input.low());
+		let tr = self.true_range.next(input);
+
+		let (prev_high, prev_low) = match self.prev.replace((high, low)) {
+			Some(prev) => prev,
+			None => {
+				return DirectionalMovementIndexOutput {
+					plus_di: 0.0,
+					minus_di: 0.0,
+					adx: 0.0,
+				}
+			}
+		};
+
+		let up_move = high - prev_high;
+		let down_move = prev_low - low;
+		let plus_dm = if up_move > down_move && up_move > 0.0 {
+			up_move
+		} else {
+			0.0
+		};
+		let minus_dm = if down_move > up_move && down_move > 0.0 {
+			down_move
+		} else {
+			0.0
+		};
+
+		if self.count < self.ready_count() {
+			self.count += 1;
+		}
+		self.plus_dm = self.smooth(self.plus_dm, plus_dm);
+		self.minus_dm = self.smooth(self.minus_dm, minus_dm);
+		self.tr = self.smooth(self.tr, tr);
+
+		let (plus_di, minus_di) = if self.tr == 0.0 {
+			(0.0, 0.0)
+		} else {
+			(
+				100.0 * self.plus_dm / self.tr,
+				100.0 * self.minus_dm / self.tr,
+			)
+		};
+
+		let di_sum = plus_di + minus_di;
+		let dx = if di_sum == 0.0 {
+			0.0
+		} else {
+			100.0 * (plus_di - minus_di).abs() / di_sum
+		};
+		self.adx = self.smooth(self.adx, dx);
+
+		DirectionalMovementIndexOutput {
+			plus_di,
+			minus_di,
+			adx: self.adx,
+		}
+	}
+}
+
+impl Reset for DirectionalMovementIndex {
+	fn reset(&mut self) {
+		self.count = 0;
+		self.true_range.reset();
+		self.prev = None;
+		self.plus_dm = 0.0;
+		self.minus_dm = 0.0;
+		self.tr = 0.0;
+		self.adx = 0.0;
+	}
+}
+
+impl Default for DirectionalMovementIndex {
+	fn default() -> Self {
+		Self::new(14).unwrap()
+	}
+}
+
+impl fmt::Display for DirectionalMovementIndex {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "DMI({})", self.period)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	#[test]
+	fn test_new() {
+		assert!(DirectionalMovementIndex::new(0).is_err());
+		assert!(DirectionalMovementIndex::new(1).is_ok());
+	}
+
+	#[test]
+	fn test_next_bar() {
+		let test_data = vec![
+			// high, low, close, +di, -di, adx
+			(10.0, 8.0, 9.0, 0.0, 0.0, 0.0),
+			(11.0, 9.0, 10.5, 50.0, 0.0, 100.0),
+			(12.0, 10.0, 11.0, 50.0, 0.0, 100.0),
+			(11.0, 8.0, 9.0, 28.571, 28.571, 66.667),
+			(10.0, 7.0, 7.5, 17.391, 30.435, 53.535),
+			(9.0, 7.0, 8.5, 12.5, 21.875, 44.781),
+			(10.5, 8.5, 10.0, 31.044, 15.385, 41.097),
+		];
+
+		let mut dmi = DirectionalMovementIndex::new(3).unwrap();
+
+		for (high, low, close, plus_di, minus_di, adx) in test_data {
+			let bar = Bar::new().high(high).low(low).close(close);
+			let out = dmi.next(&bar);
+			assert_eq!(round(out.plus_di), plus_di);
+			assert_eq!(round(out.minus_di), minus_di);
+			assert_eq!(round(out.adx), adx);
+		}
+	}
+
+	#[test]
+	fn test_next_rising() {
+		let mut dmi = DirectionalMovementIndex::new(5).unwrap();
+		for i in 0..20 {
+			let close = 10.0 + i as f64;
+			let bar = Bar::new().high(close + 0.5).low(close - 1.0).close(close);
+			let out = dmi.next(&bar);
+			if dmi.is_ready() {
+				assert!(out.plus_di > out.minus_di);
+			}
+		}
+		assert!(dmi.is_ready());
+	}
+
+	#[test]
+	fn test_next_falling() {
+		let mut dmi = DirectionalMovementIndex::new(5).unwrap();
+		for i in 0..20 {
+			let close = 30.0 - i as f64;
+			let bar = Bar::new().high(close + 1.0).low(close - 0.5).close(close);
+			let out = dmi.next(&bar);
+			if dmi.is_ready() {
+				assert!(out.minus_di > out.plus_di);
+				assert!(out.adx > 0.0);
+			}
+		}
+		assert!(dmi.is_ready());
+	}
+
+	#[test]
+	fn test_next_flat() {
+		let mut dmi = DirectionalMovementIndex::new(3).unwrap();
+		let bar = Bar::new().high(5).low(5).close(5);
+		for _ in 0..5 {
+			let (plus_di, minus_di, adx) = dmi.next(&bar).into();
+			assert_eq!((plus_di, minus_di, adx), (0.0, 0.0, 0.0));
+		}
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut dmi = DirectionalMovementIndex::new(3).unwrap();
+		let bar = Bar::new().high(5).low(4).close(5);
+		for _ in 0..5 {
+			dmi.next(&bar);
+			assert!(!dmi.is_ready());
+		}
+		dmi.next(&bar);
+		assert!(dmi.is_ready());
+
+		dmi.reset();
+		assert!(!dmi.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut dmi = DirectionalMovementIndex::new(3).unwrap();
+		let bar1 = Bar::new().high(10).low(8).close(9);
+		let bar2 = Bar::new().high(11).low(9).close(10.5);
+
+		dmi.next(&bar1);
+		assert_eq!(dmi.next(&bar2).plus_di, 50.0);
+
+		dmi.reset();
+		assert_eq!(dmi.next(&bar2).plus_di, 0.0);
+		assert_eq!(dmi.next(&bar1).minus_di, 40.0);
+	}
+
+	#[test]
+	fn test_default() {
+		DirectionalMovementIndex::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let indicator = DirectionalMovementIndex::new(14).unwrap();
+		assert_eq!(format!("{}", indicator), "DMI(14)");
+	}
+}
@@ -0,0 +1,182 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{Maximum, Minimum};
+use crate::{High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Donchian Channel (DC).
+///
+/// The channel formed by the highest high and the lowest low of the last _n_ periods.
+///
+/// # Formula
+///
+/// * _upper_ = highest high of the last _n_ periods
+/// * _lower_ = lowest low of the last _n_ periods
+/// * _middle_ = (upper + lower) / 2
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::DonchianChannel;
+/// use ta::Next;
+///
+/// let mut dc = DonchianChannel::new(2).unwrap();
+///
+/// let out = dc.next(4.0);
+/// assert_eq!((out.upper, out.middle, out.lower), (4.0, 4.0, 4.0));
+///
+/// let out = dc.next(8.0);
+/// assert_eq!((out.upper, out.middle, out.lower), (8.0, 6.0, 4.0));
+/// ```
+///
+/// # Links
+///
+/// * [Donchian channel, Wikipedia](https://en.wikipedia.org/wiki/Donchian_channel)
+#[doc(alias = "DC")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DonchianChannel {
+	period: usize,
+	minimum: Minimum,
+	maximum: Maximum,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DonchianChannelOutput {
+	pub upper: f64,
+	pub middle: f64,
+	pub lower: f64,
+}
+
+impl DonchianChannel {
+	pub fn new(period: usize) -> Result<Self> {
+		Ok(Self {
+			period,
+			minimum: Minimum::new(period)?,
+			maximum: Maximum::new(period)?,
+		})
+	}
+
+	fn calc(&mut self, high: f64, low: f64) -> DonchianChannelOutput {
+		let upper = self.maximum.next(high);
+		let lower = self.minimum.next(low);
+
+		DonchianChannelOutput {
+			upper,
+			middle: (upper + lower) / 2.0,
+			lower,
+		}
+	}
+}
+
+impl Period for DonchianChannel {
+	fn period(&self) -> usize {
+		self.period
+	}
+}
+
+impl Ready for DonchianChannel {
+	fn is_ready(&self) -> bool {
+		self.minimum.is_ready()
+	}
+}
+
+impl Next<f64> for DonchianChannel {
+	type Output = DonchianChannelOutput;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		self.calc(input, input)
+	}
+}
+
+impl<T: High + Low> Next<&T> for DonchianChannel {
+	type Output = DonchianChannelOutput;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.calc(input.high(), input.low())
+	}
+}
+
+impl Reset for DonchianChannel {
+	fn reset(&mut self) {
+		self.minimum.reset();
+		self.maximum.reset();
+	}
+}
+
+impl Default for DonchianChannel {
+	fn default() -> Self {
+		Self::new(20).unwrap()
+	}
+}
+
+impl fmt::Display for DonchianChannel {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "DC({})", self.period)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	test_indicator!(DonchianChannel);
+
+	#[test]
+	fn test_new() {
+		assert!(DonchianChannel::new(0).is_err());
+		assert!(DonchianChannel::new(1).is_ok());
+	}
+
+	#[test]
+	fn test_next_with_bars() {
+		let test_data = vec![
+			// high, low, (upper, middle, lower)
+			(10.0, 6.0, (10.0, 8.0, 6.0)),
+			(12.0, 7.0, (12.0, 9.0, 6.0)),
+			(11.0, 5.0, (12.0, 8.5, 5.0)),
+			(9.0, 8.0, (12.0, 8.5, 5.0)),
+			(8.0, 7.0, (11.0, 8.0, 5.0)),
+			(9.0, 8.5, (9.0, 8.0, 7.0)),
+			(9.5, 8.0, (9.5, 8.25, 7.0)),
+		];
+
+		let mut dc = DonchianChannel::new(3).unwrap();
+
+		for (high, low, expected) in test_data {
+			let bar = Bar::new().high(high).low(low);
+			let out = dc.next(&bar);
+			assert_eq!((out.upper, out.middle, out.lower), expected);
+		}
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut dc = DonchianChannel::new(3).unwrap();
+		dc.next(4.0);
+		dc.next(8.0);
+		assert!(!dc.is_ready());
+
+		dc.reset();
+		let out = dc.next(2.0);
+		assert_eq!((out.upper, out.middle, out.lower), (2.0, 2.0, 2.0));
+	}
+
+	#[test]
+	fn test_default() {
+		DonchianChannel::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let indicator = DonchianChannel::new(20).unwrap();
+		assert_eq!(format!("{}", indicator), "DC(20)");
+	}
+}
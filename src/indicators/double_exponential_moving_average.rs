@@ -0,0 +1,213 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Double exponential moving average (DEMA).
+///
+/// Reduces the lag of an EMA by subtracting the EMA of the EMA from twice the EMA.
+///
+/// # Formula
+///
+/// DEMA = 2 × EMA(p, n) - EMA(EMA(p, n), n)
+///
+/// Where:
+///
+/// * _p_ - input value
+/// * _n_ - period
+///
+/// The DEMA is ready once _2 × (n - 1) + 1_ values have been consumed.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::DoubleExponentialMovingAverage;
+/// use ta::Next;
+///
+/// let mut dema = DoubleExponentialMovingAverage::new(3).unwrap();
+/// assert_eq!(dema.next(2.0), 2.0);
+/// assert_eq!(dema.next(5.0), 4.25);
+/// assert_eq!(dema.next(1.0), 2.0);
+/// assert_eq!(dema.next(6.25), 5.125);
+/// ```
+///
+/// # Links
+///
+/// * [Double exponential moving average, Wikipedia](https://en.wikipedia.org/wiki/Double_exponential_moving_average)
+#[doc(alias = "DEMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DoubleExponentialMovingAverage {
+	period: usize,
+	count: usize,
+	ema1: Ema,
+	ema2: Ema,
+}
+
+impl DoubleExponentialMovingAverage {
+	pub fn new(period: usize) -> Result<Self> {
+		Ok(Self {
+			period,
+			count: 0,
+			ema1: Ema::new(period)?,
+			ema2: Ema::new(period)?,
+		})
+	}
+}
+
+impl Period for DoubleExponentialMovingAverage {
+	fn period(&self) -> usize {
+		self.period
+	}
+}
+
+impl Ready for DoubleExponentialMovingAverage {
+	fn is_ready(&self) -> bool {
+		self.count == 2 * (self.period - 1) + 1
+	}
+}
+
+impl Next<f64> for DoubleExponentialMovingAverage {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		if self.count < 2 * (self.period - 1) + 1 {
+			self.count += 1;
+		}
+
+		let ema1 = self.ema1.next(input);
+		let ema2 = self.ema2.next(ema1);
+		2.0 * ema1 - ema2
+	}
+}
+
+impl<T: Close> Next<&T> for DoubleExponentialMovingAverage {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl Reset for DoubleExponentialMovingAverage {
+	fn reset(&mut self) {
+		self.count = 0;
+		self.ema1.reset();
+		self.ema2.reset();
+	}
+}
+
+impl Default for DoubleExponentialMovingAverage {
+	fn default() -> Self {
+		Self::new(9).unwrap()
+	}
+}
+
+impl fmt::Display for DoubleExponentialMovingAverage {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "DEMA({})", self.period)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	type Dema = DoubleExponentialMovingAverage;
+
+	test_indicator!(Dema);
+
+	#[test]
+	fn test_new() {
+		assert!(Dema::new(0).is_err());
+		assert!(Dema::new(1).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let mut dema = Dema::new(3).unwrap();
+
+		assert_eq!(dema.next(2.0), 2.0);
+		assert_eq!(dema.next(5.0), 4.25);
+		assert_eq!(dema.next(1.0), 2.0);
+		assert_eq!(dema.next(6.25), 5.125);
+
+		let mut dema = Dema::new(3).unwrap();
+		let bar1 = Bar::new().close(2);
+		let bar2 = Bar::new().close(5);
+		assert_eq!(dema.next(&bar1), 2.0);
+		assert_eq!(dema.next(&bar2), 4.25);
+	}
+
+	#[test]
+	fn test_next_step() {
+		// DEMA gets close to a new level faster than the EMA
+		let mut dema = Dema::new(5).unwrap();
+		let mut ema = Ema::new(5).unwrap();
+		dema.next(0.0);
+		ema.next(0.0);
+
+		for _ in 0..3 {
+			let dema_val = dema.next(10.0);
+			let ema_val = ema.next(10.0);
+			assert!(10.0 - dema_val < 10.0 - ema_val);
+		}
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut dema = Dema::new(3).unwrap();
+		for _ in 0..4 {
+			dema.next(1.0);
+			assert!(!dema.is_ready());
+		}
+		dema.next(1.0);
+		assert!(dema.is_ready());
+
+		dema.reset();
+		assert!(!dema.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut dema = Dema::new(3).unwrap();
+		assert_eq!(dema.next(2.0), 2.0);
+		assert_eq!(dema.next(5.0), 4.25);
+
+		dema.reset();
+		assert_eq!(dema.next(2.0), 2.0);
+		assert_eq!(dema.next(5.0), 4.25);
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn test_serde() {
+		let mut dema = Dema::new(3).unwrap();
+		dema.next(2.0);
+		dema.next(5.0);
+
+		let bytes = bincode::serialize(&dema).unwrap();
+		let mut restored: Dema = bincode::deserialize(&bytes).unwrap();
+		assert_eq!(restored.next(1.0), dema.next(1.0));
+		assert_eq!(restored.next(6.25), dema.next(6.25));
+	}
+
+	#[test]
+	fn test_default() {
+		Dema::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let indicator = Dema::new(7).unwrap();
+		assert_eq!(format!("{}", indicator), "DEMA(7)");
+	}
+}
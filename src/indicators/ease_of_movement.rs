@@ -0,0 +1,204 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SimpleMovingAverage as Sma;
+use crate::{High, Low, Next, Period, Ready, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Ease of Movement (EOM).
+///
+/// Relates the price change to the volume. A positive value means the price is rising on
+/// low volume (easy upward movement), a negative value that the price is falling easily.
+///
+/// # Formula
+///
+/// EOM<sub>raw</sub> = (M<sub>t</sub> - M<sub>t-1</sub>) × (H<sub>t</sub> - L<sub>t</sub>) / V<sub>t</sub>
+///
+/// EOM = SMA(EOM<sub>raw</sub>, n)
+///
+/// Where:
+///
+/// * _M<sub>t</sub>_ - midpoint of the current period, (H<sub>t</sub> + L<sub>t</sub>) / 2
+/// * _H<sub>t</sub>_, _L<sub>t</sub>_ - high and low prices of the current period
+/// * _V<sub>t</sub>_ - volume of the current period
+///
+/// The first period has no previous midpoint, so 0 is returned. The raw value of a period
+/// with a volume of 0 or with equal high and low is 0. With a period of 1 the raw EOM is
+/// returned.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods of the smoothing SMA (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::EaseOfMovement;
+/// use ta::{DataItem, Next};
+///
+/// let mut eom = EaseOfMovement::new(1).unwrap();
+///
+/// let di1 = DataItem::builder()
+///             .high(10.0)
+///             .low(8.0)
+///             .close(9.0)
+///             .open(9.0)
+///             .volume(100.0)
+///             .build().unwrap();
+///
+/// let di2 = DataItem::builder()
+///             .high(12.0)
+///             .low(10.0)
+///             .close(11.0)
+///             .open(10.0)
+///             .volume(200.0)
+///             .build().unwrap();
+///
+/// assert_eq!(eom.next(&di1), 0.0);
+/// assert_eq!(eom.next(&di2), 0.02);
+/// ```
+///
+/// # Links
+///
+/// * [Ease of movement, Wikipedia](https://en.wikipedia.org/wiki/Ease_of_movement)
+#[doc(alias = "EOM")]
+#[doc(alias = "EMV")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EaseOfMovement {
+	sma: Sma,
+	prev: Option<(f64, f64)>,
+}
+
+impl EaseOfMovement {
+	pub fn new(period: usize) -> Result<Self> {
+		Ok(Self {
+			sma: Sma::new(period)?,
+			prev: None,
+		})
+	}
+}
+
+impl Period for EaseOfMovement {
+	fn period(&self) -> usize {
+		self.sma.period()
+	}
+}
+
+impl Ready for EaseOfMovement {
+	fn is_ready(&self) -> bool {
+		self.sma.is_ready()
+	}
+}
+
+impl<T: High + Low + Volume> Next<&T> for EaseOfMovement {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		let (high, low, volume) = (input.high(), input.low(), input.volume());
+		let (prev_high, prev_low) = match self.prev.replace((high, low)) {
+			Some(prev) => prev,
+			None => return 0.0,
+		};
+
+		let eom = if volume == 0.0 || high == low {
+			0.0
+		} else {
+			((high + low) / 2.0 - (prev_high + prev_low) / 2.0) * (high - low) / volume
+		};
+
+		self.sma.next(eom)
+	}
+}
+
+impl Reset for EaseOfMovement {
+	fn reset(&mut self) {
+		self.sma.reset();
+		self.prev = None;
+	}
+}
+
+impl Default for EaseOfMovement {
+	fn default() -> Self {
+		Self::new(14).unwrap()
+	}
+}
+
+impl fmt::Display for EaseOfMovement {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "EOM({})", self.sma.period())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	#[test]
+	fn test_new() {
+		assert!(EaseOfMovement::new(0).is_err());
+		assert!(EaseOfMovement::new(1).is_ok());
+	}
+
+	#[test]
+	fn test_next_bar() {
+		let test_data = vec![
+			// high, low, volume, raw, smoothed
+			(10.0, 8.0, 100.0, 0.0, 0.0),
+			(12.0, 10.0, 200.0, 0.02, 0.02),
+			(11.0, 9.0, 0.0, 0.0, 0.01),
+			(11.0, 11.0, 100.0, 0.0, 0.0),
+			(13.0, 11.0, 400.0, 0.005, 0.0025),
+		];
+
+		let mut raw = EaseOfMovement::new(1).unwrap();
+		let mut smoothed = EaseOfMovement::new(2).unwrap();
+
+		for (high, low, volume, expected_raw, expected_smoothed) in test_data {
+			let bar = Bar::new().high(high).low(low).volume(volume);
+			assert_eq!(raw.next(&bar), expected_raw);
+			assert_eq!(smoothed.next(&bar), expected_smoothed);
+		}
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut eom = EaseOfMovement::new(2).unwrap();
+		let bar = Bar::new().high(5).low(4).volume(100.0);
+		eom.next(&bar);
+		eom.next(&bar);
+		assert!(!eom.is_ready());
+		eom.next(&bar);
+		assert!(eom.is_ready());
+
+		eom.reset();
+		assert!(!eom.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut eom = EaseOfMovement::new(1).unwrap();
+		let bar1 = Bar::new().high(10).low(8).volume(100.0);
+		let bar2 = Bar::new().high(12).low(10).volume(200.0);
+
+		assert_eq!(eom.next(&bar1), 0.0);
+		assert_eq!(eom.next(&bar2), 0.02);
+
+		eom.reset();
+		assert_eq!(eom.next(&bar2), 0.0);
+		assert_eq!(eom.next(&bar1), -0.04);
+	}
+
+	#[test]
+	fn test_default() {
+		EaseOfMovement::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let indicator = EaseOfMovement::new(14).unwrap();
+		assert_eq!(format!("{}", indicator), "EOM(14)");
+	}
+}
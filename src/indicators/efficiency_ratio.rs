@@ -1,7 +1,10 @@
-use std::fmt;
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::traits::{Close, Next, Period, Reset};
+use crate::indicators::RollingSum;
+use crate::traits::{Close, Next, Period, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -10,6 +13,19 @@ use serde::{Deserialize, Serialize};
 /// It is calculated by dividing the price change over a period by the absolute sum of the price movements that occurred to achieve that change.
 /// The resulting ratio ranges between 0.0 and 1.0 with higher values representing a more efficient or trending market.
 ///
+/// # Formula
+///
+/// ER = |p<sub>t</sub> - p<sub>t-n</sub>| / Σ |p<sub>i</sub> - p<sub>i-1</sub>|
+///
+/// Where:
+///
+/// * _p<sub>t</sub>_ - input value at a point of time _t_
+/// * _n_ - number of periods, the sum is taken over the last _n_ price movements
+///
+/// The window holds the last _n + 1_ prices, so the ER is ready once _n + 1_ values have
+/// been consumed. Until then, it is computed from the first price. If the prices have not moved
+/// within the window, 0 is returned.
+///
 /// # Parameters
 ///
 /// * _period_ - number of periods (integer greater than 0)
@@ -20,21 +36,21 @@ use serde::{Deserialize, Serialize};
 /// use ta::indicators::EfficiencyRatio;
 /// use ta::Next;
 ///
-/// let mut er = EfficiencyRatio::new(4).unwrap();
-/// assert_eq!(er.next(10.0), 1.0);
+/// let mut er = EfficiencyRatio::new(3).unwrap();
+/// assert_eq!(er.next(10.0), 0.0);
 /// assert_eq!(er.next(13.0), 1.0);
 /// assert_eq!(er.next(12.0), 0.5);
 /// assert_eq!(er.next(13.0), 0.6);
-/// assert_eq!(er.next(18.0), 0.8);
-/// assert_eq!(er.next(19.0), 0.75);
+/// assert_eq!(er.next(16.0), 0.6);
 /// ```
 #[doc(alias = "ER")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct EfficiencyRatio {
 	period: usize,
 	index: usize,
 	count: usize,
+	volatility: RollingSum,
 	deque: Box<[f64]>,
 }
 
@@ -46,7 +62,8 @@ impl EfficiencyRatio {
 				period,
 				index: 0,
 				count: 0,
-				deque: vec![0.0; period].into_boxed_slice(),
+				volatility: RollingSum::new(period)?,
+				deque: vec![0.0; period + 1].into_boxed_slice(),
 			}),
 		}
 	}
@@ -58,33 +75,41 @@ impl Period for EfficiencyRatio {
 	}
 }
 
+impl Ready for EfficiencyRatio {
+	fn is_ready(&self) -> bool {
+		self.count == self.period + 1
+	}
+}
+
 impl Next<f64> for EfficiencyRatio {
 	type Output = f64;
 
 	fn next(&mut self, input: f64) -> f64 {
-		let first = if self.count >= self.period {
-			self.deque[self.index]
-		} else {
-			self.count += 1;
-			self.deque[0]
-		};
-		self.deque[self.index] = input;
+		if self.count == 0 {
+			// the window starts at the first price
+			for price in self.deque.iter_mut() {
+				*price = input;
+			}
+		}
 
-		self.index = if self.index + 1 < self.period {
+		let previous = self.deque[(self.index + self.period) % (self.period + 1)];
+		self.deque[self.index] = input;
+		self.index = if self.index < self.period {
 			self.index + 1
 		} else {
 			0
 		};
-
-		let mut volatility = 0.0;
-		let mut previous = first;
-		for n in &self.deque[self.index..self.count] {
-			volatility += (previous - n).abs();
-			previous = *n;
+		if self.count <= self.period {
+			self.count += 1;
 		}
-		for n in &self.deque[0..self.index] {
-			volatility += (previous - n).abs();
-			previous = *n;
+
+		// the price n periods ago
+		let first = self.deque[self.index];
+		let volatility = self.volatility.next((input - previous).abs());
+
+		if volatility == 0.0 {
+			// A flat series has no trend
+			return 0.0;
 		}
 
 		(first - input).abs() / volatility
@@ -103,7 +128,8 @@ impl Reset for EfficiencyRatio {
 	fn reset(&mut self) {
 		self.index = 0;
 		self.count = 0;
-		for i in 0..self.period {
+		self.volatility.reset();
+		for i in 0..=self.period {
 			self.deque[i] = 0.0;
 		}
 	}
@@ -138,7 +164,7 @@ mod tests {
 	fn test_next() {
 		let mut er = EfficiencyRatio::new(3).unwrap();
 
-		assert_eq!(round(er.next(3.0)), 1.0);
+		assert_eq!(round(er.next(3.0)), 0.0);
 		assert_eq!(round(er.next(5.0)), 1.0);
 		assert_eq!(round(er.next(2.0)), 0.2);
 		assert_eq!(round(er.next(3.0)), 0.0);
@@ -148,6 +174,45 @@ mod tests {
 		assert_eq!(round(er.next(6.0)), 1.0);
 	}
 
+	#[test]
+	fn test_next_flat() {
+		let mut er = EfficiencyRatio::new(3).unwrap();
+		for _ in 0..5 {
+			assert_eq!(er.next(0.0), 0.0);
+		}
+
+		let mut er = EfficiencyRatio::new(3).unwrap();
+		er.next(2.0);
+		assert_eq!(er.next(4.0), 1.0);
+		assert_eq!(er.next(4.0), 1.0);
+		// 2 leaves the window
+		assert_eq!(er.next(4.0), 1.0);
+		assert_eq!(er.next(4.0), 0.0);
+	}
+
+	#[test]
+	fn test_next_first_price() {
+		// the window starts at the first price, not at 0
+		let mut er = EfficiencyRatio::new(3).unwrap();
+		assert_eq!(er.next(100.0), 0.0);
+		assert_eq!(er.next(100.0), 0.0);
+		assert_eq!(er.next(101.0), 1.0);
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut er = EfficiencyRatio::new(3).unwrap();
+		for price in &[1.0, 2.0, 3.0] {
+			er.next(*price);
+			assert!(!er.is_ready());
+		}
+		er.next(4.0);
+		assert!(er.is_ready());
+
+		er.reset();
+		assert!(!er.is_ready());
+	}
+
 	#[test]
 	fn test_reset() {
 		let mut er = EfficiencyRatio::new(3).unwrap();
@@ -157,7 +222,7 @@ mod tests {
 
 		er.reset();
 
-		assert_eq!(round(er.next(3.0)), 1.0);
+		assert_eq!(round(er.next(3.0)), 0.0);
 		assert_eq!(round(er.next(5.0)), 1.0);
 		assert_eq!(round(er.next(2.0)), 0.2);
 		assert_eq!(round(er.next(3.0)), 0.0);
@@ -0,0 +1,222 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Elder Ray Index.
+///
+/// Measures the buying and selling pressure as the distance of the high and the low
+/// from an EMA of the close. Both powers oscillate around zero.
+///
+/// # Formula
+///
+/// Bull Power = H<sub>t</sub> - EMA(C, n)
+///
+/// Bear Power = L<sub>t</sub> - EMA(C, n)
+///
+/// Where:
+///
+/// * _H<sub>t</sub>_ - high price of the current period
+/// * _L<sub>t</sub>_ - low price of the current period
+/// * _EMA(C, n)_ - [exponential moving average](struct.ExponentialMovingAverage.html) of the close
+///
+/// A positive bull power means the high exceeds the EMA, a negative bear power means the
+/// low is below it.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods of the EMA (integer greater than 0). Default is 13.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ElderRay;
+/// use ta::{DataItem, Next};
+///
+/// let mut elder_ray = ElderRay::new(3).unwrap();
+///
+/// let di = DataItem::builder()
+///             .high(11.0)
+///             .low(9.0)
+///             .close(10.0)
+///             .open(10.0)
+///             .volume(100.0)
+///             .build().unwrap();
+///
+/// let out = elder_ray.next(&di);
+/// assert_eq!(out.bull_power, 1.0);
+/// assert_eq!(out.bear_power, -1.0);
+/// ```
+///
+/// # Links
+///
+/// * [Elder-Ray Index, investopedia](https://www.investopedia.com/terms/e/elderray.asp)
+#[doc(alias = "ERI")]
+#[doc(alias = "Bull Power")]
+#[doc(alias = "Bear Power")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElderRay {
+	ema: Ema,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElderRayOutput {
+	pub bull_power: f64,
+	pub bear_power: f64,
+}
+
+impl From<ElderRayOutput> for (f64, f64) {
+	fn from(er: ElderRayOutput) -> Self {
+		(er.bull_power, er.bear_power)
+	}
+}
+
+impl ElderRay {
+	pub fn new(period: usize) -> Result<Self> {
+		Ok(Self {
+			ema: Ema::new(period)?,
+		})
+	}
+
+	fn calc(&mut self, high: f64, low: f64, close: f64) -> ElderRayOutput {
+		let ema = self.ema.next(close);
+
+		ElderRayOutput {
+			bull_power: high - ema,
+			bear_power: low - ema,
+		}
+	}
+}
+
+impl Period for ElderRay {
+	fn period(&self) -> usize {
+		self.ema.period()
+	}
+}
+
+impl Ready for ElderRay {
+	fn is_ready(&self) -> bool {
+		self.ema.is_ready()
+	}
+}
+
+impl Next<f64> for ElderRay {
+	type Output = ElderRayOutput;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		self.calc(input, input, input)
+	}
+}
+
+impl<T: High + Low + Close> Next<&T> for ElderRay {
+	type Output = ElderRayOutput;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.calc(input.high(), input.low(), input.close())
+	}
+}
+
+impl Reset for ElderRay {
+	fn reset(&mut self) {
+		self.ema.reset();
+	}
+}
+
+impl Default for ElderRay {
+	fn default() -> Self {
+		Self::new(13).unwrap()
+	}
+}
+
+impl fmt::Display for ElderRay {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "ERI({})", self.ema.period())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	test_indicator!(ElderRay);
+
+	#[test]
+	fn test_new() {
+		assert!(ElderRay::new(0).is_err());
+		assert!(ElderRay::new(1).is_ok());
+	}
+
+	#[test]
+	fn test_next_with_bars() {
+		let test_data = vec![
+			// high, low, close, bull power, bear power
+			(11.0, 9.0, 10.0, 1.0, -1.0),
+			(14.0, 12.0, 13.0, 2.5, 0.5),
+			(12.0, 10.0, 11.0, 0.75, -1.25),
+		];
+
+		let mut elder_ray = ElderRay::new(3).unwrap();
+
+		for (high, low, close, bull_power, bear_power) in test_data {
+			let bar = Bar::new().high(high).low(low).close(close);
+			let out = elder_ray.next(&bar);
+			assert_eq!(out.bull_power, bull_power);
+			assert_eq!(out.bear_power, bear_power);
+		}
+	}
+
+	#[test]
+	fn test_next() {
+		let mut elder_ray = ElderRay::new(3).unwrap();
+
+		let (bull_power, bear_power) = elder_ray.next(10.0).into();
+		assert_eq!(bull_power, 0.0);
+		assert_eq!(bear_power, 0.0);
+
+		let (bull_power, bear_power) = elder_ray.next(13.0).into();
+		assert_eq!(bull_power, 1.5);
+		assert_eq!(bear_power, 1.5);
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut elder_ray = ElderRay::new(3).unwrap();
+		elder_ray.next(1.0);
+		elder_ray.next(1.0);
+		assert!(!elder_ray.is_ready());
+		elder_ray.next(1.0);
+		assert!(elder_ray.is_ready());
+
+		elder_ray.reset();
+		assert!(!elder_ray.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut elder_ray = ElderRay::new(3).unwrap();
+		let bar1 = Bar::new().high(11).low(9).close(10);
+		let bar2 = Bar::new().high(14).low(12).close(13);
+
+		assert_eq!(elder_ray.next(&bar1).bull_power, 1.0);
+		assert_eq!(elder_ray.next(&bar2).bull_power, 2.5);
+
+		elder_ray.reset();
+		assert_eq!(elder_ray.next(&bar2).bull_power, 1.0);
+	}
+
+	#[test]
+	fn test_default() {
+		ElderRay::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let indicator = ElderRay::new(13).unwrap();
+		assert_eq!(format!("{}", indicator), "ERI(13)");
+	}
+}
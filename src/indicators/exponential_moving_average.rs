@@ -1,7 +1,10 @@
-use std::fmt;
+use core::fmt;
 
-use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
+use num_traits::Float;
+
+use crate::errors::Result;
+use crate::types;
+use crate::{Close, Next, Period, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -53,59 +56,95 @@ use serde::{Deserialize, Serialize};
 ///
 #[doc(alias = "EMA")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone)]
-pub struct ExponentialMovingAverage {
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExponentialMovingAverage<T = f64> {
 	period: usize,
-	k: f64,
-	current: f64,
+	k: T,
+	current: T,
+	count: usize,
 	is_new: bool,
 }
 
 impl ExponentialMovingAverage {
 	pub fn new(period: usize) -> Result<Self> {
-		match period {
-			0 => Err(TaError::InvalidParameter),
-			_ => Ok(Self {
-				period,
-				k: 2.0 / (period + 1) as f64,
-				current: 0.0,
-				is_new: true,
-			}),
+		Self::new_generic(period)
+	}
+
+	/// Creates the indicator from an already validated period, which cannot fail.
+	pub fn new_with_period(period: types::PeriodValue) -> Self {
+		Self::new_generic_with_period(period)
+	}
+}
+
+impl<T: Float> ExponentialMovingAverage<T> {
+	/// Creates the indicator for another float type than `f64`, for example
+	/// `ExponentialMovingAverage::<f32>::new_generic(3)`.
+	pub fn new_generic(period: usize) -> Result<Self> {
+		Ok(Self::new_generic_with_period(types::PeriodValue::try_from(
+			period,
+		)?))
+	}
+
+	/// Same as [new_with_period](#method.new_with_period) for another float type than `f64`.
+	pub fn new_generic_with_period(period: types::PeriodValue) -> Self {
+		let period = period.get();
+		Self {
+			period,
+			k: T::from(2.0).unwrap() / T::from(period + 1).unwrap(),
+			current: T::zero(),
+			count: 0,
+			is_new: true,
 		}
 	}
+
+	/// Returns the smoothing factor _α_ = 2 / (period + 1).
+	pub fn alpha(&self) -> T {
+		self.k
+	}
 }
 
-impl Period for ExponentialMovingAverage {
+impl<T> Period for ExponentialMovingAverage<T> {
 	fn period(&self) -> usize {
 		self.period
 	}
 }
 
-impl Next<f64> for ExponentialMovingAverage {
-	type Output = f64;
+impl<T> Ready for ExponentialMovingAverage<T> {
+	fn is_ready(&self) -> bool {
+		self.count == self.period
+	}
+}
+
+impl<T: Float> Next<T> for ExponentialMovingAverage<T> {
+	type Output = T;
+
+	fn next(&mut self, input: T) -> Self::Output {
+		if self.count < self.period {
+			self.count += 1;
+		}
 
-	fn next(&mut self, input: f64) -> Self::Output {
 		if self.is_new {
 			self.is_new = false;
 			self.current = input;
 		} else {
-			self.current = self.k * input + (1.0 - self.k) * self.current;
+			self.current = self.k * input + (T::one() - self.k) * self.current;
 		}
 		self.current
 	}
 }
 
-impl<T: Close> Next<&T> for ExponentialMovingAverage {
+impl<U: Close> Next<&U> for ExponentialMovingAverage {
 	type Output = f64;
 
-	fn next(&mut self, input: &T) -> Self::Output {
+	fn next(&mut self, input: &U) -> Self::Output {
 		self.next(input.close())
 	}
 }
 
-impl Reset for ExponentialMovingAverage {
+impl<T: Float> Reset for ExponentialMovingAverage<T> {
 	fn reset(&mut self) {
-		self.current = 0.0;
+		self.current = T::zero();
+		self.count = 0;
 		self.is_new = true;
 	}
 }
@@ -116,7 +155,7 @@ impl Default for ExponentialMovingAverage {
 	}
 }
 
-impl fmt::Display for ExponentialMovingAverage {
+impl<T> fmt::Display for ExponentialMovingAverage<T> {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		write!(f, "EMA({})", self.period)
 	}
@@ -135,6 +174,36 @@ mod tests {
 		assert!(ExponentialMovingAverage::new(1).is_ok());
 	}
 
+	#[test]
+	fn test_alpha() {
+		assert_eq!(ExponentialMovingAverage::new(1).unwrap().alpha(), 1.0);
+		assert_eq!(ExponentialMovingAverage::new(3).unwrap().alpha(), 0.5);
+		assert_eq!(ExponentialMovingAverage::new(9).unwrap().alpha(), 0.2);
+		assert_eq!(
+			ExponentialMovingAverage::<f32>::new_generic(3)
+				.unwrap()
+				.alpha(),
+			0.5
+		);
+
+		let fast = ExponentialMovingAverage::new(12).unwrap();
+		let slow = ExponentialMovingAverage::new(26).unwrap();
+		assert!(fast.alpha() > slow.alpha());
+	}
+
+	#[test]
+	fn test_new_with_period() {
+		let period = types::PeriodValue::try_from(3_usize).unwrap();
+		assert_eq!(
+			ExponentialMovingAverage::new_with_period(period).period(),
+			3
+		);
+		assert_eq!(
+			ExponentialMovingAverage::<f32>::new_generic_with_period(period).period(),
+			3
+		);
+	}
+
 	#[test]
 	fn test_next() {
 		let mut ema = ExponentialMovingAverage::new(3).unwrap();
@@ -165,6 +234,18 @@ mod tests {
 		assert_eq!(ema.next(4.0), 4.0);
 	}
 
+	#[test]
+	fn test_is_ready() {
+		let mut ema = ExponentialMovingAverage::new(2).unwrap();
+		ema.next(4.0);
+		assert!(!ema.is_ready());
+		ema.next(5.0);
+		assert!(ema.is_ready());
+
+		ema.reset();
+		assert!(!ema.is_ready());
+	}
+
 	#[test]
 	fn test_default() {
 		ExponentialMovingAverage::default();
@@ -1,8 +1,8 @@
-use std::fmt;
+use core::fmt;
 
 use crate::errors::Result;
 use crate::indicators::{Maximum, Minimum};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -41,7 +41,7 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(stoch.next(15.0), 0.0);
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct FastStochastic {
 	period: usize,
 	minimum: Minimum,
@@ -64,6 +64,12 @@ impl Period for FastStochastic {
 	}
 }
 
+impl Ready for FastStochastic {
+	fn is_ready(&self) -> bool {
+		self.minimum.is_ready()
+	}
+}
+
 impl Next<f64> for FastStochastic {
 	type Output = f64;
 
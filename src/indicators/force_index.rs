@@ -0,0 +1,205 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Close, Next, Period, Ready, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Force Index (FI).
+///
+/// Combines the price change and the volume to measure the force behind a move.
+/// The raw force is smoothed with an EMA. No normalization is applied, so the values
+/// can be large in magnitude for high volume instruments.
+///
+/// # Formula
+///
+/// FI = EMA((C<sub>t</sub> - C<sub>t-1</sub>) × V<sub>t</sub>, n)
+///
+/// Where:
+///
+/// * _C<sub>t</sub>_ - close price of the current period
+/// * _C<sub>t-1</sub>_ - close price of the previous period
+/// * _V<sub>t</sub>_ - volume of the current period
+///
+/// The first period is the warm-up bar: it has no previous close, so 0 is returned.
+/// With a period of 1 the EMA is an identity and the raw force is returned.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods of the smoothing EMA (integer greater than 0). Default is 13.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ForceIndex;
+/// use ta::{DataItem, Next};
+///
+/// let mut fi = ForceIndex::new(1).unwrap();
+///
+/// let di1 = DataItem::builder()
+///             .high(11.0)
+///             .low(9.0)
+///             .close(10.0)
+///             .open(10.0)
+///             .volume(100.0)
+///             .build().unwrap();
+///
+/// let di2 = DataItem::builder()
+///             .high(13.0)
+///             .low(11.0)
+///             .close(12.0)
+///             .open(11.0)
+///             .volume(200.0)
+///             .build().unwrap();
+///
+/// assert_eq!(fi.next(&di1), 0.0);
+/// assert_eq!(fi.next(&di2), 400.0);
+/// ```
+///
+/// # Links
+///
+/// * [Force index, Wikipedia](https://en.wikipedia.org/wiki/Force_index)
+#[doc(alias = "FI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForceIndex {
+	ema: Ema,
+	prev_close: Option<f64>,
+}
+
+impl ForceIndex {
+	pub fn new(period: usize) -> Result<Self> {
+		Ok(Self {
+			ema: Ema::new(period)?,
+			prev_close: None,
+		})
+	}
+}
+
+impl Period for ForceIndex {
+	fn period(&self) -> usize {
+		self.ema.period()
+	}
+}
+
+impl Ready for ForceIndex {
+	fn is_ready(&self) -> bool {
+		self.ema.is_ready()
+	}
+}
+
+impl<T: Close + Volume> Next<&T> for ForceIndex {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		let close = input.close();
+		match self.prev_close.replace(close) {
+			// Warm-up bar, there is no change to measure yet
+			None => 0.0,
+			Some(prev_close) => self.ema.next((close - prev_close) * input.volume()),
+		}
+	}
+}
+
+impl Reset for ForceIndex {
+	fn reset(&mut self) {
+		self.ema.reset();
+		self.prev_close = None;
+	}
+}
+
+impl Default for ForceIndex {
+	fn default() -> Self {
+		Self::new(13).unwrap()
+	}
+}
+
+impl fmt::Display for ForceIndex {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "FI({})", self.ema.period())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	#[test]
+	fn test_new() {
+		assert!(ForceIndex::new(0).is_err());
+		assert!(ForceIndex::new(1).is_ok());
+	}
+
+	#[test]
+	fn test_next_raw() {
+		let mut fi = ForceIndex::new(1).unwrap();
+
+		let bar1 = Bar::new().close(10).volume(100.0);
+		let bar2 = Bar::new().close(12).volume(200.0);
+		let bar3 = Bar::new().close(11).volume(100.0);
+		let bar4 = Bar::new().close(11).volume(300.0);
+
+		assert_eq!(fi.next(&bar1), 0.0);
+		assert_eq!(fi.next(&bar2), 400.0);
+		assert_eq!(fi.next(&bar3), -100.0);
+		assert_eq!(fi.next(&bar4), 0.0);
+	}
+
+	#[test]
+	fn test_next_smoothed() {
+		let mut fi = ForceIndex::new(2).unwrap();
+
+		let bar1 = Bar::new().close(10).volume(100.0);
+		let bar2 = Bar::new().close(12).volume(200.0);
+		let bar3 = Bar::new().close(11).volume(100.0);
+		let bar4 = Bar::new().close(13).volume(50.0);
+
+		assert_eq!(fi.next(&bar1), 0.0);
+		assert_eq!(fi.next(&bar2), 400.0);
+		assert_eq!(round(fi.next(&bar3)), 66.667);
+		assert_eq!(round(fi.next(&bar4)), 88.889);
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut fi = ForceIndex::new(2).unwrap();
+		let bar = Bar::new().close(10).volume(100.0);
+
+		fi.next(&bar);
+		fi.next(&bar);
+		assert!(!fi.is_ready());
+		fi.next(&bar);
+		assert!(fi.is_ready());
+
+		fi.reset();
+		assert!(!fi.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut fi = ForceIndex::new(1).unwrap();
+
+		let bar1 = Bar::new().close(10).volume(100.0);
+		let bar2 = Bar::new().close(12).volume(200.0);
+
+		assert_eq!(fi.next(&bar1), 0.0);
+		assert_eq!(fi.next(&bar2), 400.0);
+
+		fi.reset();
+		assert_eq!(fi.next(&bar2), 0.0);
+		assert_eq!(fi.next(&bar1), -200.0);
+	}
+
+	#[test]
+	fn test_default() {
+		ForceIndex::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let indicator = ForceIndex::new(13).unwrap();
+		assert_eq!(format!("{}", indicator), "FI(13)");
+	}
+}
@@ -0,0 +1,221 @@
+use core::fmt;
+
+use num_traits::Float;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{LogReturn, StandardDeviation};
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Historical Volatility (HV).
+///
+/// Returns the annualized standard deviation of the logarithmic returns of the last n
+/// periods, in decimal form: 0.2 means an annualized volatility of 20%.
+///
+/// # Formula
+///
+/// HV = SD(ln(C<sub>t</sub> / C<sub>t-1</sub>), n) × √d
+///
+/// Where:
+///
+/// * _SD_ - [standard deviation](struct.StandardDeviation.html) of the last _n_ [log returns](struct.LogReturn.html)
+/// * _C<sub>t</sub>_ - close price of the current period
+/// * _d_ - number of trading days per year
+///
+/// The first period has no previous price, so 0 is returned. A period whose current or
+/// previous price is not positive has no log return; it is skipped and the previous value
+/// is returned.
+///
+/// # Parameters
+///
+/// * _period_ - number of log returns (integer greater than 0). Default is 20.
+/// * _trading_days_per_year_ - annualization factor (integer greater than 0). Default is 252.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::HistoricalVolatility;
+/// use ta::Next;
+///
+/// let mut hv = HistoricalVolatility::new(3, 252).unwrap();
+/// assert_eq!(hv.next(100.0), 0.0);
+/// assert_eq!(hv.next(102.0), 0.0);
+/// assert!(hv.next(101.0) > 0.2);
+/// ```
+///
+/// # Links
+///
+/// * [Volatility, Wikipedia](https://en.wikipedia.org/wiki/Volatility_(finance))
+#[doc(alias = "HV")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoricalVolatility {
+	sd: StandardDeviation,
+	trading_days_per_year: usize,
+	log_return: LogReturn,
+	current: f64,
+}
+
+impl HistoricalVolatility {
+	pub fn new(period: usize, trading_days_per_year: usize) -> Result<Self> {
+		if trading_days_per_year == 0 {
+			return Err(TaError::InvalidParameter);
+		}
+
+		Ok(Self {
+			sd: StandardDeviation::new(period)?,
+			trading_days_per_year,
+			log_return: LogReturn::new(),
+			current: 0.0,
+		})
+	}
+
+	pub fn trading_days_per_year(&self) -> usize {
+		self.trading_days_per_year
+	}
+}
+
+impl Period for HistoricalVolatility {
+	fn period(&self) -> usize {
+		self.sd.period()
+	}
+}
+
+impl Ready for HistoricalVolatility {
+	fn is_ready(&self) -> bool {
+		self.sd.is_ready()
+	}
+}
+
+impl Next<f64> for HistoricalVolatility {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		// the first period has no log return and a non positive price gives NaN
+		let had_prev = self.log_return.is_ready();
+		let log_return = self.log_return.next(input);
+		if had_prev && !log_return.is_nan() {
+			self.current =
+				self.sd.next(log_return) * Float::sqrt(self.trading_days_per_year as f64);
+		}
+
+		self.current
+	}
+}
+
+impl<T: Close> Next<&T> for HistoricalVolatility {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl Reset for HistoricalVolatility {
+	fn reset(&mut self) {
+		self.sd.reset();
+		self.log_return.reset();
+		self.current = 0.0;
+	}
+}
+
+impl Default for HistoricalVolatility {
+	fn default() -> Self {
+		Self::new(20, 252).unwrap()
+	}
+}
+
+impl fmt::Display for HistoricalVolatility {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self.trading_days_per_year {
+			252 => write!(f, "HV({})", self.sd.period()),
+			days => write!(f, "HV({}, {})", self.sd.period(), days),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	test_indicator!(HistoricalVolatility);
+
+	#[test]
+	fn test_new() {
+		assert!(HistoricalVolatility::new(0, 252).is_err());
+		assert!(HistoricalVolatility::new(20, 0).is_err());
+		assert!(HistoricalVolatility::new(1, 1).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let mut hv = HistoricalVolatility::new(3, 252).unwrap();
+		assert_eq!(hv.next(100.0), 0.0);
+		assert_eq!(hv.next(102.0), 0.0);
+		assert_eq!(round(hv.next(101.0)), 0.235);
+		assert_eq!(round(hv.next(106.0)), 0.377);
+		assert_eq!(round(hv.next(103.0)), 0.52);
+		assert_eq!(round(hv.next(104.0)), 0.499);
+	}
+
+	#[test]
+	fn test_next_trading_days() {
+		let mut hv = HistoricalVolatility::new(3, 365).unwrap();
+		hv.next(100.0);
+		hv.next(102.0);
+		assert_eq!(round(hv.next(101.0)), 0.283);
+	}
+
+	#[test]
+	fn test_next_non_positive_price() {
+		let mut hv = HistoricalVolatility::new(3, 252).unwrap();
+		hv.next(100.0);
+		hv.next(102.0);
+		assert_eq!(round(hv.next(101.0)), 0.235);
+		assert_eq!(round(hv.next(0.0)), 0.235);
+		assert_eq!(round(hv.next(105.0)), 0.235);
+		assert_eq!(round(hv.next(-1.0)), 0.235);
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut hv = HistoricalVolatility::new(2, 252).unwrap();
+		hv.next(100.0);
+		hv.next(101.0);
+		assert!(!hv.is_ready());
+		hv.next(102.0);
+		assert!(hv.is_ready());
+
+		hv.reset();
+		assert!(!hv.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut hv = HistoricalVolatility::new(3, 252).unwrap();
+		hv.next(100.0);
+		hv.next(102.0);
+		assert_eq!(round(hv.next(101.0)), 0.235);
+
+		hv.reset();
+		assert_eq!(hv.next(100.0), 0.0);
+		assert_eq!(hv.next(102.0), 0.0);
+		assert_eq!(round(hv.next(101.0)), 0.235);
+	}
+
+	#[test]
+	fn test_default() {
+		HistoricalVolatility::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let hv = HistoricalVolatility::new(20, 252).unwrap();
+		assert_eq!(format!("{}", hv), "HV(20)");
+
+		let hv = HistoricalVolatility::new(20, 365).unwrap();
+		assert_eq!(format!("{}", hv), "HV(20, 365)");
+	}
+}
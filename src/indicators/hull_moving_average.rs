@@ -0,0 +1,207 @@
+use core::fmt;
+
+use num_traits::Float;
+
+use crate::errors::Result;
+use crate::indicators::WeightedMovingAverage as Wma;
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Hull moving average (HMA).
+///
+/// A moving average which reduces the lag of the WMA while keeping the curve smooth.
+///
+/// # Formula
+///
+/// HMA = WMA(2 × WMA(p, n / 2) - WMA(p, n), floor(sqrt(n)))
+///
+/// Where:
+///
+/// * _p_ - input value
+/// * _n_ - period, _n / 2_ is rounded down (but is at least 1)
+///
+/// The HMA is ready once _n + floor(sqrt(n)) - 1_ values have been consumed.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::HullMovingAverage;
+/// use ta::Next;
+///
+/// let mut hma = HullMovingAverage::new(1).unwrap();
+/// assert_eq!(hma.next(10.0), 10.0);
+/// assert_eq!(hma.next(13.0), 13.0);
+/// ```
+///
+/// # Links
+///
+/// * [Hull Moving Average, Alan Hull](https://alanhull.com/hull-moving-average)
+#[doc(alias = "HMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct HullMovingAverage {
+	period: usize,
+	count: usize,
+	half_wma: Wma,
+	full_wma: Wma,
+	sqrt_wma: Wma,
+}
+
+impl HullMovingAverage {
+	pub fn new(period: usize) -> Result<Self> {
+		let full_wma = Wma::new(period)?;
+		let sqrt_period = Float::floor(Float::sqrt(period as f64)) as usize;
+
+		Ok(Self {
+			period,
+			count: 0,
+			half_wma: Wma::new((period / 2).max(1))?,
+			full_wma,
+			sqrt_wma: Wma::new(sqrt_period)?,
+		})
+	}
+}
+
+impl Period for HullMovingAverage {
+	fn period(&self) -> usize {
+		self.period
+	}
+}
+
+impl Ready for HullMovingAverage {
+	fn is_ready(&self) -> bool {
+		self.count == self.period + self.sqrt_wma.period() - 1
+	}
+}
+
+impl Next<f64> for HullMovingAverage {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		if self.count < self.period + self.sqrt_wma.period() - 1 {
+			self.count += 1;
+		}
+
+		let half = self.half_wma.next(input);
+		let full = self.full_wma.next(input);
+		self.sqrt_wma.next(2.0 * half - full)
+	}
+}
+
+impl<T: Close> Next<&T> for HullMovingAverage {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl Reset for HullMovingAverage {
+	fn reset(&mut self) {
+		self.count = 0;
+		self.half_wma.reset();
+		self.full_wma.reset();
+		self.sqrt_wma.reset();
+	}
+}
+
+impl Default for HullMovingAverage {
+	fn default() -> Self {
+		Self::new(9).unwrap()
+	}
+}
+
+impl fmt::Display for HullMovingAverage {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "HMA({})", self.period)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	test_indicator!(HullMovingAverage);
+
+	#[test]
+	fn test_new() {
+		assert!(HullMovingAverage::new(0).is_err());
+		assert!(HullMovingAverage::new(1).is_ok());
+		assert!(HullMovingAverage::new(2).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let mut hma = HullMovingAverage::new(4).unwrap();
+
+		assert_eq!(round(hma.next(2.0)), 2.0);
+		assert_eq!(round(hma.next(4.0)), 2.889);
+		assert_eq!(round(hma.next(3.0)), 3.444);
+		assert_eq!(round(hma.next(6.0)), 4.967);
+		assert_eq!(round(hma.next(8.0)), 7.678);
+		assert_eq!(round(hma.next(7.0)), 8.2);
+		assert_eq!(round(hma.next(5.0)), 6.011);
+
+		let mut hma = HullMovingAverage::new(4).unwrap();
+		let bar1 = Bar::new().close(2);
+		let bar2 = Bar::new().close(4);
+		assert_eq!(round(hma.next(&bar1)), 2.0);
+		assert_eq!(round(hma.next(&bar2)), 2.889);
+	}
+
+	#[test]
+	fn test_next_short_periods() {
+		// HMA(1) returns the input unchanged
+		let mut hma = HullMovingAverage::new(1).unwrap();
+		assert_eq!(hma.next(3.0), 3.0);
+		assert_eq!(hma.next(7.0), 7.0);
+		assert!(hma.is_ready());
+
+		// HMA(2) is 2 × p - WMA(p, 2) smoothed over 1 period
+		let mut hma = HullMovingAverage::new(2).unwrap();
+		assert_eq!(hma.next(3.0), 3.0);
+		assert_eq!(round(hma.next(6.0)), 7.0);
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut hma = HullMovingAverage::new(4).unwrap();
+		for _ in 0..4 {
+			hma.next(1.0);
+			assert!(!hma.is_ready());
+		}
+		hma.next(1.0);
+		assert!(hma.is_ready());
+
+		hma.reset();
+		assert!(!hma.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut hma = HullMovingAverage::new(4).unwrap();
+		assert_eq!(round(hma.next(2.0)), 2.0);
+		assert_eq!(round(hma.next(4.0)), 2.889);
+
+		hma.reset();
+		assert_eq!(round(hma.next(2.0)), 2.0);
+		assert_eq!(round(hma.next(4.0)), 2.889);
+	}
+
+	#[test]
+	fn test_default() {
+		HullMovingAverage::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let indicator = HullMovingAverage::new(16).unwrap();
+		assert_eq!(format!("{}", indicator), "HMA(16)");
+	}
+}
@@ -0,0 +1,285 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{Maximum, Minimum};
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Ichimoku Cloud (Ichimoku Kinko Hyo).
+///
+/// A collection of five lines which together define support and resistance, momentum
+/// and trend direction.
+///
+/// # Formula
+///
+/// * _Tenkan-sen_ = (highest high + lowest low) / 2 over the last _tenkan_ periods
+/// * _Kijun-sen_ = (highest high + lowest low) / 2 over the last _kijun_ periods
+/// * _Senkou Span A_ = (Tenkan-sen + Kijun-sen) / 2, shifted _kijun_ periods forward
+/// * _Senkou Span B_ = (highest high + lowest low) / 2 over the last _senkou_b_ periods,
+///   shifted _kijun_ periods forward
+/// * _Chikou Span_ = close, shifted _kijun_ periods backward
+///
+/// Since the indicator can not look into the future, the Senkou spans returned for the current
+/// period are the ones calculated _kijun_ periods ago. Until _kijun_ periods have been seen,
+/// the unshifted values are returned instead. The Chikou span is the current close and is
+/// meant to be plotted _kijun_ periods behind.
+///
+/// # Parameters
+///
+/// * _tenkan_ - period of the conversion line (integer greater than 0). Default is 9.
+/// * _kijun_ - period of the base line and the displacement (integer greater than 0). Default is 26.
+/// * _senkou_b_ - period of the Senkou Span B (integer greater than 0). Default is 52.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::IchimokuCloud;
+/// use ta::{DataItem, Next};
+///
+/// let mut ichimoku = IchimokuCloud::new(2, 3, 4).unwrap();
+///
+/// let di = DataItem::builder()
+///             .high(10.0)
+///             .low(8.0)
+///             .close(9.0)
+///             .open(9.0)
+///             .volume(100.0)
+///             .build().unwrap();
+///
+/// let out = ichimoku.next(&di);
+/// assert_eq!(out.tenkan_sen, 9.0);
+/// assert_eq!(out.kijun_sen, 9.0);
+/// assert_eq!(out.chikou_span, 9.0);
+/// ```
+///
+/// # Links
+///
+/// * [Ichimoku Kinko Hyo, Wikipedia](https://en.wikipedia.org/wiki/Ichimoku_Kink%C5%8D_Hy%C5%8D)
+#[doc(alias = "Ichimoku")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct IchimokuCloud {
+	tenkan_max: Maximum,
+	tenkan_min: Minimum,
+	kijun_max: Maximum,
+	kijun_min: Minimum,
+	senkou_b_max: Maximum,
+	senkou_b_min: Minimum,
+	index: usize,
+	count: usize,
+	senkou_a_delay: Box<[f64]>,
+	senkou_b_delay: Box<[f64]>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IchimokuCloudOutput {
+	pub tenkan_sen: f64,
+	pub kijun_sen: f64,
+	pub senkou_a: f64,
+	pub senkou_b: f64,
+	pub chikou_span: f64,
+}
+
+impl IchimokuCloud {
+	pub fn new(tenkan: usize, kijun: usize, senkou_b: usize) -> Result<Self> {
+		Ok(Self {
+			tenkan_max: Maximum::new(tenkan)?,
+			tenkan_min: Minimum::new(tenkan)?,
+			kijun_max: Maximum::new(kijun)?,
+			kijun_min: Minimum::new(kijun)?,
+			senkou_b_max: Maximum::new(senkou_b)?,
+			senkou_b_min: Minimum::new(senkou_b)?,
+			index: 0,
+			count: 0,
+			senkou_a_delay: vec![0.0; kijun].into_boxed_slice(),
+			senkou_b_delay: vec![0.0; kijun].into_boxed_slice(),
+		})
+	}
+
+	fn calc(&mut self, high: f64, low: f64, close: f64) -> IchimokuCloudOutput {
+		let tenkan_sen = (self.tenkan_max.next(high) + self.tenkan_min.next(low)) / 2.0;
+		let kijun_sen = (self.kijun_max.next(high) + self.kijun_min.next(low)) / 2.0;
+		let senkou_a = (tenkan_sen + kijun_sen) / 2.0;
+		let senkou_b = (self.senkou_b_max.next(high) + self.senkou_b_min.next(low)) / 2.0;
+
+		let displacement = self.senkou_a_delay.len();
+		let (shifted_a, shifted_b) = if self.count < displacement {
+			self.count += 1;
+			(senkou_a, senkou_b)
+		} else {
+			(
+				self.senkou_a_delay[self.index],
+				self.senkou_b_delay[self.index],
+			)
+		};
+
+		self.senkou_a_delay[self.index] = senkou_a;
+		self.senkou_b_delay[self.index] = senkou_b;
+		self.index = if self.index + 1 < displacement {
+			self.index + 1
+		} else {
+			0
+		};
+
+		IchimokuCloudOutput {
+			tenkan_sen,
+			kijun_sen,
+			senkou_a: shifted_a,
+			senkou_b: shifted_b,
+			chikou_span: close,
+		}
+	}
+}
+
+impl Period for IchimokuCloud {
+	fn period(&self) -> usize {
+		self.senkou_b_max.period()
+	}
+}
+
+impl Ready for IchimokuCloud {
+	fn is_ready(&self) -> bool {
+		self.tenkan_max.is_ready()
+			&& self.kijun_max.is_ready()
+			&& self.senkou_b_max.is_ready()
+			&& self.count == self.senkou_a_delay.len()
+	}
+}
+
+impl Next<f64> for IchimokuCloud {
+	type Output = IchimokuCloudOutput;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		self.calc(input, input, input)
+	}
+}
+
+impl<T: High + Low + Close> Next<&T> for IchimokuCloud {
+	type Output = IchimokuCloudOutput;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.calc(input.high(), input.low(), input.close())
+	}
+}
+
+impl Reset for IchimokuCloud {
+	fn reset(&mut self) {
+		self.tenkan_max.reset();
+		self.tenkan_min.reset();
+		self.kijun_max.reset();
+		self.kijun_min.reset();
+		self.senkou_b_max.reset();
+		self.senkou_b_min.reset();
+		self.index = 0;
+		self.count = 0;
+		for i in 0..self.senkou_a_delay.len() {
+			self.senkou_a_delay[i] = 0.0;
+			self.senkou_b_delay[i] = 0.0;
+		}
+	}
+}
+
+impl Default for IchimokuCloud {
+	fn default() -> Self {
+		Self::new(9, 26, 52).unwrap()
+	}
+}
+
+impl fmt::Display for IchimokuCloud {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"ICHIMOKU({}, {}, {})",
+			self.tenkan_max.period(),
+			self.kijun_max.period(),
+			self.senkou_b_max.period()
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	test_indicator!(IchimokuCloud);
+
+	fn values(out: IchimokuCloudOutput) -> (f64, f64, f64, f64, f64) {
+		(
+			out.tenkan_sen,
+			out.kijun_sen,
+			out.senkou_a,
+			out.senkou_b,
+			out.chikou_span,
+		)
+	}
+
+	#[test]
+	fn test_new() {
+		assert!(IchimokuCloud::new(0, 26, 52).is_err());
+		assert!(IchimokuCloud::new(9, 0, 52).is_err());
+		assert!(IchimokuCloud::new(9, 26, 0).is_err());
+		assert!(IchimokuCloud::new(9, 26, 52).is_ok());
+	}
+
+	#[test]
+	fn test_next_with_bars() {
+		let test_data = vec![
+			// high, low, close, (tenkan, kijun, senkou a, senkou b, chikou)
+			(10.0, 8.0, 9.0, (9.0, 9.0, 9.0, 9.0, 9.0)),
+			(12.0, 9.0, 11.0, (10.0, 10.0, 10.0, 10.0, 11.0)),
+			(13.0, 10.0, 12.0, (11.0, 10.5, 10.75, 10.5, 12.0)),
+			(11.0, 7.0, 8.0, (10.0, 10.0, 9.0, 9.0, 8.0)),
+			(14.0, 10.0, 13.0, (10.5, 10.5, 10.0, 10.0, 13.0)),
+			(15.0, 12.0, 14.0, (12.5, 11.0, 10.75, 10.5, 14.0)),
+		];
+
+		let mut ichimoku = IchimokuCloud::new(2, 3, 4).unwrap();
+
+		for (high, low, close, expected) in test_data {
+			let bar = Bar::new().high(high).low(low).close(close);
+			assert_eq!(values(ichimoku.next(&bar)), expected);
+		}
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut ichimoku = IchimokuCloud::new(2, 3, 4).unwrap();
+		for _ in 0..3 {
+			ichimoku.next(1.0);
+			assert!(!ichimoku.is_ready());
+		}
+		ichimoku.next(1.0);
+		assert!(ichimoku.is_ready());
+
+		ichimoku.reset();
+		assert!(!ichimoku.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut ichimoku = IchimokuCloud::new(2, 3, 4).unwrap();
+		let bar1 = Bar::new().high(10).low(8).close(9);
+		let bar2 = Bar::new().high(12).low(9).close(11);
+
+		ichimoku.next(&bar1);
+		ichimoku.next(&bar2);
+		ichimoku.reset();
+
+		assert_eq!(values(ichimoku.next(&bar2)), (10.5, 10.5, 10.5, 10.5, 11.0));
+	}
+
+	#[test]
+	fn test_default() {
+		IchimokuCloud::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let indicator = IchimokuCloud::new(9, 26, 52).unwrap();
+		assert_eq!(format!("{}", indicator), "ICHIMOKU(9, 26, 52)");
+	}
+}
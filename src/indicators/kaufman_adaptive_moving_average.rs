@@ -0,0 +1,231 @@
+use core::fmt;
+
+use num_traits::Float;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::EfficiencyRatio;
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Kaufman's adaptive moving average (KAMA).
+///
+/// A moving average which adapts its smoothing to the [Efficiency Ratio](struct.EfficiencyRatio.html)
+/// of the price: it follows the price closely when the market trends and smooths aggressively
+/// when the market moves sideways.
+///
+/// # Formula
+///
+/// SC = (ER × (2 / (fast + 1) - 2 / (slow + 1)) + 2 / (slow + 1))<sup>2</sup>
+///
+/// KAMA<sub>t</sub> = KAMA<sub>t-1</sub> + SC × (p<sub>t</sub> - KAMA<sub>t-1</sub>)
+///
+/// Where:
+///
+/// * _ER_ - efficiency ratio over the last _er_period_ periods
+/// * _p<sub>t</sub>_ - input value at a time period t
+///
+/// KAMA starts at the first input value.
+///
+/// # Parameters
+///
+/// * _er_period_ - period of the efficiency ratio (integer greater than 0). Default is 10.
+/// * _fast_period_ - period of the fastest EMA (integer greater than 0). Default is 2.
+/// * _slow_period_ - period of the slowest EMA (integer greater than 0). Default is 30.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::KaufmanAdaptiveMovingAverage;
+/// use ta::Next;
+///
+/// let mut kama = KaufmanAdaptiveMovingAverage::new(3, 2, 30).unwrap();
+/// assert_eq!(kama.next(5.0), 5.0);
+/// assert_eq!(kama.next(5.0), 5.0);
+/// ```
+///
+/// # Links
+///
+/// * [Kaufman's Adaptive Moving Average, stockcharts](https://school.stockcharts.com/doku.php?id=technical_indicators:kaufman_s_adaptive_moving_average)
+#[doc(alias = "KAMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct KaufmanAdaptiveMovingAverage {
+	fast_period: usize,
+	slow_period: usize,
+	fast_sc: f64,
+	slow_sc: f64,
+	er: EfficiencyRatio,
+	current: f64,
+	is_new: bool,
+}
+
+impl KaufmanAdaptiveMovingAverage {
+	pub fn new(er_period: usize, fast_period: usize, slow_period: usize) -> Result<Self> {
+		if fast_period == 0 || slow_period == 0 {
+			return Err(TaError::InvalidParameter);
+		}
+
+		Ok(Self {
+			fast_period,
+			slow_period,
+			fast_sc: 2.0 / (fast_period as f64 + 1.0),
+			slow_sc: 2.0 / (slow_period as f64 + 1.0),
+			er: EfficiencyRatio::new(er_period)?,
+			current: 0.0,
+			is_new: true,
+		})
+	}
+}
+
+impl Period for KaufmanAdaptiveMovingAverage {
+	fn period(&self) -> usize {
+		self.er.period()
+	}
+}
+
+impl Ready for KaufmanAdaptiveMovingAverage {
+	fn is_ready(&self) -> bool {
+		self.er.is_ready()
+	}
+}
+
+impl Next<f64> for KaufmanAdaptiveMovingAverage {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		let er = self.er.next(input);
+
+		if self.is_new {
+			self.is_new = false;
+			self.current = input;
+		} else {
+			let sc = Float::powi(er * (self.fast_sc - self.slow_sc) + self.slow_sc, 2);
+			self.current += sc * (input - self.current);
+		}
+		self.current
+	}
+}
+
+impl<T: Close> Next<&T> for KaufmanAdaptiveMovingAverage {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl Reset for KaufmanAdaptiveMovingAverage {
+	fn reset(&mut self) {
+		self.er.reset();
+		self.current = 0.0;
+		self.is_new = true;
+	}
+}
+
+impl Default for KaufmanAdaptiveMovingAverage {
+	fn default() -> Self {
+		Self::new(10, 2, 30).unwrap()
+	}
+}
+
+impl fmt::Display for KaufmanAdaptiveMovingAverage {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"KAMA({}, {}, {})",
+			self.er.period(),
+			self.fast_period,
+			self.slow_period
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	type Kama = KaufmanAdaptiveMovingAverage;
+
+	test_indicator!(Kama);
+
+	#[test]
+	fn test_new() {
+		assert!(Kama::new(0, 2, 30).is_err());
+		assert!(Kama::new(10, 0, 30).is_err());
+		assert!(Kama::new(10, 2, 0).is_err());
+		assert!(Kama::new(10, 2, 30).is_ok());
+	}
+
+	#[test]
+	fn test_next_trending() {
+		let mut kama = Kama::new(3, 2, 30).unwrap();
+
+		assert_eq!(kama.next(10.0), 10.0);
+		assert_eq!(round(kama.next(11.0)), 10.444);
+		assert_eq!(round(kama.next(12.0)), 11.136);
+		assert_eq!(round(kama.next(13.0)), 11.964);
+		assert_eq!(round(kama.next(14.0)), 12.869);
+		assert_eq!(round(kama.next(15.0)), 13.816);
+	}
+
+	#[test]
+	fn test_next_ranging() {
+		let mut kama = Kama::new(3, 2, 30).unwrap();
+
+		assert_eq!(kama.next(10.0), 10.0);
+		assert_eq!(round(kama.next(12.0)), 10.889);
+		// once the market moves sideways, KAMA barely moves
+		assert_eq!(round(kama.next(10.0)), 10.885);
+		assert_eq!(round(kama.next(12.0)), 10.964);
+		assert_eq!(round(kama.next(10.0)), 10.896);
+		assert_eq!(round(kama.next(12.0)), 10.974);
+	}
+
+	#[test]
+	fn test_next_with_bars() {
+		let mut kama = Kama::new(3, 2, 30).unwrap();
+		let bar1 = Bar::new().close(10);
+		let bar2 = Bar::new().close(11);
+
+		assert_eq!(kama.next(&bar1), 10.0);
+		assert_eq!(round(kama.next(&bar2)), 10.444);
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut kama = Kama::new(3, 2, 30).unwrap();
+		kama.next(1.0);
+		kama.next(2.0);
+		kama.next(3.0);
+		// the efficiency ratio needs the price 3 periods ago
+		assert!(!kama.is_ready());
+		kama.next(4.0);
+		assert!(kama.is_ready());
+
+		kama.reset();
+		assert!(!kama.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut kama = Kama::new(3, 2, 30).unwrap();
+		assert_eq!(kama.next(10.0), 10.0);
+		assert_eq!(round(kama.next(11.0)), 10.444);
+
+		kama.reset();
+		assert_eq!(kama.next(20.0), 20.0);
+	}
+
+	#[test]
+	fn test_default() {
+		Kama::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let indicator = Kama::new(10, 2, 30).unwrap();
+		assert_eq!(format!("{}", indicator), "KAMA(10, 2, 30)");
+	}
+}
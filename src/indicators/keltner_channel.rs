@@ -1,8 +1,8 @@
-use std::fmt;
+use core::fmt;
 
-use crate::errors::Result;
+use crate::errors::{Result, TaError};
 use crate::indicators::{AverageTrueRange, ExponentialMovingAverage};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -22,6 +22,13 @@ use serde::{Deserialize, Serialize};
 ///  * _KC<sub>Upper Band</sub>_ = EMA + ATR of observation * multipler (usually 2.0)
 ///  * _KC<sub>Lower Band</sub>_ = EMA - ATR of observation * multipler (usually 2.0)
 ///
+/// # Parameters
+///
+/// * _period_ - period of the EMA and the ATR (integer greater than 0). Default is 10.
+/// * _multiplier_ - multiplier of the ATR (greater than 0). Default is 2.0.
+///
+/// Use [new_with_atr_period](#method.new_with_atr_period) to give the ATR its own period.
+///
 /// # Example
 ///
 ///```
@@ -48,7 +55,7 @@ use serde::{Deserialize, Serialize};
 /// * [Keltner channel, Wikipedia](https://en.wikipedia.org/wiki/Keltner_channel)
 #[doc(alias = "KC")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct KeltnerChannel {
 	period: usize,
 	multiplier: f64,
@@ -58,17 +65,35 @@ pub struct KeltnerChannel {
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct KeltnerChannelOutput {
+	/// Middle band.
 	pub average: f64,
 	pub upper: f64,
 	pub lower: f64,
 }
 
+/// Converts the bands into an `(average, upper, lower)` tuple, in the order of the fields,
+/// e.g. for code written against a tuple output.
+impl From<KeltnerChannelOutput> for (f64, f64, f64) {
+	fn from(output: KeltnerChannelOutput) -> Self {
+		(output.average, output.upper, output.lower)
+	}
+}
+
 impl KeltnerChannel {
 	pub fn new(period: usize, multiplier: f64) -> Result<Self> {
+		Self::new_with_atr_period(period, period, multiplier)
+	}
+
+	/// Creates a Keltner Channel whose ATR uses a different period than the EMA.
+	pub fn new_with_atr_period(period: usize, atr_period: usize, multiplier: f64) -> Result<Self> {
+		if multiplier.is_nan() || multiplier <= 0.0 {
+			return Err(TaError::InvalidParameter);
+		}
+
 		Ok(Self {
 			period,
 			multiplier,
-			atr: AverageTrueRange::new(period)?,
+			atr: AverageTrueRange::new(atr_period)?,
 			ema: ExponentialMovingAverage::new(period)?,
 		})
 	}
@@ -84,6 +109,12 @@ impl Period for KeltnerChannel {
 	}
 }
 
+impl Ready for KeltnerChannel {
+	fn is_ready(&self) -> bool {
+		self.atr.is_ready() && self.ema.is_ready()
+	}
+}
+
 impl Next<f64> for KeltnerChannel {
 	type Output = KeltnerChannelOutput;
 
@@ -131,7 +162,16 @@ impl Default for KeltnerChannel {
 
 impl fmt::Display for KeltnerChannel {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		write!(f, "KC({}, {})", self.period, self.multiplier)
+		let atr_period = self.atr.period();
+		if atr_period == self.period {
+			write!(f, "KC({}, {})", self.period, self.multiplier)
+		} else {
+			write!(
+				f,
+				"KC({}, {}, {})",
+				self.period, atr_period, self.multiplier
+			)
+		}
 	}
 }
 
@@ -147,6 +187,44 @@ mod tests {
 		assert!(KeltnerChannel::new(0, 2_f64).is_err());
 		assert!(KeltnerChannel::new(1, 2_f64).is_ok());
 		assert!(KeltnerChannel::new(2, 2_f64).is_ok());
+		assert!(KeltnerChannel::new(2, 0_f64).is_err());
+		assert!(KeltnerChannel::new(2, -1_f64).is_err());
+		assert!(KeltnerChannel::new(2, f64::NAN).is_err());
+
+		assert!(KeltnerChannel::new_with_atr_period(0, 10, 2_f64).is_err());
+		assert!(KeltnerChannel::new_with_atr_period(20, 0, 2_f64).is_err());
+		assert!(KeltnerChannel::new_with_atr_period(20, 10, 0_f64).is_err());
+		assert!(KeltnerChannel::new_with_atr_period(20, 10, 2_f64).is_ok());
+	}
+
+	#[test]
+	fn test_next_with_atr_period() {
+		let mut kc = KeltnerChannel::new_with_atr_period(3, 1, 2.0_f64).unwrap();
+		assert_eq!(kc.period(), 3);
+
+		let a = kc.next(2.0);
+		let b = kc.next(5.0);
+		let c = kc.next(1.0);
+
+		assert_eq!(round(a.average), 2.0);
+		assert_eq!(round(b.average), 3.5);
+		assert_eq!(round(c.average), 2.25);
+
+		// ATR(1) is the true range of the current period
+		assert_eq!(round(b.upper), 9.5);
+		assert_eq!(round(c.lower), -5.75);
+
+		let (average, upper, lower) = c.into();
+		assert_eq!(round(average), 2.25);
+		assert!(lower < average && average < upper);
+	}
+
+	#[test]
+	fn test_next_into_tuple() {
+		let mut kc = KeltnerChannel::new(3, 2.0_f64).unwrap();
+		kc.next(2.0);
+		let (average, upper, lower) = kc.next(5.0).into();
+		assert_eq!((average, upper, lower), (3.5, 6.5, 0.5));
 	}
 
 	#[test]
@@ -233,5 +311,8 @@ mod tests {
 	fn test_display() {
 		let kc = KeltnerChannel::new(10, 3.0_f64).unwrap();
 		assert_eq!(format!("{}", kc), "KC(10, 3)");
+
+		let kc = KeltnerChannel::new_with_atr_period(20, 10, 2.0_f64).unwrap();
+		assert_eq!(format!("{}", kc), "KC(20, 10, 2)");
 	}
 }
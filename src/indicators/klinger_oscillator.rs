@@ -0,0 +1,285 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Close, High, Low, Next, Period, Ready, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Klinger Volume Oscillator (KVO).
+///
+/// Compares the volume flowing in and out of a security to detect long term trends of the
+/// money flow, while remaining sensitive to short term fluctuations.
+///
+/// # Formula
+///
+/// T = +1 if (H + L + C) > (H<sub>t-1</sub> + L<sub>t-1</sub> + C<sub>t-1</sub>), otherwise -1
+///
+/// DM = H - L
+///
+/// CM = CM<sub>t-1</sub> + DM if T = T<sub>t-1</sub>, otherwise DM<sub>t-1</sub> + DM
+///
+/// VF = V × |2 × (DM / CM - 1)| × T × 100
+///
+/// KVO = EMA(VF, fast) - EMA(VF, slow)
+///
+/// Signal = EMA(KVO, signal)
+///
+/// Where:
+///
+/// * _T_ - trend direction
+/// * _DM_ - daily measurement
+/// * _CM_ - cumulative measurement
+/// * _VF_ - volume force, 0 if _CM_ is 0
+///
+/// The first period has no previous price to compare with, so 0 is returned for both lines.
+///
+/// # Parameters
+///
+/// * _fast_period_ - period of the fast EMA (integer greater than 0). Default is 34.
+/// * _slow_period_ - period of the slow EMA (integer greater than _fast_period_). Default is 55.
+/// * _signal_period_ - period of the signal EMA (integer greater than 0). Default is 13.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::KlingerOscillator;
+/// use ta::{DataItem, Next};
+///
+/// let mut kvo = KlingerOscillator::new(2, 3, 2).unwrap();
+///
+/// let di = DataItem::builder()
+///             .high(10.0)
+///             .low(8.0)
+///             .close(9.0)
+///             .open(9.0)
+///             .volume(100.0)
+///             .build().unwrap();
+///
+/// let out = kvo.next(&di);
+/// assert_eq!(out.kvo, 0.0);
+/// assert_eq!(out.signal, 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Klinger Oscillator, investopedia](https://www.investopedia.com/terms/k/klingeroscillator.asp)
+#[doc(alias = "KVO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct KlingerOscillator {
+	prev_hlc: Option<f64>,
+	trend: f64,
+	dm: f64,
+	cm: f64,
+	fast_ema: Ema,
+	slow_ema: Ema,
+	signal_ema: Ema,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KlingerOscillatorOutput {
+	pub kvo: f64,
+	pub signal: f64,
+}
+
+impl From<KlingerOscillatorOutput> for (f64, f64) {
+	fn from(ko: KlingerOscillatorOutput) -> Self {
+		(ko.kvo, ko.signal)
+	}
+}
+
+impl KlingerOscillator {
+	pub fn new(fast_period: usize, slow_period: usize, signal_period: usize) -> Result<Self> {
+		if fast_period >= slow_period {
+			return Err(TaError::InvalidParameter);
+		}
+
+		Ok(Self {
+			prev_hlc: None,
+			trend: 0.0,
+			dm: 0.0,
+			cm: 0.0,
+			fast_ema: Ema::new(fast_period)?,
+			slow_ema: Ema::new(slow_period)?,
+			signal_ema: Ema::new(signal_period)?,
+		})
+	}
+}
+
+impl Period for KlingerOscillator {
+	fn period(&self) -> usize {
+		self.slow_ema.period()
+	}
+}
+
+impl Ready for KlingerOscillator {
+	fn is_ready(&self) -> bool {
+		self.slow_ema.is_ready() && self.signal_ema.is_ready()
+	}
+}
+
+impl<T: High + Low + Close + Volume> Next<&T> for KlingerOscillator {
+	type Output = KlingerOscillatorOutput;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		let hlc = input.high() + input.low() + input.close();
+		let dm = input.high() - input.low();
+
+		let prev_hlc = match self.prev_hlc.replace(hlc) {
+			Some(prev_hlc) => prev_hlc,
+			None => {
+				self.dm = dm;
+				self.cm = dm;
+				return KlingerOscillatorOutput {
+					kvo: 0.0,
+					signal: 0.0,
+				};
+			}
+		};
+
+		let trend = if hlc > prev_hlc { 1.0 } else { -1.0 };
+		self.cm = if trend == self.trend {
+			self.cm + dm
+		} else {
+			self.dm + dm
+		};
+		self.trend = trend;
+		self.dm = dm;
+
+		let volume_force = if self.cm == 0.0 {
+			0.0
+		} else {
+			input.volume() * (2.0 * (dm / self.cm - 1.0)).abs() * trend * 100.0
+		};
+
+		let kvo = self.fast_ema.next(volume_force) - self.slow_ema.next(volume_force);
+		let signal = self.signal_ema.next(kvo);
+
+		KlingerOscillatorOutput { kvo, signal }
+	}
+}
+
+impl Reset for KlingerOscillator {
+	fn reset(&mut self) {
+		self.prev_hlc = None;
+		self.trend = 0.0;
+		self.dm = 0.0;
+		self.cm = 0.0;
+		self.fast_ema.reset();
+		self.slow_ema.reset();
+		self.signal_ema.reset();
+	}
+}
+
+impl Default for KlingerOscillator {
+	fn default() -> Self {
+		Self::new(34, 55, 13).unwrap()
+	}
+}
+
+impl fmt::Display for KlingerOscillator {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"KVO({}, {}, {})",
+			self.fast_ema.period(),
+			self.slow_ema.period(),
+			self.signal_ema.period()
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	#[test]
+	fn test_new() {
+		assert!(KlingerOscillator::new(0, 55, 13).is_err());
+		assert!(KlingerOscillator::new(55, 55, 13).is_err());
+		assert!(KlingerOscillator::new(55, 34, 13).is_err());
+		assert!(KlingerOscillator::new(34, 55, 0).is_err());
+		assert!(KlingerOscillator::new(34, 55, 13).is_ok());
+	}
+
+	#[test]
+	fn test_next_bar() {
+		let test_data = vec![
+			// high, low, close, volume, kvo, signal
+			(10.0, 8.0, 9.0, 100.0, 0.0, 0.0),
+			(11.0, 9.0, 10.5, 200.0, 0.0, 0.0),
+			(12.0, 10.0, 11.0, 150.0, 0.0, 0.0),
+			(11.0, 8.0, 9.0, 300.0, -7333.333, -4888.889),
+			(10.0, 7.0, 7.5, 100.0, -4194.444, -4425.926),
+			(9.0, 7.0, 8.5, 50.0, -1523.148, -2490.741),
+		];
+
+		let mut kvo = KlingerOscillator::new(2, 3, 2).unwrap();
+
+		for (high, low, close, volume, expected_kvo, expected_signal) in test_data {
+			let bar = Bar::new().high(high).low(low).close(close).volume(volume);
+			let (kvo, signal) = kvo.next(&bar).into();
+			assert_eq!(round(kvo), expected_kvo);
+			assert_eq!(round(signal), expected_signal);
+		}
+	}
+
+	#[test]
+	fn test_next_zero_range() {
+		let mut kvo = KlingerOscillator::new(2, 3, 2).unwrap();
+		let bar = Bar::new().high(5).low(5).close(5).volume(100.0);
+		for _ in 0..3 {
+			assert_eq!(kvo.next(&bar).kvo, 0.0);
+		}
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut kvo = KlingerOscillator::new(2, 3, 2).unwrap();
+		let bar = Bar::new().high(5).low(4).close(5).volume(100.0);
+		for _ in 0..3 {
+			kvo.next(&bar);
+			assert!(!kvo.is_ready());
+		}
+		kvo.next(&bar);
+		assert!(kvo.is_ready());
+
+		kvo.reset();
+		assert!(!kvo.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut kvo = KlingerOscillator::new(2, 3, 2).unwrap();
+		let bars = [
+			Bar::new().high(10).low(8).close(9).volume(100.0),
+			Bar::new().high(11).low(9).close(10.5).volume(200.0),
+			Bar::new().high(12).low(10).close(11).volume(150.0),
+			Bar::new().high(11).low(8).close(9).volume(300.0),
+		];
+
+		for bar in &bars[..3] {
+			kvo.next(bar);
+		}
+		assert_eq!(round(kvo.next(&bars[3]).kvo), -7333.333);
+
+		kvo.reset();
+		for bar in &bars[..3] {
+			kvo.next(bar);
+		}
+		assert_eq!(round(kvo.next(&bars[3]).kvo), -7333.333);
+	}
+
+	#[test]
+	fn test_default() {
+		KlingerOscillator::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let indicator = KlingerOscillator::new(34, 55, 13).unwrap();
+		assert_eq!(format!("{}", indicator), "KVO(34, 55, 13)");
+	}
+}
@@ -0,0 +1,227 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Kurtosis (KURT).
+///
+/// Returns the adjusted sample excess kurtosis (Fisher's definition) of the last n values,
+/// a measure of the weight of the tails of their distribution. A normal distribution has
+/// an excess kurtosis of about 0, a heavy-tailed one a positive value.
+///
+/// Like [Skewness](struct.Skewness.html), the central moments are recomputed over the
+/// whole window on each period.
+///
+/// # Formula
+///
+/// KURT = N(N + 1) × Σ (x<sub>i</sub> - μ)<sup>4</sup> / ((N - 1)(N - 2)(N - 3) × s<sup>4</sup>) - 3(N - 1)² / ((N - 2)(N - 3))
+///
+/// Where:
+///
+/// * _μ_ - mean of the last _N_ values
+/// * _s_ - sample standard deviation of the last _N_ values
+/// * _N_ - number of values in the observation
+///
+/// Until 4 values have been consumed, or if all the values are equal, 0 is returned.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 3). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Kurtosis;
+/// use ta::Next;
+///
+/// let mut kurt = Kurtosis::new(4).unwrap();
+/// assert_eq!(kurt.next(1.0), 0.0);
+/// assert_eq!(kurt.next(2.0), 0.0);
+/// assert_eq!(kurt.next(3.0), 0.0);
+/// assert!(kurt.next(10.0) > 3.0);
+/// ```
+///
+/// # Links
+///
+/// * [Kurtosis, Wikipedia](https://en.wikipedia.org/wiki/Kurtosis#Estimators_of_population_kurtosis)
+#[doc(alias = "KURT")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Kurtosis {
+	period: usize,
+	index: usize,
+	count: usize,
+	deque: Box<[f64]>,
+}
+
+impl Kurtosis {
+	pub fn new(period: usize) -> Result<Self> {
+		match period {
+			0..=3 => Err(TaError::InvalidParameter),
+			_ => Ok(Self {
+				period,
+				index: 0,
+				count: 0,
+				deque: vec![0.0; period].into_boxed_slice(),
+			}),
+		}
+	}
+}
+
+impl Period for Kurtosis {
+	fn period(&self) -> usize {
+		self.period
+	}
+}
+
+impl Ready for Kurtosis {
+	fn is_ready(&self) -> bool {
+		self.count == self.period
+	}
+}
+
+impl Next<f64> for Kurtosis {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		self.deque[self.index] = input;
+		self.index = if self.index + 1 < self.period {
+			self.index + 1
+		} else {
+			0
+		};
+		if self.count < self.period {
+			self.count += 1;
+		}
+
+		if self.count < 4 {
+			return 0.0;
+		}
+
+		let values = &self.deque[..self.count];
+		let n = self.count as f64;
+		let mean = values.iter().sum::<f64>() / n;
+		let (m2, m4) = values.iter().fold((0.0, 0.0), |(m2, m4), x| {
+			let d2 = (x - mean) * (x - mean);
+			(m2 + d2, m4 + d2 * d2)
+		});
+
+		if m2 == 0.0 {
+			return 0.0;
+		}
+
+		let var = m2 / (n - 1.0);
+		n * (n + 1.0) * m4 / ((n - 1.0) * (n - 2.0) * (n - 3.0) * var * var)
+			- 3.0 * (n - 1.0) * (n - 1.0) / ((n - 2.0) * (n - 3.0))
+	}
+}
+
+impl<T: Close> Next<&T> for Kurtosis {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl Reset for Kurtosis {
+	fn reset(&mut self) {
+		self.index = 0;
+		self.count = 0;
+		for i in 0..self.period {
+			self.deque[i] = 0.0;
+		}
+	}
+}
+
+impl Default for Kurtosis {
+	fn default() -> Self {
+		Self::new(20).unwrap()
+	}
+}
+
+impl fmt::Display for Kurtosis {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "KURT({})", self.period)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	test_indicator!(Kurtosis);
+
+	#[test]
+	fn test_new() {
+		assert!(Kurtosis::new(0).is_err());
+		assert!(Kurtosis::new(3).is_err());
+		assert!(Kurtosis::new(4).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let mut kurt = Kurtosis::new(5).unwrap();
+		assert_eq!(kurt.next(10.0), 0.0);
+		assert_eq!(kurt.next(20.0), 0.0);
+		assert_eq!(kurt.next(30.0), 0.0);
+		assert_eq!(round(kurt.next(20.0)), 1.5);
+		assert_eq!(round(kurt.next(10.0)), -0.612);
+		assert_eq!(round(kurt.next(100.0)), 4.272);
+		assert_eq!(round(kurt.next(40.0)), 3.152);
+		assert_eq!(round(kurt.next(50.0)), 1.505);
+	}
+
+	#[test]
+	fn test_next_same_values() {
+		let mut kurt = Kurtosis::new(4).unwrap();
+		for _ in 0..6 {
+			assert_eq!(kurt.next(4.2), 0.0);
+		}
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut kurt = Kurtosis::new(4).unwrap();
+		kurt.next(1.0);
+		kurt.next(2.0);
+		kurt.next(3.0);
+		assert!(!kurt.is_ready());
+		kurt.next(4.0);
+		assert!(kurt.is_ready());
+
+		kurt.reset();
+		assert!(!kurt.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut kurt = Kurtosis::new(4).unwrap();
+		kurt.next(1.0);
+		kurt.next(2.0);
+		kurt.next(3.0);
+		assert_eq!(round(kurt.next(10.0)), 3.228);
+
+		kurt.reset();
+		assert_eq!(kurt.next(2.0), 0.0);
+		kurt.next(3.0);
+		kurt.next(10.0);
+		assert_eq!(round(kurt.next(1.0)), 3.228);
+	}
+
+	#[test]
+	fn test_default() {
+		Kurtosis::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let kurt = Kurtosis::new(20).unwrap();
+		assert_eq!(format!("{}", kurt), "KURT(20)");
+	}
+}
@@ -0,0 +1,331 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling linear regression (LINREG).
+///
+/// Fits a line to the last _n_ inputs with ordinary least squares, the time index of the
+/// window going from 0 for the oldest input to _n - 1_ for the current one.
+///
+/// # Formula
+///
+/// y = a + b × t
+///
+/// b = (n × Σty - Σt × Σy) / (n × Σt² - (Σt)²)
+///
+/// a = (Σy - b × Σt) / n
+///
+/// R² = 1 - Σ(y - a - b × t)² / Σ(y - ȳ)²
+///
+/// Where:
+///
+/// * _y_ - input value at the time index _t_ of the window
+/// * _b_ - slope of the line, _a_ - its intercept
+/// * _ȳ_ - mean of the inputs of the window
+///
+/// The forecast is the value of the line at the current bar, _a + b × (n - 1)_. Until _n_
+/// inputs have been consumed, the line is fitted to the inputs so far: the first one gives
+/// a slope of 0. If the inputs have not moved within the window, R² is taken as 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 1). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::LinearRegression;
+/// use ta::Next;
+///
+/// let mut linreg = LinearRegression::new(3).unwrap();
+/// linreg.next(1.0);
+/// assert_eq!(linreg.next(3.0).slope, 2.0);
+///
+/// let out = linreg.next(2.0);
+/// assert_eq!(out.slope, 0.5);
+/// assert_eq!(out.intercept, 1.5);
+/// assert_eq!(out.r_squared, 0.25);
+/// assert_eq!(out.forecast, 2.5);
+/// ```
+///
+/// # Links
+///
+/// * [Simple linear regression, Wikipedia](https://en.wikipedia.org/wiki/Simple_linear_regression)
+#[doc(alias = "LINREG")]
+#[doc(alias = "OLS")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearRegression {
+	period: usize,
+	index: usize,
+	count: usize,
+	sum_t: f64,
+	sum_t2: f64,
+	deque: Box<[f64]>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearRegressionOutput {
+	pub slope: f64,
+	pub intercept: f64,
+	pub r_squared: f64,
+	/// Value of the fitted line at the current bar.
+	pub forecast: f64,
+}
+
+impl From<LinearRegressionOutput> for (f64, f64, f64, f64) {
+	fn from(lr: LinearRegressionOutput) -> Self {
+		(lr.slope, lr.intercept, lr.r_squared, lr.forecast)
+	}
+}
+
+/// Σt and Σt² of the time indexes 0 to n - 1.
+fn time_sums(n: usize) -> (f64, f64) {
+	let n = n as f64;
+	(n * (n - 1.0) / 2.0, (n - 1.0) * n * (2.0 * n - 1.0) / 6.0)
+}
+
+impl LinearRegression {
+	pub fn new(period: usize) -> Result<Self> {
+		match period {
+			0 | 1 => Err(TaError::InvalidParameter),
+			_ => {
+				let (sum_t, sum_t2) = time_sums(period);
+				Ok(Self {
+					period,
+					index: 0,
+					count: 0,
+					sum_t,
+					sum_t2,
+					deque: vec![0.0; period].into_boxed_slice(),
+				})
+			}
+		}
+	}
+
+	/// Inputs of the window, from the oldest to the current one.
+	fn window(&self) -> impl Iterator<Item = f64> + '_ {
+		let (newer, older) = self.deque[..self.count].split_at(self.index);
+		older.iter().chain(newer).copied()
+	}
+
+	/// Number of inputs of the window, which is lower than the period during the warm-up.
+	pub(crate) fn count(&self) -> usize {
+		self.count
+	}
+
+	/// Sum of the squared residuals of the window around the given line.
+	pub(crate) fn residual_sum_of_squares(&self, slope: f64, intercept: f64) -> f64 {
+		self.window()
+			.enumerate()
+			.map(|(t, y)| {
+				let residual = y - (intercept + slope * t as f64);
+				residual * residual
+			})
+			.sum()
+	}
+}
+
+impl Period for LinearRegression {
+	fn period(&self) -> usize {
+		self.period
+	}
+}
+
+impl Ready for LinearRegression {
+	fn is_ready(&self) -> bool {
+		self.count == self.period
+	}
+}
+
+impl Next<f64> for LinearRegression {
+	type Output = LinearRegressionOutput;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		self.deque[self.index] = input;
+		self.index = if self.index + 1 < self.period {
+			self.index + 1
+		} else {
+			0
+		};
+		if self.count < self.period {
+			self.count += 1;
+		}
+
+		let (sum_t, sum_t2) = if self.count == self.period {
+			(self.sum_t, self.sum_t2)
+		} else {
+			time_sums(self.count)
+		};
+		let n = self.count as f64;
+
+		let (mut sum_y, mut sum_ty) = (0.0, 0.0);
+		for (t, y) in self.window().enumerate() {
+			sum_y += y;
+			sum_ty += t as f64 * y;
+		}
+
+		let denominator = n * sum_t2 - sum_t * sum_t;
+		let slope = if denominator == 0.0 {
+			// a single input
+			0.0
+		} else {
+			(n * sum_ty - sum_t * sum_y) / denominator
+		};
+		let intercept = (sum_y - slope * sum_t) / n;
+
+		let mean = sum_y / n;
+		let ss_tot: f64 = self.window().map(|y| (y - mean) * (y - mean)).sum();
+		let r_squared = if ss_tot == 0.0 {
+			0.0
+		} else {
+			1.0 - self.residual_sum_of_squares(slope, intercept) / ss_tot
+		};
+
+		LinearRegressionOutput {
+			slope,
+			intercept,
+			r_squared,
+			forecast: intercept + slope * (n - 1.0),
+		}
+	}
+}
+
+impl<T: Close> Next<&T> for LinearRegression {
+	type Output = LinearRegressionOutput;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl Reset for LinearRegression {
+	fn reset(&mut self) {
+		self.index = 0;
+		self.count = 0;
+		for i in 0..self.period {
+			self.deque[i] = 0.0;
+		}
+	}
+}
+
+impl Default for LinearRegression {
+	fn default() -> Self {
+		Self::new(14).unwrap()
+	}
+}
+
+impl fmt::Display for LinearRegression {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "LINREG({})", self.period)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	test_indicator!(LinearRegression);
+
+	#[test]
+	fn test_new() {
+		assert!(LinearRegression::new(0).is_err());
+		assert!(LinearRegression::new(1).is_err());
+		assert!(LinearRegression::new(2).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let test_data = vec![
+			// input, slope, intercept, r², forecast
+			(1.0, 0.0, 1.0, 0.0, 1.0),
+			(3.0, 2.0, 1.0, 1.0, 3.0),
+			(2.0, 0.5, 1.5, 0.25, 2.5),
+			(5.0, 1.0, 2.333, 0.429, 4.333),
+			(5.0, 1.5, 2.5, 0.75, 5.5),
+		];
+
+		let mut linreg = LinearRegression::new(3).unwrap();
+
+		for (input, slope, intercept, r_squared, forecast) in test_data {
+			let out = linreg.next(input);
+			assert_eq!(round(out.slope), slope);
+			assert_eq!(round(out.intercept), intercept);
+			assert_eq!(round(out.r_squared), r_squared);
+			assert_eq!(round(out.forecast), forecast);
+		}
+	}
+
+	#[test]
+	fn test_next_linear() {
+		let mut linreg = LinearRegression::new(5).unwrap();
+		for i in 0..20 {
+			let input = 3.0 - 0.5 * i as f64;
+			let out = linreg.next(input);
+			if i > 0 {
+				assert_eq!(out.slope, -0.5);
+				assert_eq!(out.r_squared, 1.0);
+				assert_eq!(out.forecast, input);
+			}
+		}
+	}
+
+	#[test]
+	fn test_next_flat() {
+		let mut linreg = LinearRegression::new(3).unwrap();
+		for _ in 0..5 {
+			let (slope, intercept, r_squared, forecast) = linreg.next(4.0).into();
+			assert_eq!(
+				(slope, intercept, r_squared, forecast),
+				(0.0, 4.0, 0.0, 4.0)
+			);
+		}
+	}
+
+	#[test]
+	fn test_next_with_bars() {
+		let mut linreg = LinearRegression::new(2).unwrap();
+		linreg.next(&Bar::new().close(4));
+		assert_eq!(linreg.next(&Bar::new().close(7)).slope, 3.0);
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut linreg = LinearRegression::new(2).unwrap();
+		linreg.next(1.0);
+		assert!(!linreg.is_ready());
+		linreg.next(2.0);
+		assert!(linreg.is_ready());
+
+		linreg.reset();
+		assert!(!linreg.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut linreg = LinearRegression::new(3).unwrap();
+		linreg.next(1.0);
+		linreg.next(3.0);
+
+		linreg.reset();
+		assert_eq!(linreg.next(5.0).forecast, 5.0);
+		assert_eq!(linreg.next(4.0).slope, -1.0);
+	}
+
+	#[test]
+	fn test_default() {
+		assert_eq!(LinearRegression::default().period(), 14);
+	}
+
+	#[test]
+	fn test_display() {
+		let linreg = LinearRegression::new(10).unwrap();
+		assert_eq!(format!("{}", linreg), "LINREG(10)");
+	}
+}
@@ -0,0 +1,177 @@
+use core::fmt;
+
+use num_traits::Float;
+
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Logarithmic return.
+///
+/// Returns the natural logarithm of the ratio between the current and the previous price.
+/// Unlike the [rate of change](struct.RateOfChange.html), log returns add up over time,
+/// which makes them the usual input of volatility and risk measures.
+///
+/// # Formula
+///
+/// LR = ln(P<sub>t</sub> / P<sub>t-1</sub>)
+///
+/// Where:
+///
+/// * _P<sub>t</sub>_ - price of the current period
+///
+/// The first period has no previous price, so 0 is returned. The logarithm is undefined if
+/// the current or the previous price is 0 or negative, in which case `f64::NAN` is returned.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::LogReturn;
+/// use ta::Next;
+///
+/// let mut lr = LogReturn::new();
+/// assert_eq!(lr.next(100.0), 0.0);
+/// assert_eq!(lr.next(100.0), 0.0);
+/// assert!(lr.next(110.0) > 0.0);
+/// assert!(lr.next(0.0).is_nan());
+/// ```
+///
+/// # Links
+///
+/// * [Logarithmic return, Wikipedia](https://en.wikipedia.org/wiki/Rate_of_return#Logarithmic_or_continuously_compounded_return)
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogReturn {
+	prev_price: Option<f64>,
+}
+
+impl LogReturn {
+	pub fn new() -> Self {
+		Self { prev_price: None }
+	}
+}
+
+impl Period for LogReturn {
+	fn period(&self) -> usize {
+		1
+	}
+}
+
+impl Ready for LogReturn {
+	fn is_ready(&self) -> bool {
+		self.prev_price.is_some()
+	}
+}
+
+impl Next<f64> for LogReturn {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		match self.prev_price.replace(input) {
+			Some(prev_price) if prev_price > 0.0 && input > 0.0 => Float::ln(input / prev_price),
+			Some(_) => f64::NAN,
+			None => 0.0,
+		}
+	}
+}
+
+impl<T: Close> Next<&T> for LogReturn {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl Reset for LogReturn {
+	fn reset(&mut self) {
+		self.prev_price = None;
+	}
+}
+
+impl Default for LogReturn {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl fmt::Display for LogReturn {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "LOG_RETURN")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	test_indicator!(LogReturn);
+
+	#[test]
+	fn test_next() {
+		let mut lr = LogReturn::new();
+		assert_eq!(lr.next(100.0), 0.0);
+		assert_eq!(round(lr.next(110.0)), 0.095);
+		assert_eq!(lr.next(110.0), 0.0);
+		assert_eq!(round(lr.next(100.0)), -0.095);
+	}
+
+	#[test]
+	fn test_next_additive() {
+		let mut lr = LogReturn::new();
+		lr.next(100.0);
+		let sum = lr.next(120.0) + lr.next(90.0) + lr.next(150.0);
+		assert_eq!(round(sum), round((150.0f64 / 100.0).ln()));
+	}
+
+	#[test]
+	fn test_next_non_positive_price() {
+		let mut lr = LogReturn::new();
+		assert_eq!(lr.next(0.0), 0.0);
+		assert!(lr.next(10.0).is_nan());
+		assert!(lr.next(-10.0).is_nan());
+		assert!(lr.next(10.0).is_nan());
+		assert_eq!(lr.next(10.0), 0.0);
+	}
+
+	#[test]
+	fn test_next_with_bars() {
+		let mut lr = LogReturn::new();
+		assert_eq!(lr.next(&Bar::new().close(100)), 0.0);
+		assert_eq!(round(lr.next(&Bar::new().close(110))), 0.095);
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut lr = LogReturn::new();
+		assert!(!lr.is_ready());
+		lr.next(1.0);
+		assert!(lr.is_ready());
+
+		lr.reset();
+		assert!(!lr.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut lr = LogReturn::new();
+		lr.next(100.0);
+		assert_eq!(round(lr.next(110.0)), 0.095);
+
+		lr.reset();
+		assert_eq!(lr.next(110.0), 0.0);
+		assert_eq!(round(lr.next(100.0)), -0.095);
+	}
+
+	#[test]
+	fn test_default() {
+		LogReturn::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let lr = LogReturn::new();
+		assert_eq!(format!("{}", lr), "LOG_RETURN");
+	}
+}
@@ -0,0 +1,209 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{ExponentialMovingAverage as Ema, RollingSum};
+use crate::{High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Mass Index (MI).
+///
+/// Identifies trend reversals by measuring the widening and narrowing of the range between
+/// the high and the low. A "reversal bulge" occurs when the index rises above 27 and then
+/// falls below 26.5.
+///
+/// # Formula
+///
+/// MI = Σ EMA(H - L, n) / EMA(EMA(H - L, n), n) over the last _m_ periods
+///
+/// Where:
+///
+/// * _H_ - high price
+/// * _L_ - low price
+/// * _n_ - period of the EMAs
+/// * _m_ - period of the sum
+///
+/// If the double smoothed EMA is 0, the ratio is taken as 0.
+///
+/// # Parameters
+///
+/// * _ema_period_ - period of the EMAs (integer greater than 0). Default is 9.
+/// * _sum_period_ - number of ratios to sum (integer greater than 0). Default is 25.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::MassIndex;
+/// use ta::{DataItem, Next};
+///
+/// let mut mi = MassIndex::new(2, 3).unwrap();
+///
+/// let di = DataItem::builder()
+///             .high(10.0)
+///             .low(8.0)
+///             .close(9.0)
+///             .open(9.0)
+///             .volume(100.0)
+///             .build().unwrap();
+///
+/// assert_eq!(mi.next(&di), 1.0);
+/// assert_eq!(mi.next(&di), 2.0);
+/// ```
+///
+/// # Links
+///
+/// * [Mass index, Wikipedia](https://en.wikipedia.org/wiki/Mass_index)
+#[doc(alias = "MI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MassIndex {
+	count: usize,
+	ema1: Ema,
+	ema2: Ema,
+	sum: RollingSum,
+}
+
+impl MassIndex {
+	pub fn new(ema_period: usize, sum_period: usize) -> Result<Self> {
+		Ok(Self {
+			count: 0,
+			ema1: Ema::new(ema_period)?,
+			ema2: Ema::new(ema_period)?,
+			sum: RollingSum::new(sum_period)?,
+		})
+	}
+
+	fn ready_count(&self) -> usize {
+		2 * (self.ema1.period() - 1) + self.sum.period()
+	}
+}
+
+impl Period for MassIndex {
+	fn period(&self) -> usize {
+		self.sum.period()
+	}
+}
+
+impl Ready for MassIndex {
+	fn is_ready(&self) -> bool {
+		self.count == self.ready_count()
+	}
+}
+
+impl<T: High + Low> Next<&T> for MassIndex {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		if self.count < self.ready_count() {
+			self.count += 1;
+		}
+
+		let ema1 = self.ema1.next(input.high() - input.low());
+		let ema2 = self.ema2.next(ema1);
+		let ratio = if ema2 == 0.0 { 0.0 } else { ema1 / ema2 };
+
+		self.sum.next(ratio)
+	}
+}
+
+impl Reset for MassIndex {
+	fn reset(&mut self) {
+		self.count = 0;
+		self.ema1.reset();
+		self.ema2.reset();
+		self.sum.reset();
+	}
+}
+
+impl Default for MassIndex {
+	fn default() -> Self {
+		Self::new(9, 25).unwrap()
+	}
+}
+
+impl fmt::Display for MassIndex {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "MI({}, {})", self.ema1.period(), self.sum.period())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	#[test]
+	fn test_new() {
+		assert!(MassIndex::new(0, 25).is_err());
+		assert!(MassIndex::new(9, 0).is_err());
+		assert!(MassIndex::new(9, 25).is_ok());
+	}
+
+	#[test]
+	fn test_next_bar() {
+		let test_data = vec![
+			// high, low, expected
+			(10.0, 8.0, 1.0),
+			(11.0, 8.0, 2.091),
+			(12.0, 9.0, 3.145),
+			(12.0, 11.0, 2.96),
+			(13.0, 10.0, 2.946),
+			(11.0, 10.0, 2.734),
+		];
+
+		let mut mi = MassIndex::new(2, 3).unwrap();
+
+		for (high, low, expected) in test_data {
+			let bar = Bar::new().high(high).low(low);
+			assert_eq!(round(mi.next(&bar)), expected);
+		}
+	}
+
+	#[test]
+	fn test_next_zero_range() {
+		let mut mi = MassIndex::new(2, 3).unwrap();
+		let bar = Bar::new().high(5).low(5);
+		assert_eq!(mi.next(&bar), 0.0);
+		assert_eq!(mi.next(&bar), 0.0);
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut mi = MassIndex::new(2, 3).unwrap();
+		let bar = Bar::new().high(5).low(4);
+		for _ in 0..4 {
+			mi.next(&bar);
+			assert!(!mi.is_ready());
+		}
+		mi.next(&bar);
+		assert!(mi.is_ready());
+
+		mi.reset();
+		assert!(!mi.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut mi = MassIndex::new(2, 3).unwrap();
+		let bar1 = Bar::new().high(10).low(8);
+		let bar2 = Bar::new().high(11).low(8);
+
+		assert_eq!(mi.next(&bar1), 1.0);
+		assert_eq!(round(mi.next(&bar2)), 2.091);
+
+		mi.reset();
+		assert_eq!(mi.next(&bar1), 1.0);
+		assert_eq!(round(mi.next(&bar2)), 2.091);
+	}
+
+	#[test]
+	fn test_default() {
+		MassIndex::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let indicator = MassIndex::new(9, 25).unwrap();
+		assert_eq!(format!("{}", indicator), "MI(9, 25)");
+	}
+}
@@ -0,0 +1,221 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Maximum Drawdown (MDD).
+///
+/// Returns the largest peak-to-trough decline within the last n prices, as a fraction of
+/// the peak: 0 means no drawdown, 0.3 a maximum drawdown of 30%. It ranges from 0 to 1
+/// for positive prices.
+///
+/// The window is scanned from the oldest to the newest price on each period while keeping
+/// track of the running peak, which is O(n).
+///
+/// # Formula
+///
+/// MDD = max<sub>i ≤ j</sub> (P<sub>i</sub> - P<sub>j</sub>) / P<sub>i</sub>
+///
+/// Where:
+///
+/// * _P<sub>i</sub>_, _P<sub>j</sub>_ - prices of the last _n_ periods, _P<sub>i</sub>_ not after _P<sub>j</sub>_
+///
+/// Peaks that are not positive are ignored.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 252.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::MaxDrawdown;
+/// use ta::Next;
+///
+/// let mut mdd = MaxDrawdown::new(3).unwrap();
+/// assert_eq!(mdd.next(100.0), 0.0);
+/// assert_eq!(mdd.next(80.0), 0.2);
+/// assert_eq!(mdd.next(90.0), 0.2);
+/// assert_eq!(mdd.next(95.0), 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Drawdown, Wikipedia](https://en.wikipedia.org/wiki/Drawdown_(economics))
+#[doc(alias = "MDD")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaxDrawdown {
+	period: usize,
+	index: usize,
+	count: usize,
+	deque: Box<[f64]>,
+}
+
+impl MaxDrawdown {
+	pub fn new(period: usize) -> Result<Self> {
+		match period {
+			0 => Err(TaError::InvalidParameter),
+			_ => Ok(Self {
+				period,
+				index: 0,
+				count: 0,
+				deque: vec![0.0; period].into_boxed_slice(),
+			}),
+		}
+	}
+}
+
+impl Period for MaxDrawdown {
+	fn period(&self) -> usize {
+		self.period
+	}
+}
+
+impl Ready for MaxDrawdown {
+	fn is_ready(&self) -> bool {
+		self.count == self.period
+	}
+}
+
+impl Next<f64> for MaxDrawdown {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		self.deque[self.index] = input;
+		self.index = if self.index + 1 < self.period {
+			self.index + 1
+		} else {
+			0
+		};
+		if self.count < self.period {
+			self.count += 1;
+		}
+
+		// the oldest value is the next one to be overwritten once the window is full
+		let oldest = if self.count < self.period {
+			0
+		} else {
+			self.index
+		};
+
+		let mut peak = f64::NEG_INFINITY;
+		let mut max_drawdown = 0.0;
+		for i in 0..self.count {
+			let price = self.deque[(oldest + i) % self.period];
+			if price > peak {
+				peak = price;
+			} else if peak > 0.0 {
+				let drawdown = (peak - price) / peak;
+				if drawdown > max_drawdown {
+					max_drawdown = drawdown;
+				}
+			}
+		}
+
+		max_drawdown
+	}
+}
+
+impl<T: Close> Next<&T> for MaxDrawdown {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl Reset for MaxDrawdown {
+	fn reset(&mut self) {
+		self.index = 0;
+		self.count = 0;
+		for i in 0..self.period {
+			self.deque[i] = 0.0;
+		}
+	}
+}
+
+impl Default for MaxDrawdown {
+	fn default() -> Self {
+		Self::new(252).unwrap()
+	}
+}
+
+impl fmt::Display for MaxDrawdown {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "MDD({})", self.period)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	test_indicator!(MaxDrawdown);
+
+	#[test]
+	fn test_new() {
+		assert!(MaxDrawdown::new(0).is_err());
+		assert!(MaxDrawdown::new(1).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let mut mdd = MaxDrawdown::new(4).unwrap();
+		assert_eq!(mdd.next(100.0), 0.0);
+		assert_eq!(mdd.next(110.0), 0.0);
+		assert_eq!(round(mdd.next(99.0)), 0.1);
+		assert_eq!(round(mdd.next(105.0)), 0.1);
+		assert_eq!(round(mdd.next(88.0)), 0.2);
+		assert_eq!(round(mdd.next(120.0)), 0.162);
+		assert_eq!(round(mdd.next(90.0)), 0.25);
+	}
+
+	#[test]
+	fn test_next_rising() {
+		let mut mdd = MaxDrawdown::new(3).unwrap();
+		for x in 1..10 {
+			assert_eq!(mdd.next(x as f64), 0.0);
+		}
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut mdd = MaxDrawdown::new(3).unwrap();
+		mdd.next(1.0);
+		mdd.next(2.0);
+		assert!(!mdd.is_ready());
+		mdd.next(3.0);
+		assert!(mdd.is_ready());
+
+		mdd.reset();
+		assert!(!mdd.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut mdd = MaxDrawdown::new(3).unwrap();
+		mdd.next(100.0);
+		assert_eq!(mdd.next(50.0), 0.5);
+
+		mdd.reset();
+		assert_eq!(mdd.next(50.0), 0.0);
+		assert_eq!(mdd.next(40.0), 0.2);
+	}
+
+	#[test]
+	fn test_default() {
+		MaxDrawdown::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let mdd = MaxDrawdown::new(252).unwrap();
+		assert_eq!(format!("{}", mdd), "MDD(252)");
+	}
+}
@@ -1,12 +1,20 @@
-use std::fmt;
+use alloc::collections::VecDeque;
+use core::fmt;
 
-use crate::errors::{Result, TaError};
-use crate::{High, Next, Period, Reset};
+use num_traits::Float;
+
+use crate::errors::Result;
+use crate::types;
+use crate::{High, Next, Period, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Returns the highest value in a given time frame.
 ///
+/// The candidates are kept in a monotonic deque, so each value costs O(1) amortized
+/// whatever the period. NaN inputs are ignored, NaN is only returned if the window holds
+/// nothing else.
+///
 /// # Parameters
 ///
 /// * _period_ - size of the time frame (integer greater than 0). Default value is 14.
@@ -25,83 +33,102 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(max.next(8.0), 8.0);
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone)]
-pub struct Maximum {
+#[derive(Debug, Clone, PartialEq)]
+pub struct Maximum<T = f64> {
 	period: usize,
-	max_index: usize,
-	cur_index: usize,
-	deque: Box<[f64]>,
+	// number of inputs received, the sequence index of the next one
+	count: usize,
+	// the candidates for the highest value and their sequence indexes, in the order they were
+	// received: NaN inputs are never candidates, every value is
+	// lower than or equal to the previous one, the front is the result
+	monotonic: VecDeque<(usize, T)>,
 }
 
 impl Maximum {
 	pub fn new(period: usize) -> Result<Self> {
-		match period {
-			0 => Err(TaError::InvalidParameter),
-			_ => Ok(Self {
-				period,
-				max_index: 0,
-				cur_index: 0,
-				deque: vec![f64::NEG_INFINITY; period].into_boxed_slice(),
-			}),
-		}
+		Self::new_generic(period)
 	}
 
-	fn find_max_index(&self) -> usize {
-		let mut max = f64::NEG_INFINITY;
-		let mut index: usize = 0;
+	/// Creates the indicator from an already validated period, which cannot fail.
+	pub fn new_with_period(period: types::PeriodValue) -> Self {
+		Self::new_generic_with_period(period)
+	}
+}
 
-		for (i, &val) in self.deque.iter().enumerate() {
-			if val > max {
-				max = val;
-				index = i;
-			}
-		}
+impl<T: Float> Maximum<T> {
+	/// Creates the indicator for another float type than `f64`, for example
+	/// `Maximum::<f32>::new_generic(3)`.
+	pub fn new_generic(period: usize) -> Result<Self> {
+		Ok(Self::new_generic_with_period(types::PeriodValue::try_from(
+			period,
+		)?))
+	}
 
-		index
+	/// Same as [new_with_period](#method.new_with_period) for another float type than `f64`.
+	pub fn new_generic_with_period(period: types::PeriodValue) -> Self {
+		let period = period.get();
+		Self {
+			period,
+			count: 0,
+			monotonic: VecDeque::with_capacity(period),
+		}
 	}
 }
 
-impl Period for Maximum {
+impl<T> Period for Maximum<T> {
 	fn period(&self) -> usize {
 		self.period
 	}
 }
 
-impl Next<f64> for Maximum {
-	type Output = f64;
+impl<T> Ready for Maximum<T> {
+	fn is_ready(&self) -> bool {
+		self.count >= self.period
+	}
+}
 
-	fn next(&mut self, input: f64) -> Self::Output {
-		self.deque[self.cur_index] = input;
+impl<T: Float> Next<T> for Maximum<T> {
+	type Output = T;
 
-		if input > self.deque[self.max_index] {
-			self.max_index = self.cur_index;
-		} else if self.max_index == self.cur_index {
-			self.max_index = self.find_max_index();
+	fn next(&mut self, input: T) -> Self::Output {
+		// the candidate received `period` inputs ago leaves the window
+		if self
+			.monotonic
+			.front()
+			.is_some_and(|&(index, _)| index + self.period <= self.count)
+		{
+			self.monotonic.pop_front();
 		}
+		let index = self.count;
+		self.count += 1;
 
-		self.cur_index = if self.cur_index + 1 < self.period {
-			self.cur_index + 1
-		} else {
-			0
-		};
+		if input.is_nan() {
+			// NaN is ignored, unless the window only holds NaN
+			return self.monotonic.front().map_or(input, |&(_, v)| v);
+		}
+
+		// values lower than the input can never be the highest again
+		while self.monotonic.back().is_some_and(|&(_, v)| v < input) {
+			self.monotonic.pop_back();
+		}
+		self.monotonic.push_back((index, input));
 
-		self.deque[self.max_index]
+		self.monotonic[0].1
 	}
 }
 
-impl<T: High> Next<&T> for Maximum {
+impl<U: High> Next<&U> for Maximum {
 	type Output = f64;
 
-	fn next(&mut self, input: &T) -> Self::Output {
+	fn next(&mut self, input: &U) -> Self::Output {
 		self.next(input.high())
 	}
 }
 
-impl Reset for Maximum {
+impl<T> Reset for Maximum<T> {
 	fn reset(&mut self) {
-		for i in 0..self.period {
-			self.deque[i] = f64::NEG_INFINITY;
-		}
+		self.count = 0;
+		self.monotonic.clear();
 	}
 }
 
@@ -111,7 +138,7 @@ impl Default for Maximum {
 	}
 }
 
-impl fmt::Display for Maximum {
+impl<T> fmt::Display for Maximum<T> {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		write!(f, "MAX({})", self.period)
 	}
@@ -130,6 +157,13 @@ mod tests {
 		assert!(Maximum::new(1).is_ok());
 	}
 
+	#[test]
+	fn test_new_with_period() {
+		let period = types::PeriodValue::try_from(3_usize).unwrap();
+		assert_eq!(Maximum::new_with_period(period).period(), 3);
+		assert_eq!(Maximum::<f32>::new_generic_with_period(period).period(), 3);
+	}
+
 	#[test]
 	fn test_next() {
 		let mut max = Maximum::new(3).unwrap();
@@ -159,6 +193,39 @@ mod tests {
 		assert_eq!(max.next(&bar(2.0)), 3.5);
 	}
 
+	#[test]
+	fn test_next_sliding_window() {
+		let inputs: Vec<f64> = (0..50).map(|i| ((i * 37) % 11) as f64).collect();
+		let mut max = Maximum::new(4).unwrap();
+		for (i, &x) in inputs.iter().enumerate() {
+			let window = &inputs[i.saturating_sub(3)..=i];
+			let expected = window.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+			assert_eq!(max.next(x), expected);
+		}
+	}
+
+	#[test]
+	fn test_next_nan() {
+		let mut max = Maximum::new(3).unwrap();
+		assert!(max.next(f64::NAN).is_nan());
+		assert_eq!(max.next(4.0), 4.0);
+		assert_eq!(max.next(6.0), 6.0);
+		assert_eq!(max.next(5.0), 6.0);
+
+		// NaN is ignored and leaves the window like any other input
+		assert_eq!(max.next(f64::NAN), 6.0);
+		assert_eq!(max.next(f64::NAN), 5.0);
+		assert!(max.next(f64::NAN).is_nan());
+		for i in 0..1000 {
+			max.next(if i % 2 == 0 { f64::NAN } else { i as f64 });
+			assert!(max.monotonic.len() <= 3);
+		}
+		for x in &[3.0, 2.0, 1.0] {
+			max.next(*x);
+		}
+		assert_eq!(max.next(7.0), 7.0);
+	}
+
 	#[test]
 	fn test_reset() {
 		let mut max = Maximum::new(100).unwrap();
@@ -170,6 +237,18 @@ mod tests {
 		assert_eq!(max.next(4.0), 4.0);
 	}
 
+	#[test]
+	fn test_is_ready() {
+		let mut max = Maximum::new(2).unwrap();
+		max.next(4.0);
+		assert!(!max.is_ready());
+		max.next(5.0);
+		assert!(max.is_ready());
+
+		max.reset();
+		assert!(!max.is_ready());
+	}
+
 	#[test]
 	fn test_default() {
 		Maximum::default();
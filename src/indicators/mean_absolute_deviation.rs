@@ -1,10 +1,12 @@
-use std::fmt;
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
+use crate::{Close, Next, Period, Ready, Reset};
 
 /// Mean Absolute Deviation (MAD)
 ///
@@ -28,7 +30,7 @@ use crate::{Close, Next, Period, Reset};
 /// * [Mean Absolute Deviation, Wikipedia](https://en.wikipedia.org/wiki/Mean_absolute_deviation)
 ///
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct MeanAbsoluteDeviation {
 	period: usize,
 	index: usize,
@@ -58,6 +60,12 @@ impl Period for MeanAbsoluteDeviation {
 	}
 }
 
+impl Ready for MeanAbsoluteDeviation {
+	fn is_ready(&self) -> bool {
+		self.count == self.period
+	}
+}
+
 impl Next<f64> for MeanAbsoluteDeviation {
 	type Output = f64;
 
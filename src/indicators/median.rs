@@ -0,0 +1,218 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SortedWindow;
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling median (MEDIAN).
+///
+/// Returns the exact median of the last n values. Unlike mean based indicators, it is not
+/// pulled by a single outlier tick.
+///
+/// The last n values are kept both in insertion order, to know which one to evict, and in a
+/// sorted copy, updated by insertion on each period in O(n). NaN is ranked above every other
+/// value.
+///
+/// If the number of values is even, the mean of the two middle values is returned.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Median;
+/// use ta::Next;
+///
+/// let mut median = Median::new(3).unwrap();
+/// assert_eq!(median.next(5.0), 5.0);
+/// assert_eq!(median.next(1.0), 3.0);
+/// assert_eq!(median.next(100.0), 5.0);
+/// assert_eq!(median.next(2.0), 2.0);
+/// ```
+///
+/// # Links
+///
+/// * [Median filter, Wikipedia](https://en.wikipedia.org/wiki/Median_filter)
+#[doc(alias = "MEDIAN")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Median {
+	window: SortedWindow,
+}
+
+impl Median {
+	pub fn new(period: usize) -> Result<Self> {
+		Ok(Self {
+			window: SortedWindow::new(period)?,
+		})
+	}
+}
+
+impl Period for Median {
+	fn period(&self) -> usize {
+		self.window.period()
+	}
+}
+
+impl Ready for Median {
+	fn is_ready(&self) -> bool {
+		self.window.is_ready()
+	}
+}
+
+impl Next<f64> for Median {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		self.window.push(input);
+		let sorted = self.window.sorted();
+
+		let len = sorted.len();
+		if len % 2 == 1 {
+			sorted[len / 2]
+		} else {
+			(sorted[len / 2 - 1] + sorted[len / 2]) / 2.0
+		}
+	}
+}
+
+impl<T: Close> Next<&T> for Median {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl Reset for Median {
+	fn reset(&mut self) {
+		self.window.reset();
+	}
+}
+
+impl Default for Median {
+	fn default() -> Self {
+		Self::new(9).unwrap()
+	}
+}
+
+impl fmt::Display for Median {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "MEDIAN({})", self.window.period())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	test_indicator!(Median);
+
+	#[test]
+	fn test_new() {
+		assert!(Median::new(0).is_err());
+		assert!(Median::new(1).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let mut median = Median::new(4).unwrap();
+		assert_eq!(median.next(5.0), 5.0);
+		assert_eq!(median.next(1.0), 3.0);
+		assert_eq!(median.next(3.0), 3.0);
+		assert_eq!(median.next(8.0), 4.0);
+		assert_eq!(median.next(2.0), 2.5);
+		assert_eq!(median.next(9.0), 5.5);
+		assert_eq!(median.next(4.0), 6.0);
+	}
+
+	#[test]
+	fn test_next_duplicates() {
+		let mut median = Median::new(3).unwrap();
+		assert_eq!(median.next(2.0), 2.0);
+		assert_eq!(median.next(2.0), 2.0);
+		assert_eq!(median.next(7.0), 2.0);
+		assert_eq!(median.next(7.0), 7.0);
+		assert_eq!(median.next(1.0), 7.0);
+		assert_eq!(median.next(1.0), 1.0);
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut median = Median::new(3).unwrap();
+		median.next(1.0);
+		median.next(2.0);
+		assert!(!median.is_ready());
+		median.next(3.0);
+		assert!(median.is_ready());
+
+		median.reset();
+		assert!(!median.is_ready());
+	}
+
+	#[test]
+	fn test_next_nan() {
+		let inputs = [
+			6.0,
+			4.0,
+			3.0,
+			8.0,
+			9.0,
+			8.0,
+			4.0,
+			f64::NAN,
+			4.0,
+			f64::NAN,
+			8.0,
+			9.0,
+			8.0,
+			5.0,
+			1.0,
+			6.0,
+			7.0,
+			3.0,
+			6.0,
+			6.0,
+		];
+		let mut median = Median::new(4).unwrap();
+		for x in &inputs {
+			median.next(*x);
+		}
+		assert_eq!(median.next(5.0), 5.5);
+
+		let mut median = Median::new(3).unwrap();
+		median.next(1.0);
+		median.next(f64::NAN);
+		assert_eq!(median.next(2.0), 2.0);
+		assert!(median.next(f64::NAN).is_nan());
+		assert_eq!(median.next(3.0), 3.0);
+		assert_eq!(median.next(4.0), 4.0);
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut median = Median::new(3).unwrap();
+		assert_eq!(median.next(5.0), 5.0);
+		assert_eq!(median.next(1.0), 3.0);
+
+		median.reset();
+		assert_eq!(median.next(10.0), 10.0);
+		assert_eq!(median.next(20.0), 15.0);
+	}
+
+	#[test]
+	fn test_default() {
+		Median::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let median = Median::new(9).unwrap();
+		assert_eq!(format!("{}", median), "MEDIAN(9)");
+	}
+}
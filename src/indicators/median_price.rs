@@ -0,0 +1,129 @@
+use core::fmt;
+
+use crate::{High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Median price (MEDPRICE).
+///
+/// The middle of the range of a bar, a price transform which other indicators can be applied
+/// to instead of the close price. It has no state, so it is ready from the first input.
+///
+/// # Formula
+///
+/// MEDPRICE = (H<sub>t</sub> + L<sub>t</sub>) / 2
+///
+/// Where:
+///
+/// * _H<sub>t</sub>_, _L<sub>t</sub>_ - high and low prices of the current period
+///
+/// When a single `f64` value is given, it is both the high and the low, and is returned as is.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::MedianPrice;
+/// use ta::{DataItem, Next};
+///
+/// let mut median_price = MedianPrice::new();
+///
+/// let di = DataItem::builder()
+///             .high(12.0)
+///             .low(9.0)
+///             .close(10.0)
+///             .open(11.0)
+///             .volume(1000.0)
+///             .build().unwrap();
+///
+/// assert_eq!(median_price.next(&di), 10.5);
+/// ```
+#[doc(alias = "MEDPRICE")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MedianPrice {}
+
+impl MedianPrice {
+	pub fn new() -> Self {
+		Self {}
+	}
+}
+
+impl Period for MedianPrice {
+	fn period(&self) -> usize {
+		1
+	}
+}
+
+impl Ready for MedianPrice {
+	fn is_ready(&self) -> bool {
+		true
+	}
+}
+
+impl Next<f64> for MedianPrice {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		input
+	}
+}
+
+impl<T: High + Low> Next<&T> for MedianPrice {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		(input.high() + input.low()) / 2.0
+	}
+}
+
+impl Reset for MedianPrice {
+	fn reset(&mut self) {}
+}
+
+impl Default for MedianPrice {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl fmt::Display for MedianPrice {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "MEDPRICE")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	test_indicator!(MedianPrice);
+
+	#[test]
+	fn test_next_with_f64() {
+		let mut median_price = MedianPrice::new();
+		assert_eq!(median_price.next(3.5), 3.5);
+		assert_eq!(median_price.next(-1.0), -1.0);
+	}
+
+	#[test]
+	fn test_next_with_bars() {
+		let mut median_price = MedianPrice::new();
+		assert_eq!(median_price.next(&Bar::new().high(12).low(9)), 10.5);
+		assert_eq!(median_price.next(&Bar::new().high(5).low(5)), 5.0);
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut median_price = MedianPrice::new();
+		median_price.next(&Bar::new().high(4).low(2));
+		median_price.reset();
+		assert_eq!(median_price, MedianPrice::default());
+		assert_eq!(median_price.next(&Bar::new().high(4).low(2)), 3.0);
+	}
+
+	#[test]
+	fn test_display() {
+		assert_eq!(format!("{}", MedianPrice::new()), "MEDPRICE");
+	}
+}
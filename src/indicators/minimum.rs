@@ -1,12 +1,20 @@
-use std::fmt;
+use alloc::collections::VecDeque;
+use core::fmt;
 
-use crate::errors::{Result, TaError};
-use crate::{Low, Next, Period, Reset};
+use num_traits::Float;
+
+use crate::errors::Result;
+use crate::types;
+use crate::{Low, Next, Period, Ready, Reset, Warmup};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Returns the lowest value in a given time frame.
 ///
+/// The candidates are kept in a monotonic deque, so each value costs O(1) amortized
+/// whatever the period. NaN inputs are ignored, NaN is only returned if the window holds
+/// nothing else.
+///
 /// # Parameters
 ///
 /// * _period_ - size of the time frame (integer greater than 0). Default value is 14.
@@ -23,84 +31,126 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(min.next(12.0), 10.0);
 /// assert_eq!(min.next(13.0), 11.0);
 /// ```
+///
+/// The outputs of the first `period - 1` inputs are the lowest of a partial window. To tell
+/// them apart, use [new_strict](#method.new_strict).
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone)]
-pub struct Minimum {
+#[derive(Debug, Clone, PartialEq)]
+pub struct Minimum<T = f64> {
 	period: usize,
-	min_index: usize,
-	cur_index: usize,
-	deque: Box<[f64]>,
+	// number of inputs received, the sequence index of the next one
+	count: usize,
+	// the candidates for the lowest value and their sequence indexes, in the order they were
+	// received: NaN inputs are never candidates, every value is
+	// greater than or equal to the previous one, the front is the result
+	monotonic: VecDeque<(usize, T)>,
 }
 
 impl Minimum {
 	pub fn new(period: usize) -> Result<Self> {
-		match period {
-			0 => Err(TaError::InvalidParameter),
-			_ => Ok(Self {
-				period,
-				min_index: 0,
-				cur_index: 0,
-				deque: vec![f64::INFINITY; period].into_boxed_slice(),
-			}),
-		}
+		Self::new_generic(period)
 	}
 
-	fn find_min_index(&self) -> usize {
-		let mut min = f64::INFINITY;
-		let mut index: usize = 0;
+	/// Creates the indicator from an already validated period, which cannot fail.
+	pub fn new_with_period(period: types::PeriodValue) -> Self {
+		Self::new_generic_with_period(period)
+	}
 
-		for (i, &val) in self.deque.iter().enumerate() {
-			if val < min {
-				min = val;
-				index = i;
-			}
-		}
+	/// Creates a minimum returning `None` until its window is full, see
+	/// [Warmup](../struct.Warmup.html).
+	///
+	/// ```
+	/// use ta::indicators::Minimum;
+	/// use ta::Next;
+	///
+	/// let mut min = Minimum::new_strict(3).unwrap();
+	/// assert_eq!(min.next(10.0), None);
+	/// assert_eq!(min.next(11.0), None);
+	/// assert_eq!(min.next(12.0), Some(10.0));
+	/// ```
+	pub fn new_strict(period: usize) -> Result<StrictMinimum> {
+		Ok(Warmup::new(Self::new(period)?))
+	}
+}
+
+/// A [Minimum](struct.Minimum.html) returning `None` until its window is full, created with
+/// [Minimum::new_strict](struct.Minimum.html#method.new_strict).
+pub type StrictMinimum = Warmup<Minimum>;
+
+impl<T: Float> Minimum<T> {
+	/// Creates the indicator for another float type than `f64`, for example
+	/// `Minimum::<f32>::new_generic(3)`.
+	pub fn new_generic(period: usize) -> Result<Self> {
+		Ok(Self::new_generic_with_period(types::PeriodValue::try_from(
+			period,
+		)?))
+	}
 
-		index
+	/// Same as [new_with_period](#method.new_with_period) for another float type than `f64`.
+	pub fn new_generic_with_period(period: types::PeriodValue) -> Self {
+		let period = period.get();
+		Self {
+			period,
+			count: 0,
+			monotonic: VecDeque::with_capacity(period),
+		}
 	}
 }
 
-impl Period for Minimum {
+impl<T> Period for Minimum<T> {
 	fn period(&self) -> usize {
 		self.period
 	}
 }
 
-impl Next<f64> for Minimum {
-	type Output = f64;
+impl<T> Ready for Minimum<T> {
+	fn is_ready(&self) -> bool {
+		self.count >= self.period
+	}
+}
 
-	fn next(&mut self, input: f64) -> Self::Output {
-		self.deque[self.cur_index] = input;
+impl<T: Float> Next<T> for Minimum<T> {
+	type Output = T;
 
-		if input < self.deque[self.min_index] {
-			self.min_index = self.cur_index;
-		} else if self.min_index == self.cur_index {
-			self.min_index = self.find_min_index();
+	fn next(&mut self, input: T) -> Self::Output {
+		// the candidate received `period` inputs ago leaves the window
+		if self
+			.monotonic
+			.front()
+			.is_some_and(|&(index, _)| index + self.period <= self.count)
+		{
+			self.monotonic.pop_front();
 		}
+		let index = self.count;
+		self.count += 1;
 
-		self.cur_index = if self.cur_index + 1 < self.period {
-			self.cur_index + 1
-		} else {
-			0
-		};
+		if input.is_nan() {
+			// NaN is ignored, unless the window only holds NaN
+			return self.monotonic.front().map_or(input, |&(_, v)| v);
+		}
 
-		self.deque[self.min_index]
+		// values greater than the input can never be the lowest again
+		while self.monotonic.back().is_some_and(|&(_, v)| v > input) {
+			self.monotonic.pop_back();
+		}
+		self.monotonic.push_back((index, input));
+
+		self.monotonic[0].1
 	}
 }
 
-impl<T: Low> Next<&T> for Minimum {
+impl<U: Low> Next<&U> for Minimum {
 	type Output = f64;
 
-	fn next(&mut self, input: &T) -> Self::Output {
+	fn next(&mut self, input: &U) -> Self::Output {
 		self.next(input.low())
 	}
 }
 
-impl Reset for Minimum {
+impl<T> Reset for Minimum<T> {
 	fn reset(&mut self) {
-		for i in 0..self.period {
-			self.deque[i] = f64::INFINITY;
-		}
+		self.count = 0;
+		self.monotonic.clear();
 	}
 }
 
@@ -110,7 +160,7 @@ impl Default for Minimum {
 	}
 }
 
-impl fmt::Display for Minimum {
+impl<T> fmt::Display for Minimum<T> {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		write!(f, "MIN({})", self.period)
 	}
@@ -129,6 +179,30 @@ mod tests {
 		assert!(Minimum::new(1).is_ok());
 	}
 
+	#[test]
+	fn test_new_strict() {
+		assert!(Minimum::new_strict(0).is_err());
+
+		let mut min = Minimum::new_strict(3).unwrap();
+		assert_eq!(min.next(4.0), None);
+		assert_eq!(min.next(1.2), None);
+		assert_eq!(min.next(5.0), Some(1.2));
+		assert_eq!(min.next(&Bar::new().low(3.0)), Some(1.2));
+		assert_eq!(min.next(6.0), Some(3.0));
+		assert!(min.is_ready());
+
+		min.reset();
+		assert_eq!(min.next(4.0), None);
+		assert_eq!(min.period(), 3);
+	}
+
+	#[test]
+	fn test_new_with_period() {
+		let period = types::PeriodValue::try_from(3_usize).unwrap();
+		assert_eq!(Minimum::new_with_period(period).period(), 3);
+		assert_eq!(Minimum::<f32>::new_generic_with_period(period).period(), 3);
+	}
+
 	#[test]
 	fn test_next() {
 		let mut min = Minimum::new(3).unwrap();
@@ -159,6 +233,39 @@ mod tests {
 		assert_eq!(min.next(&bar(5.0)), 1.2);
 	}
 
+	#[test]
+	fn test_next_sliding_window() {
+		let inputs: Vec<f64> = (0..50).map(|i| ((i * 37) % 11) as f64).collect();
+		let mut min = Minimum::new(4).unwrap();
+		for (i, &x) in inputs.iter().enumerate() {
+			let window = &inputs[i.saturating_sub(3)..=i];
+			let expected = window.iter().copied().fold(f64::INFINITY, f64::min);
+			assert_eq!(min.next(x), expected);
+		}
+	}
+
+	#[test]
+	fn test_next_nan() {
+		let mut min = Minimum::new(3).unwrap();
+		assert!(min.next(f64::NAN).is_nan());
+		assert_eq!(min.next(4.0), 4.0);
+		assert_eq!(min.next(6.0), 4.0);
+		assert_eq!(min.next(5.0), 4.0);
+
+		// NaN is ignored and leaves the window like any other input
+		assert_eq!(min.next(f64::NAN), 5.0);
+		assert_eq!(min.next(f64::NAN), 5.0);
+		assert!(min.next(f64::NAN).is_nan());
+		for i in 0..1000 {
+			min.next(if i % 2 == 0 { f64::NAN } else { i as f64 });
+			assert!(min.monotonic.len() <= 3);
+		}
+		for x in &[3.0, 2.0, 1.0] {
+			min.next(*x);
+		}
+		assert_eq!(min.next(7.0), 1.0);
+	}
+
 	#[test]
 	fn test_reset() {
 		let mut min = Minimum::new(10).unwrap();
@@ -170,6 +277,37 @@ mod tests {
 		assert_eq!(min.next(8.0), 8.0);
 	}
 
+	#[test]
+	fn test_peek() {
+		let mut min = Minimum::new(2).unwrap();
+		assert_eq!(min.next(4.0), 4.0);
+		assert_eq!(min.next(3.0), 3.0);
+
+		assert_eq!(min.peek(5.0), 3.0);
+		assert_eq!(min.peek(1.0), 1.0);
+		assert_eq!(min.peek(&Bar::new().low(2.0)), 2.0);
+
+		assert_eq!(min.next(5.0), 3.0);
+		assert_eq!(min.next(6.0), 5.0);
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut min = Minimum::new(3).unwrap();
+		assert!(!min.is_ready());
+		min.next(4.0);
+		assert!(!min.is_ready());
+		min.next(5.0);
+		assert!(!min.is_ready());
+		min.next(6.0);
+		assert!(min.is_ready());
+		min.next(7.0);
+		assert!(min.is_ready());
+
+		min.reset();
+		assert!(!min.is_ready());
+	}
+
 	#[test]
 	fn test_default() {
 		Minimum::default();
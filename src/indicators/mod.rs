@@ -14,10 +14,10 @@ mod mean_absolute_deviation;
 pub use self::mean_absolute_deviation::MeanAbsoluteDeviation;
 
 mod relative_strength_index;
-pub use self::relative_strength_index::RelativeStrengthIndex;
+pub use self::relative_strength_index::{RelativeStrengthIndex, RsiSmoothing};
 
 mod minimum;
-pub use self::minimum::Minimum;
+pub use self::minimum::{Minimum, StrictMinimum};
 
 mod maximum;
 pub use self::maximum::Maximum;
@@ -36,7 +36,8 @@ pub use self::average_true_range::AverageTrueRange;
 
 mod moving_average_convergence_divergence;
 pub use self::moving_average_convergence_divergence::{
-	MovingAverageConvergenceDivergence, MovingAverageConvergenceDivergenceOutput,
+	MovingAverageConvergenceDivergence, MovingAverageConvergenceDivergenceBuilder,
+	MovingAverageConvergenceDivergenceOutput,
 };
 
 mod percentage_price_oscillator;
@@ -51,7 +52,7 @@ mod efficiency_ratio;
 pub use self::efficiency_ratio::EfficiencyRatio;
 
 mod bollinger_bands;
-pub use self::bollinger_bands::{BollingerBands, BollingerBandsOutput};
+pub use self::bollinger_bands::{BollingerBands, BollingerBandsBuilder, BollingerBandsOutput};
 
 mod chandelier_exit;
 pub use self::chandelier_exit::{ChandelierExit, ChandelierExitOutput};
@@ -67,3 +68,181 @@ pub use self::money_flow_index::MoneyFlowIndex;
 
 mod on_balance_volume;
 pub use self::on_balance_volume::OnBalanceVolume;
+
+mod volume_weighted_average_price;
+pub use self::volume_weighted_average_price::{
+	VolumeWeightedAveragePrice, VolumeWeightedAveragePriceBands,
+	VolumeWeightedAveragePriceBandsOutput,
+};
+
+mod ichimoku_cloud;
+pub use self::ichimoku_cloud::{IchimokuCloud, IchimokuCloudOutput};
+
+mod parabolic_sar;
+pub use self::parabolic_sar::ParabolicSar;
+
+mod williams_percent_range;
+pub use self::williams_percent_range::WilliamsPercentRange;
+
+mod chaikin_money_flow;
+pub use self::chaikin_money_flow::ChaikinMoneyFlow;
+
+mod aroon;
+pub use self::aroon::{Aroon, AroonOutput};
+
+mod donchian_channel;
+pub use self::donchian_channel::{DonchianChannel, DonchianChannelOutput};
+
+mod hull_moving_average;
+pub use self::hull_moving_average::HullMovingAverage;
+
+mod kaufman_adaptive_moving_average;
+pub use self::kaufman_adaptive_moving_average::KaufmanAdaptiveMovingAverage;
+
+mod zero_lag_exponential_moving_average;
+pub use self::zero_lag_exponential_moving_average::ZeroLagExponentialMovingAverage;
+
+mod double_exponential_moving_average;
+pub use self::double_exponential_moving_average::DoubleExponentialMovingAverage;
+
+mod triple_exponential_moving_average;
+pub use self::triple_exponential_moving_average::TripleExponentialMovingAverage;
+
+mod triple_exponential_average;
+pub use self::triple_exponential_average::{
+	TripleExponentialAverage, TripleExponentialAverageSignal, TripleExponentialAverageSignalOutput,
+};
+
+mod ultimate_oscillator;
+pub use self::ultimate_oscillator::UltimateOscillator;
+
+mod detrended_price_oscillator;
+pub use self::detrended_price_oscillator::DetrendedPriceOscillator;
+
+mod chande_momentum_oscillator;
+pub use self::chande_momentum_oscillator::ChandeMomentumOscillator;
+
+mod elder_ray;
+pub use self::elder_ray::{ElderRay, ElderRayOutput};
+
+mod force_index;
+pub use self::force_index::ForceIndex;
+
+mod coppock_curve;
+pub use self::coppock_curve::CoppockCurve;
+
+mod mass_index;
+pub use self::mass_index::MassIndex;
+
+mod vortex_indicator;
+pub use self::vortex_indicator::{VortexIndicator, VortexIndicatorOutput};
+
+mod chaikin_oscillator;
+pub use self::chaikin_oscillator::ChaikinOscillator;
+
+mod stochastic_rsi;
+pub use self::stochastic_rsi::{StochasticRsi, StochasticRsiOutput};
+
+mod choppiness_index;
+pub use self::choppiness_index::ChoppinessIndex;
+
+mod ease_of_movement;
+pub use self::ease_of_movement::EaseOfMovement;
+
+mod balance_of_power;
+pub use self::balance_of_power::BalanceOfPower;
+
+mod klinger_oscillator;
+pub use self::klinger_oscillator::{KlingerOscillator, KlingerOscillatorOutput};
+
+mod price_volume_trend;
+pub use self::price_volume_trend::PriceVolumeTrend;
+
+mod accumulation_distribution;
+pub use self::accumulation_distribution::AccumulationDistribution;
+
+mod variance;
+pub use self::variance::Variance;
+
+mod covariance;
+pub use self::covariance::Covariance;
+
+mod correlation;
+pub use self::correlation::Correlation;
+
+mod skewness;
+pub use self::skewness::Skewness;
+
+mod kurtosis;
+pub use self::kurtosis::Kurtosis;
+
+mod sorted_window;
+pub(crate) use self::sorted_window::SortedWindow;
+
+mod median;
+pub use self::median::Median;
+
+mod percentile;
+pub use self::percentile::Percentile;
+
+mod historical_volatility;
+pub use self::historical_volatility::HistoricalVolatility;
+
+mod max_drawdown;
+pub use self::max_drawdown::MaxDrawdown;
+
+mod sharpe_ratio;
+pub use self::sharpe_ratio::SharpeRatio;
+
+mod log_return;
+pub use self::log_return::LogReturn;
+
+mod cumulative_return;
+pub use self::cumulative_return::CumulativeReturn;
+
+mod average_true_range_percent;
+pub use self::average_true_range_percent::AverageTrueRangePercent;
+
+mod directional_movement_index;
+pub use self::directional_movement_index::{
+	DirectionalMovementIndex, DirectionalMovementIndexOutput,
+};
+
+mod volume_rsi;
+pub use self::volume_rsi::VolumeRsi;
+
+mod pivot_points;
+pub use self::pivot_points::{PivotPoints, PivotPointsOutput, PivotVariant};
+
+mod zigzag;
+pub use self::zigzag::Zigzag;
+
+mod bollinger_band_pct_b;
+pub use self::bollinger_band_pct_b::BollingerBandPctB;
+
+mod bollinger_band_width;
+pub use self::bollinger_band_width::BollingerBandWidth;
+
+mod stochastic_oscillator;
+pub use self::stochastic_oscillator::{StochasticOscillator, StochasticOutput};
+
+mod rolling_sum;
+pub use self::rolling_sum::RollingSum;
+
+mod median_price;
+pub use self::median_price::MedianPrice;
+
+mod typical_price;
+pub use self::typical_price::TypicalPrice;
+
+mod weighted_close;
+pub use self::weighted_close::WeightedClose;
+
+mod linear_regression;
+pub use self::linear_regression::{LinearRegression, LinearRegressionOutput};
+
+mod time_series_forecast;
+pub use self::time_series_forecast::TimeSeriesForecast;
+
+mod standard_error;
+pub use self::standard_error::StandardError;
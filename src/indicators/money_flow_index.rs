@@ -1,7 +1,8 @@
-use std::fmt;
+use core::fmt;
 
-use crate::errors::{Result, TaError};
-use crate::{Close, High, Low, Next, Period, Reset, Volume};
+use crate::errors::Result;
+use crate::indicators::RollingSum;
+use crate::{Close, High, Low, Next, Period, Ready, Reset, Volume};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -27,6 +28,8 @@ use serde::{Deserialize, Serialize};
 ///
 /// Money Flow Index(MFI) = PMF / (PMF + NMF) * 100
 ///
+/// If NMF is 0, 100 is returned. If PMF is 0, 0 is returned. If both are 0 (flat market),
+/// 50 is returned, like for the first period.
 ///
 /// # Parameters
 ///
@@ -54,37 +57,34 @@ use serde::{Deserialize, Serialize};
 /// * [Money Flow Index, stockcharts](https://stockcharts.com/school/doku.php?id=chart_school:technical_indicators:money_flow_index_mfi)
 #[doc(alias = "MFI")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct MoneyFlowIndex {
-	period: usize,
-	index: usize,
 	count: usize,
 	previous_typical_price: f64,
-	total_positive_money_flow: f64,
-	total_negative_money_flow: f64,
-	deque: Box<[f64]>,
+	total_positive_money_flow: RollingSum,
+	total_negative_money_flow: RollingSum,
 }
 
 impl MoneyFlowIndex {
 	pub fn new(period: usize) -> Result<Self> {
-		match period {
-			0 => Err(TaError::InvalidParameter),
-			_ => Ok(Self {
-				period,
-				index: 0,
-				count: 0,
-				previous_typical_price: 0.0,
-				total_positive_money_flow: 0.0,
-				total_negative_money_flow: 0.0,
-				deque: vec![0.0; period].into_boxed_slice(),
-			}),
-		}
+		Ok(Self {
+			count: 0,
+			previous_typical_price: 0.0,
+			total_positive_money_flow: RollingSum::new(period)?,
+			total_negative_money_flow: RollingSum::new(period)?,
+		})
 	}
 }
 
 impl Period for MoneyFlowIndex {
 	fn period(&self) -> usize {
-		self.period
+		self.total_positive_money_flow.period()
+	}
+}
+
+impl Ready for MoneyFlowIndex {
+	fn is_ready(&self) -> bool {
+		self.count == self.period()
 	}
 }
 
@@ -94,43 +94,35 @@ impl<T: High + Low + Close + Volume> Next<&T> for MoneyFlowIndex {
 	fn next(&mut self, input: &T) -> f64 {
 		let tp = (input.close() + input.high() + input.low()) / 3.0;
 
-		self.index = if self.index + 1 < self.period {
-			self.index + 1
-		} else {
-			0
-		};
-
-		if self.count < self.period {
+		if self.count < self.period() {
 			self.count += 1;
 			if self.count == 1 {
 				self.previous_typical_price = tp;
 				return 50.0;
 			}
-		} else {
-			let popped = self.deque[self.index];
-			if popped.is_sign_positive() {
-				self.total_positive_money_flow -= popped;
-			} else {
-				self.total_negative_money_flow += popped;
-			}
 		}
 
-		if tp > self.previous_typical_price {
-			let raw_money_flow = tp * input.volume();
-			self.total_positive_money_flow += raw_money_flow;
-			self.deque[self.index] = raw_money_flow;
+		let raw_money_flow = tp * input.volume();
+		let (positive_money_flow, negative_money_flow) = if tp > self.previous_typical_price {
+			(raw_money_flow, 0.0)
 		} else if tp < self.previous_typical_price {
-			let raw_money_flow = tp * input.volume();
-			self.total_negative_money_flow += raw_money_flow;
-			self.deque[self.index] = -raw_money_flow;
+			(0.0, raw_money_flow)
 		} else {
-			self.deque[self.index] = 0.0;
-		}
+			(0.0, 0.0)
+		};
+		let total_positive_money_flow = self.total_positive_money_flow.next(positive_money_flow);
+		let total_negative_money_flow = self.total_negative_money_flow.next(negative_money_flow);
 		self.previous_typical_price = tp;
 
-		self.total_positive_money_flow
-			/ (self.total_positive_money_flow + self.total_negative_money_flow)
-			* 100.0
+		if total_positive_money_flow == 0.0 && total_negative_money_flow == 0.0 {
+			return 50.0;
+		} else if total_negative_money_flow == 0.0 {
+			return 100.0;
+		} else if total_positive_money_flow == 0.0 {
+			return 0.0;
+		}
+
+		total_positive_money_flow / (total_positive_money_flow + total_negative_money_flow) * 100.0
 	}
 }
 
@@ -142,20 +134,16 @@ impl Default for MoneyFlowIndex {
 
 impl fmt::Display for MoneyFlowIndex {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		write!(f, "MFI({})", self.period)
+		write!(f, "MFI({})", self.period())
 	}
 }
 
 impl Reset for MoneyFlowIndex {
 	fn reset(&mut self) {
-		self.index = 0;
 		self.count = 0;
 		self.previous_typical_price = 0.0;
-		self.total_positive_money_flow = 0.0;
-		self.total_negative_money_flow = 0.0;
-		for i in 0..self.period {
-			self.deque[i] = 0.0;
-		}
+		self.total_positive_money_flow.reset();
+		self.total_negative_money_flow.reset();
 	}
 }
 
@@ -199,6 +187,33 @@ mod tests {
 		assert_eq!(round(mfi.next(&bar8)), 60.87);
 	}
 
+	#[test]
+	fn test_next_one_sided() {
+		let mut mfi = MoneyFlowIndex::new(2).unwrap();
+
+		let bar1 = Bar::new().high(3).low(1).close(2).volume(500.0);
+		let bar2 = Bar::new().high(4).low(2).close(3).volume(500.0);
+		let bar3 = Bar::new().high(5).low(3).close(4).volume(500.0);
+		assert_eq!(mfi.next(&bar1), 50.0);
+		assert_eq!(mfi.next(&bar2), 100.0);
+		assert_eq!(mfi.next(&bar3), 100.0);
+
+		let bar4 = Bar::new().high(4).low(2).close(3).volume(500.0);
+		let bar5 = Bar::new().high(3).low(1).close(2).volume(500.0);
+		assert_eq!(round(mfi.next(&bar4)), 57.143);
+		assert_eq!(mfi.next(&bar5), 0.0);
+	}
+
+	#[test]
+	fn test_next_flat() {
+		let mut mfi = MoneyFlowIndex::new(2).unwrap();
+
+		let bar = Bar::new().high(3).low(1).close(2).volume(500.0);
+		for _ in 0..4 {
+			assert_eq!(mfi.next(&bar), 50.0);
+		}
+	}
+
 	#[test]
 	fn test_reset() {
 		let mut mfi = MoneyFlowIndex::new(3).unwrap();
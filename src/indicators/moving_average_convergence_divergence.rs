@@ -1,8 +1,8 @@
-use std::fmt;
+use core::fmt;
 
 use crate::errors::Result;
 use crate::indicators::ExponentialMovingAverage as Ema;
-use crate::{Close, Next, Period, Reset};
+use crate::{Close, Next, Period, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -22,6 +22,13 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Formula
 ///
+/// * _MACD_ = EMA<sub>fast</sub>(P) - EMA<sub>slow</sub>(P)
+/// * _Signal_ = EMA<sub>signal</sub>(MACD)
+/// * _Histogram_ = MACD - Signal
+///
+/// Where _P_ is the price. The output holds the three series, so the histogram does not have
+/// to be computed by the caller.
+///
 /// # Parameters
 ///
 /// * _fast_period_ - period for the fast EMA. Default is 12.
@@ -52,7 +59,7 @@ use serde::{Deserialize, Serialize};
 /// ```
 #[doc(alias = "MACD")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct MovingAverageConvergenceDivergence {
 	fast_ema: Ema,
 	slow_ema: Ema,
@@ -67,6 +74,10 @@ impl MovingAverageConvergenceDivergence {
 			signal_ema: Ema::new(signal_period)?,
 		})
 	}
+
+	pub fn builder() -> MovingAverageConvergenceDivergenceBuilder {
+		MovingAverageConvergenceDivergenceBuilder::default()
+	}
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -109,6 +120,18 @@ impl<T: Close> Next<&T> for MovingAverageConvergenceDivergence {
 	}
 }
 
+impl Period for MovingAverageConvergenceDivergence {
+	fn period(&self) -> usize {
+		self.slow_ema.period()
+	}
+}
+
+impl Ready for MovingAverageConvergenceDivergence {
+	fn is_ready(&self) -> bool {
+		self.fast_ema.is_ready() && self.slow_ema.is_ready() && self.signal_ema.is_ready()
+	}
+}
+
 impl Reset for MovingAverageConvergenceDivergence {
 	fn reset(&mut self) {
 		self.fast_ema.reset();
@@ -135,6 +158,66 @@ impl fmt::Display for MovingAverageConvergenceDivergence {
 	}
 }
 
+/// Builder of [MovingAverageConvergenceDivergence](struct.MovingAverageConvergenceDivergence.html)
+/// with named parameters.
+///
+/// Each parameter starts at its default value, so only the differing ones have to be set.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::MovingAverageConvergenceDivergence as Macd;
+///
+/// let macd = Macd::builder()
+///     .fast_period(5)
+///     .slow_period(35)
+///     .signal_period(5)
+///     .build()
+///     .unwrap();
+/// assert_eq!(format!("{}", macd), "MACD(5, 35, 5)");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MovingAverageConvergenceDivergenceBuilder {
+	fast_period: usize,
+	slow_period: usize,
+	signal_period: usize,
+}
+
+impl MovingAverageConvergenceDivergenceBuilder {
+	pub fn fast_period(mut self, period: usize) -> Self {
+		self.fast_period = period;
+		self
+	}
+
+	pub fn slow_period(mut self, period: usize) -> Self {
+		self.slow_period = period;
+		self
+	}
+
+	pub fn signal_period(mut self, period: usize) -> Self {
+		self.signal_period = period;
+		self
+	}
+
+	pub fn build(self) -> Result<MovingAverageConvergenceDivergence> {
+		MovingAverageConvergenceDivergence::new(
+			self.fast_period,
+			self.slow_period,
+			self.signal_period,
+		)
+	}
+}
+
+impl Default for MovingAverageConvergenceDivergenceBuilder {
+	fn default() -> Self {
+		Self {
+			fast_period: 12,
+			slow_period: 26,
+			signal_period: 9,
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -170,6 +253,16 @@ mod tests {
 		assert_eq!(round(macd.next(6.5).into()), (0.94, 0.87, 0.07));
 	}
 
+	#[test]
+	fn test_histogram() {
+		let mut macd = Macd::new(3, 6, 4).unwrap();
+
+		for x in &[2.0, 3.0, 4.2, 7.0, 6.7, 6.5, 1.0, 9.3] {
+			let output = macd.next(*x);
+			assert_eq!(output.histogram, output.macd - output.signal);
+		}
+	}
+
 	#[test]
 	fn test_reset() {
 		let mut macd = Macd::new(3, 6, 4).unwrap();
@@ -183,6 +276,20 @@ mod tests {
 		assert_eq!(round(macd.next(3.0).into()), (0.21, 0.09, 0.13));
 	}
 
+	#[test]
+	fn test_is_ready() {
+		let mut macd = Macd::new(2, 4, 3).unwrap();
+		for _ in 0..3 {
+			macd.next(2.0);
+			assert!(!macd.is_ready());
+		}
+		macd.next(2.0);
+		assert!(macd.is_ready());
+
+		macd.reset();
+		assert!(!macd.is_ready());
+	}
+
 	#[test]
 	fn test_default() {
 		Macd::default();
@@ -193,4 +300,23 @@ mod tests {
 		let indicator = Macd::new(13, 30, 10).unwrap();
 		assert_eq!(format!("{}", indicator), "MACD(13, 30, 10)");
 	}
+
+	#[test]
+	fn test_builder() {
+		let macd = Macd::builder().build().unwrap();
+		assert_eq!(format!("{}", macd), "MACD(12, 26, 9)");
+
+		let macd = Macd::builder().slow_period(30).build().unwrap();
+		assert_eq!(format!("{}", macd), "MACD(12, 30, 9)");
+
+		let macd = MovingAverageConvergenceDivergenceBuilder::default()
+			.fast_period(3)
+			.slow_period(6)
+			.signal_period(4)
+			.build()
+			.unwrap();
+		assert_eq!(format!("{}", macd), "MACD(3, 6, 4)");
+
+		assert!(Macd::builder().signal_period(0).build().is_err());
+	}
 }
@@ -0,0 +1,267 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Parabolic SAR (stop and reverse).
+///
+/// A trailing stop that follows the price. While the trend continues the SAR accelerates
+/// towards the extreme price of the trend, and when the price crosses the SAR the direction
+/// flips.
+///
+/// # Formula
+///
+/// SAR<sub>t</sub> = SAR<sub>t-1</sub> + AF × (EP - SAR<sub>t-1</sub>)
+///
+/// Where:
+///
+/// * _EP_ - extreme point, the highest high of an uptrend or the lowest low of a downtrend
+/// * _AF_ - acceleration factor, starts at _initial_af_ and increases by _step_ every time a new
+///   extreme point is reached, up to _max_af_
+///
+/// In an uptrend the SAR is never placed above the lows of the two previous periods, in a
+/// downtrend it is never placed below the highs of the two previous periods.
+///
+/// The first period has no history, so the indicator assumes an uptrend and returns the low.
+/// The first values should be considered unreliable.
+///
+/// # Parameters
+///
+/// * _initial_af_ - initial acceleration factor (greater than 0, not greater than _max_af_). Default is 0.02.
+/// * _step_ - increment of the acceleration factor (greater than 0). Default is 0.02.
+/// * _max_af_ - maximum acceleration factor. Default is 0.2.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ParabolicSar;
+/// use ta::{DataItem, Next};
+///
+/// let mut sar = ParabolicSar::new(0.02, 0.02, 0.2).unwrap();
+///
+/// let di = DataItem::builder()
+///             .high(10.0)
+///             .low(8.0)
+///             .close(9.0)
+///             .open(9.0)
+///             .volume(100.0)
+///             .build().unwrap();
+///
+/// assert_eq!(sar.next(&di), 8.0);
+/// ```
+///
+/// # Links
+///
+/// * [Parabolic SAR, Wikipedia](https://en.wikipedia.org/wiki/Parabolic_SAR)
+#[doc(alias = "PSAR")]
+#[doc(alias = "SAR")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParabolicSar {
+	initial_af: f64,
+	step: f64,
+	max_af: f64,
+	is_new: bool,
+	is_bullish: bool,
+	sar: f64,
+	ep: f64,
+	af: f64,
+	prev_highs: (f64, f64),
+	prev_lows: (f64, f64),
+}
+
+impl ParabolicSar {
+	pub fn new(initial_af: f64, step: f64, max_af: f64) -> Result<Self> {
+		if !(initial_af > 0.0 && initial_af <= max_af && step > 0.0) {
+			return Err(TaError::InvalidParameter);
+		}
+
+		Ok(Self {
+			initial_af,
+			step,
+			max_af,
+			is_new: true,
+			is_bullish: true,
+			sar: 0.0,
+			ep: 0.0,
+			af: initial_af,
+			prev_highs: (0.0, 0.0),
+			prev_lows: (0.0, 0.0),
+		})
+	}
+
+	/// Returns true if the indicator currently tracks an uptrend.
+	pub fn is_bullish(&self) -> bool {
+		self.is_bullish
+	}
+
+	fn calc(&mut self, high: f64, low: f64) -> f64 {
+		if self.is_new {
+			self.is_new = false;
+			self.is_bullish = true;
+			self.sar = low;
+			self.ep = high;
+			self.af = self.initial_af;
+			self.prev_highs = (high, high);
+			self.prev_lows = (low, low);
+			return self.sar;
+		}
+
+		let mut sar = self.sar + self.af * (self.ep - self.sar);
+
+		if self.is_bullish {
+			sar = sar.min(self.prev_lows.0).min(self.prev_lows.1);
+			if low < sar {
+				self.is_bullish = false;
+				sar = self.ep;
+				self.ep = low;
+				self.af = self.initial_af;
+			} else if high > self.ep {
+				self.ep = high;
+				self.af = (self.af + self.step).min(self.max_af);
+			}
+		} else {
+			sar = sar.max(self.prev_highs.0).max(self.prev_highs.1);
+			if high > sar {
+				self.is_bullish = true;
+				sar = self.ep;
+				self.ep = high;
+				self.af = self.initial_af;
+			} else if low < self.ep {
+				self.ep = low;
+				self.af = (self.af + self.step).min(self.max_af);
+			}
+		}
+
+		self.sar = sar;
+		self.prev_highs = (high, self.prev_highs.0);
+		self.prev_lows = (low, self.prev_lows.0);
+		sar
+	}
+}
+
+impl Period for ParabolicSar {
+	fn period(&self) -> usize {
+		1
+	}
+}
+
+impl Ready for ParabolicSar {
+	fn is_ready(&self) -> bool {
+		!self.is_new
+	}
+}
+
+impl Next<f64> for ParabolicSar {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		self.calc(input, input)
+	}
+}
+
+impl<T: High + Low> Next<&T> for ParabolicSar {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.calc(input.high(), input.low())
+	}
+}
+
+impl Reset for ParabolicSar {
+	fn reset(&mut self) {
+		self.is_new = true;
+		self.is_bullish = true;
+		self.sar = 0.0;
+		self.ep = 0.0;
+		self.af = self.initial_af;
+		self.prev_highs = (0.0, 0.0);
+		self.prev_lows = (0.0, 0.0);
+	}
+}
+
+impl Default for ParabolicSar {
+	fn default() -> Self {
+		Self::new(0.02, 0.02, 0.2).unwrap()
+	}
+}
+
+impl fmt::Display for ParabolicSar {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"SAR({}, {}, {})",
+			self.initial_af, self.step, self.max_af
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	test_indicator!(ParabolicSar);
+
+	#[test]
+	fn test_new() {
+		assert!(ParabolicSar::new(0.0, 0.02, 0.2).is_err());
+		assert!(ParabolicSar::new(0.02, 0.0, 0.2).is_err());
+		assert!(ParabolicSar::new(0.3, 0.02, 0.2).is_err());
+		assert!(ParabolicSar::new(0.02, 0.02, 0.2).is_ok());
+		assert!(ParabolicSar::new(0.2, 0.02, 0.2).is_ok());
+	}
+
+	#[test]
+	fn test_next_with_bars() {
+		let test_data = vec![
+			// high, low, expected, is_bullish
+			(10.0, 8.0, 8.0, true),
+			(11.0, 9.0, 8.0, true),
+			(12.0, 10.0, 8.0, true),
+			(13.0, 11.0, 8.24, true),
+			(12.0, 9.0, 8.621, true),
+			(10.0, 7.0, 13.0, false), // reversal
+			(9.0, 6.0, 12.88, false),
+			(11.0, 8.0, 12.605, false),
+		];
+
+		let mut sar = ParabolicSar::new(0.02, 0.02, 0.2).unwrap();
+
+		for (high, low, expected, is_bullish) in test_data {
+			let bar = Bar::new().high(high).low(low);
+			assert_eq!(round(sar.next(&bar)), expected);
+			assert_eq!(sar.is_bullish(), is_bullish);
+		}
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut sar = ParabolicSar::default();
+		let bar1 = Bar::new().high(10).low(8);
+		let bar2 = Bar::new().high(6).low(5);
+
+		assert_eq!(sar.next(&bar1), 8.0);
+		assert_eq!(sar.next(&bar2), 10.0);
+		assert!(!sar.is_bullish());
+
+		sar.reset();
+		assert!(!sar.is_ready());
+		assert_eq!(sar.next(&bar2), 5.0);
+		assert!(sar.is_bullish());
+		assert!(sar.is_ready());
+	}
+
+	#[test]
+	fn test_default() {
+		ParabolicSar::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let indicator = ParabolicSar::default();
+		assert_eq!(format!("{}", indicator), "SAR(0.02, 0.02, 0.2)");
+	}
+}
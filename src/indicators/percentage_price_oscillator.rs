@@ -1,8 +1,8 @@
-use std::fmt;
+use core::fmt;
 
-use crate::errors::Result;
+use crate::errors::{Result, TaError};
 use crate::indicators::ExponentialMovingAverage as Ema;
-use crate::{Close, Next, Period, Reset};
+use crate::{Close, Next, Period, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -22,10 +22,16 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Formula
 ///
+/// PPO = (EMA<sub>fast</sub> - EMA<sub>slow</sub>) / EMA<sub>slow</sub> × 100
+///
+/// Unlike MACD, which is expressed in price units, PPO is a percentage and thus comparable
+/// across securities with different price levels. If the slow EMA is 0, all three values
+/// are 0 for that period and the signal EMA is left untouched.
+///
 /// # Parameters
 ///
 /// * _fast_period_ - period for the fast EMA. Default is 12.
-/// * _slow_period_ - period for the slow EMA. Default is 26.
+/// * _slow_period_ - period for the slow EMA, must be greater than _fast_period_. Default is 26.
 /// * _signal_period_ - period for the signal EMA. Default is 9.
 ///
 /// # Example
@@ -52,7 +58,7 @@ use serde::{Deserialize, Serialize};
 /// ```
 #[doc(alias = "PPO")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct PercentagePriceOscillator {
 	fast_ema: Ema,
 	slow_ema: Ema,
@@ -61,6 +67,10 @@ pub struct PercentagePriceOscillator {
 
 impl PercentagePriceOscillator {
 	pub fn new(fast_period: usize, slow_period: usize, signal_period: usize) -> Result<Self> {
+		if fast_period >= slow_period {
+			return Err(TaError::InvalidParameter);
+		}
+
 		Ok(PercentagePriceOscillator {
 			fast_ema: Ema::new(fast_period)?,
 			slow_ema: Ema::new(slow_period)?,
@@ -89,6 +99,14 @@ impl Next<f64> for PercentagePriceOscillator {
 		let fast_val = self.fast_ema.next(input);
 		let slow_val = self.slow_ema.next(input);
 
+		if slow_val == 0.0 {
+			return PercentagePriceOscillatorOutput {
+				ppo: 0.0,
+				signal: 0.0,
+				histogram: 0.0,
+			};
+		}
+
 		let ppo = (fast_val - slow_val) / slow_val * 100.0;
 		let signal = self.signal_ema.next(ppo);
 		let histogram = ppo - signal;
@@ -109,6 +127,18 @@ impl<T: Close> Next<&T> for PercentagePriceOscillator {
 	}
 }
 
+impl Period for PercentagePriceOscillator {
+	fn period(&self) -> usize {
+		self.slow_ema.period()
+	}
+}
+
+impl Ready for PercentagePriceOscillator {
+	fn is_ready(&self) -> bool {
+		self.fast_ema.is_ready() && self.slow_ema.is_ready() && self.signal_ema.is_ready()
+	}
+}
+
 impl Reset for PercentagePriceOscillator {
 	fn reset(&mut self) {
 		self.fast_ema.reset();
@@ -138,6 +168,7 @@ impl fmt::Display for PercentagePriceOscillator {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::indicators::MovingAverageConvergenceDivergence;
 	use crate::test_helper::*;
 	type Ppo = PercentagePriceOscillator;
 
@@ -154,8 +185,47 @@ mod tests {
 	fn test_new() {
 		assert!(Ppo::new(0, 1, 1).is_err());
 		assert!(Ppo::new(1, 0, 1).is_err());
-		assert!(Ppo::new(1, 1, 0).is_err());
-		assert!(Ppo::new(1, 1, 1).is_ok());
+		assert!(Ppo::new(1, 2, 0).is_err());
+		assert!(Ppo::new(1, 1, 1).is_err());
+		assert!(Ppo::new(2, 1, 1).is_err());
+		assert!(Ppo::new(1, 2, 1).is_ok());
+	}
+
+	#[test]
+	fn test_next_zero_slow_ema() {
+		let mut ppo = Ppo::new(3, 6, 4).unwrap();
+		assert_eq!(round(ppo.next(0.0).into()), (0.0, 0.0, 0.0));
+		assert_eq!(round(ppo.next(0.0).into()), (0.0, 0.0, 0.0));
+	}
+
+	#[test]
+	fn test_same_crossovers_as_macd() {
+		// the bars where the line crosses its signal line; the PPO is the MACD scaled by the
+		// slow EMA, which barely moves around a stable price level
+		let mut ppo = Ppo::new(3, 6, 4).unwrap();
+		let mut macd = MovingAverageConvergenceDivergence::new(3, 6, 4).unwrap();
+		let inputs = [
+			100.0, 101.5, 103.0, 102.0, 100.5, 99.0, 98.5, 99.5, 101.0, 102.5, 102.0, 100.0, 98.0,
+			99.0, 101.0,
+		];
+
+		let (mut ppo_crossovers, mut macd_crossovers) = (vec![], vec![]);
+		let (mut ppo_above, mut macd_above) = (false, false);
+		for (i, &input) in inputs.iter().enumerate() {
+			let above = ppo.next(input).histogram > 0.0;
+			if above != ppo_above {
+				ppo_crossovers.push(i);
+			}
+			ppo_above = above;
+
+			let above = macd.next(input).histogram > 0.0;
+			if above != macd_above {
+				macd_crossovers.push(i);
+			}
+			macd_above = above;
+		}
+		assert_eq!(ppo_crossovers, vec![1, 4, 8, 11, 14]);
+		assert_eq!(ppo_crossovers, macd_crossovers);
 	}
 
 	#[test]
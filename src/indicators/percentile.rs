@@ -0,0 +1,254 @@
+use core::fmt;
+
+use num_traits::Float;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::SortedWindow;
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling percentile (PERCENTILE).
+///
+/// Returns the given quantile of the last n values, for example the 5th or 95th percentile
+/// of the volatility or of the returns. Like [Median](struct.Median.html), it keeps a sorted
+/// copy of the last n values, updated by insertion on each period in O(n). NaN is ranked
+/// above every other value.
+///
+/// # Formula
+///
+/// P = S<sub>⌊r⌋</sub> + (r - ⌊r⌋) × (S<sub>⌊r⌋+1</sub> - S<sub>⌊r⌋</sub>)
+///
+/// Where:
+///
+/// * _S_ - last _N_ values sorted in ascending order, indexed from 0
+/// * _r_ - rank of the quantile, q × (N - 1)
+/// * _N_ - number of values in the observation
+///
+/// The linear interpolation between the two closest values makes the output continuous in
+/// _q_. With a quantile of 0.5 the result is the median.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+/// * _quantile_ - quantile to compute (number strictly between 0 and 1). Default is 0.5.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Percentile;
+/// use ta::Next;
+///
+/// let mut p = Percentile::new(5, 0.75).unwrap();
+/// for x in &[1.0, 2.0, 3.0, 4.0] {
+///     p.next(*x);
+/// }
+/// assert_eq!(p.next(5.0), 4.0);
+/// ```
+///
+/// # Links
+///
+/// * [Percentile, Wikipedia](https://en.wikipedia.org/wiki/Percentile#The_linear_interpolation_between_closest_ranks_method)
+#[doc(alias = "PERCENTILE")]
+#[doc(alias = "Quantile")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Percentile {
+	quantile: f64,
+	window: SortedWindow,
+}
+
+impl Percentile {
+	pub fn new(period: usize, quantile: f64) -> Result<Self> {
+		if quantile.is_nan() || quantile <= 0.0 || quantile >= 1.0 {
+			return Err(TaError::InvalidParameter);
+		}
+
+		Ok(Self {
+			quantile,
+			window: SortedWindow::new(period)?,
+		})
+	}
+
+	pub fn quantile(&self) -> f64 {
+		self.quantile
+	}
+}
+
+impl Period for Percentile {
+	fn period(&self) -> usize {
+		self.window.period()
+	}
+}
+
+impl Ready for Percentile {
+	fn is_ready(&self) -> bool {
+		self.window.is_ready()
+	}
+}
+
+impl Next<f64> for Percentile {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		self.window.push(input);
+		let sorted = self.window.sorted();
+
+		let rank = self.quantile * (sorted.len() - 1) as f64;
+		let lower = Float::floor(rank) as usize;
+		let upper = (lower + 1).min(sorted.len() - 1);
+		let (lo, hi) = (sorted[lower], sorted[upper]);
+		lo + (hi - lo) * (rank - lower as f64)
+	}
+}
+
+impl<T: Close> Next<&T> for Percentile {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl Reset for Percentile {
+	fn reset(&mut self) {
+		self.window.reset();
+	}
+}
+
+impl Default for Percentile {
+	fn default() -> Self {
+		Self::new(20, 0.5).unwrap()
+	}
+}
+
+impl fmt::Display for Percentile {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "PERCENTILE({}, {})", self.window.period(), self.quantile)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::indicators::Median;
+	use crate::test_helper::*;
+
+	test_indicator!(Percentile);
+
+	#[test]
+	fn test_new() {
+		assert!(Percentile::new(0, 0.5).is_err());
+		assert!(Percentile::new(5, 0.0).is_err());
+		assert!(Percentile::new(5, 1.0).is_err());
+		assert!(Percentile::new(5, -0.5).is_err());
+		assert!(Percentile::new(5, f64::NAN).is_err());
+		assert!(Percentile::new(1, 0.5).is_ok());
+		assert!(Percentile::new(5, 0.95).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let test_data = vec![
+			// input, q=0.25, q=0.9
+			(5.0, 5.0, 5.0),
+			(1.0, 2.0, 4.6),
+			(3.0, 2.0, 4.6),
+			(8.0, 2.5, 7.1),
+			(2.0, 1.75, 6.5),
+			(9.0, 2.75, 8.7),
+			(4.0, 3.5, 8.7),
+		];
+
+		let mut p25 = Percentile::new(4, 0.25).unwrap();
+		let mut p90 = Percentile::new(4, 0.9).unwrap();
+
+		for (input, expected_p25, expected_p90) in test_data {
+			assert_eq!(round(p25.next(input)), expected_p25);
+			assert_eq!(round(p90.next(input)), expected_p90);
+		}
+	}
+
+	#[test]
+	fn test_next_median() {
+		let mut p = Percentile::new(4, 0.5).unwrap();
+		let mut median = Median::new(4).unwrap();
+		for x in &[5.0, 1.0, 3.0, 8.0, 2.0, 9.0, 4.0, 4.0] {
+			assert_eq!(p.next(*x), median.next(*x));
+		}
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut p = Percentile::new(3, 0.5).unwrap();
+		p.next(1.0);
+		p.next(2.0);
+		assert!(!p.is_ready());
+		p.next(3.0);
+		assert!(p.is_ready());
+
+		p.reset();
+		assert!(!p.is_ready());
+	}
+
+	#[test]
+	fn test_next_nan() {
+		let inputs = [
+			6.0,
+			4.0,
+			3.0,
+			8.0,
+			9.0,
+			8.0,
+			4.0,
+			f64::NAN,
+			4.0,
+			f64::NAN,
+			8.0,
+			9.0,
+			8.0,
+			5.0,
+			1.0,
+			6.0,
+			7.0,
+			3.0,
+			6.0,
+			6.0,
+		];
+		let mut p = Percentile::new(4, 0.5).unwrap();
+		for x in &inputs {
+			p.next(*x);
+		}
+		assert_eq!(p.next(5.0), 5.5);
+
+		let mut p = Percentile::new(3, 0.25).unwrap();
+		p.next(1.0);
+		assert!(p.next(f64::NAN).is_nan());
+		assert_eq!(p.next(2.0), 1.5);
+		assert!(p.next(f64::NAN).is_nan());
+		assert_eq!(p.next(3.0), 2.5);
+		assert_eq!(p.next(4.0), 3.5);
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut p = Percentile::new(3, 0.25).unwrap();
+		assert_eq!(p.next(4.0), 4.0);
+		assert_eq!(p.next(8.0), 5.0);
+
+		p.reset();
+		assert_eq!(p.next(8.0), 8.0);
+		assert_eq!(p.next(12.0), 9.0);
+	}
+
+	#[test]
+	fn test_default() {
+		Percentile::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let p = Percentile::new(20, 0.95).unwrap();
+		assert_eq!(format!("{}", p), "PERCENTILE(20, 0.95)");
+	}
+}
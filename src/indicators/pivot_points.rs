@@ -0,0 +1,305 @@
+use core::fmt;
+
+use crate::{Close, High, Low, Next, Open, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Pivot Points (PIVOT).
+///
+/// Support and resistance levels for the current period, derived from the high, low and
+/// close prices of the previous period. The pivot is the main level, the resistances R1 to
+/// R3 are above it and the supports S1 to S3 below it.
+///
+/// # Formula
+///
+/// Classic:
+///
+/// * PP = (H + L + C) / 3
+/// * R1 = 2 × PP - L, R2 = PP + (H - L), R3 = H + 2 × (PP - L)
+/// * S1 = 2 × PP - H, S2 = PP - (H - L), S3 = L - 2 × (H - PP)
+///
+/// Fibonacci:
+///
+/// * PP = (H + L + C) / 3
+/// * R1 = PP + 0.382 × (H - L), R2 = PP + 0.618 × (H - L), R3 = PP + (H - L)
+/// * S1 = PP - 0.382 × (H - L), S2 = PP - 0.618 × (H - L), S3 = PP - (H - L)
+///
+/// Woodie:
+///
+/// * PP = (H + L + 2 × O<sub>t</sub>) / 4
+/// * R1 to R3 and S1 to S3 as in the classic variant
+///
+/// Camarilla:
+///
+/// * PP = (H + L + C) / 3
+/// * R1 = C + 1.1 × (H - L) / 12, R2 = C + 1.1 × (H - L) / 6, R3 = C + 1.1 × (H - L) / 4
+/// * S1 = C - 1.1 × (H - L) / 12, S2 = C - 1.1 × (H - L) / 6, S3 = C - 1.1 × (H - L) / 4
+///
+/// Where:
+///
+/// * _H_, _L_, _C_ - high, low and close prices of the previous period
+/// * _O<sub>t</sub>_ - open price of the current period
+///
+/// The first period has no previous prices, so 0 is returned for all the levels.
+///
+/// # Parameters
+///
+/// * _variant_ - formula used to compute the levels. Default is [PivotVariant::Classic](enum.PivotVariant.html).
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{PivotPoints, PivotVariant};
+/// use ta::{DataItem, Next};
+///
+/// let mut pivot = PivotPoints::new(PivotVariant::Classic);
+///
+/// let di1 = DataItem::builder()
+///             .high(12.0)
+///             .low(9.0)
+///             .close(9.0)
+///             .open(10.0)
+///             .volume(1000.0)
+///             .build().unwrap();
+///
+/// let di2 = DataItem::builder()
+///             .high(11.0)
+///             .low(9.0)
+///             .close(10.0)
+///             .open(9.0)
+///             .volume(1000.0)
+///             .build().unwrap();
+///
+/// assert_eq!(pivot.next(&di1).pivot, 0.0);
+///
+/// let out = pivot.next(&di2);
+/// assert_eq!(out.pivot, 10.0);
+/// assert_eq!(out.r1, 11.0);
+/// assert_eq!(out.s1, 8.0);
+/// ```
+///
+/// # Links
+///
+/// * [Pivot point, Wikipedia](https://en.wikipedia.org/wiki/Pivot_point_(technical_analysis))
+#[doc(alias = "PIVOT")]
+#[doc(alias = "PP")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PivotPoints {
+	variant: PivotVariant,
+	prev: Option<(f64, f64, f64)>,
+}
+
+/// Formula used by the [PivotPoints](struct.PivotPoints.html) indicator.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PivotVariant {
+	Classic,
+	Fibonacci,
+	Woodie,
+	Camarilla,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PivotPointsOutput {
+	pub pivot: f64,
+	pub r1: f64,
+	pub r2: f64,
+	pub r3: f64,
+	pub s1: f64,
+	pub s2: f64,
+	pub s3: f64,
+}
+
+impl PivotPoints {
+	pub fn new(variant: PivotVariant) -> Self {
+		Self {
+			variant,
+			prev: None,
+		}
+	}
+
+	pub fn variant(&self) -> PivotVariant {
+		self.variant
+	}
+}
+
+impl Period for PivotPoints {
+	fn period(&self) -> usize {
+		1
+	}
+}
+
+impl Ready for PivotPoints {
+	fn is_ready(&self) -> bool {
+		self.prev.is_some()
+	}
+}
+
+impl<T: Open + High + Low + Close> Next<&T> for PivotPoints {
+	type Output = PivotPointsOutput;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		let (high, low, close) = match self
+			.prev
+			.replace((input.high(), input.low(), input.close()))
+		{
+			Some(prev) => prev,
+			None => {
+				return PivotPointsOutput {
+					pivot: 0.0,
+					r1: 0.0,
+					r2: 0.0,
+					r3: 0.0,
+					s1: 0.0,
+					s2: 0.0,
+					s3: 0.0,
+				}
+			}
+		};
+
+		let range = high - low;
+		let classic = |pivot: f64| PivotPointsOutput {
+			pivot,
+			r1: 2.0 * pivot - low,
+			r2: pivot + range,
+			r3: high + 2.0 * (pivot - low),
+			s1: 2.0 * pivot - high,
+			s2: pivot - range,
+			s3: low - 2.0 * (high - pivot),
+		};
+
+		match self.variant {
+			PivotVariant::Classic => classic((high + low + close) / 3.0),
+			PivotVariant::Woodie => classic((high + low + 2.0 * input.open()) / 4.0),
+			PivotVariant::Fibonacci => {
+				let pivot = (high + low + close) / 3.0;
+				PivotPointsOutput {
+					pivot,
+					r1: pivot + 0.382 * range,
+					r2: pivot + 0.618 * range,
+					r3: pivot + range,
+					s1: pivot - 0.382 * range,
+					s2: pivot - 0.618 * range,
+					s3: pivot - range,
+				}
+			}
+			PivotVariant::Camarilla => PivotPointsOutput {
+				pivot: (high + low + close) / 3.0,
+				r1: close + 1.1 * range / 12.0,
+				r2: close + 1.1 * range / 6.0,
+				r3: close + 1.1 * range / 4.0,
+				s1: close - 1.1 * range / 12.0,
+				s2: close - 1.1 * range / 6.0,
+				s3: close - 1.1 * range / 4.0,
+			},
+		}
+	}
+}
+
+impl Reset for PivotPoints {
+	fn reset(&mut self) {
+		self.prev = None;
+	}
+}
+
+impl Default for PivotPoints {
+	fn default() -> Self {
+		Self::new(PivotVariant::Classic)
+	}
+}
+
+impl fmt::Display for PivotPoints {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self.variant {
+			PivotVariant::Classic => write!(f, "PIVOT"),
+			PivotVariant::Fibonacci => write!(f, "PIVOT(FIBONACCI)"),
+			PivotVariant::Woodie => write!(f, "PIVOT(WOODIE)"),
+			PivotVariant::Camarilla => write!(f, "PIVOT(CAMARILLA)"),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	fn levels(out: PivotPointsOutput) -> Vec<f64> {
+		vec![out.pivot, out.r1, out.r2, out.r3, out.s1, out.s2, out.s3]
+			.into_iter()
+			.map(round)
+			.collect()
+	}
+
+	#[test]
+	fn test_next_bar() {
+		let test_data = vec![
+			(
+				PivotVariant::Classic,
+				vec![9.167, 10.333, 11.167, 12.333, 8.333, 7.167, 6.333],
+			),
+			(
+				PivotVariant::Fibonacci,
+				vec![9.167, 9.931, 10.403, 11.167, 8.403, 7.931, 7.167],
+			),
+			(
+				PivotVariant::Woodie,
+				vec![9.3, 10.6, 11.3, 12.6, 8.6, 7.3, 6.6],
+			),
+			(
+				PivotVariant::Camarilla,
+				vec![9.167, 9.683, 9.867, 10.05, 9.317, 9.133, 8.95],
+			),
+		];
+
+		let bar1 = Bar::new().open(9).high(10).low(8).close(9.5);
+		let bar2 = Bar::new().open(9.6).high(11).low(9).close(10.5);
+
+		for (variant, expected) in test_data {
+			let mut pivot = PivotPoints::new(variant);
+			assert_eq!(levels(pivot.next(&bar1)), vec![0.0; 7]);
+			assert_eq!(levels(pivot.next(&bar2)), expected);
+		}
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut pivot = PivotPoints::default();
+		assert!(!pivot.is_ready());
+		pivot.next(&Bar::new().open(9).high(10).low(8).close(9.5));
+		assert!(pivot.is_ready());
+
+		pivot.reset();
+		assert!(!pivot.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut pivot = PivotPoints::default();
+		let bar1 = Bar::new().open(9).high(10).low(8).close(9.5);
+		let bar2 = Bar::new().open(9.6).high(11).low(9).close(10.5);
+
+		pivot.next(&bar1);
+		assert_eq!(round(pivot.next(&bar2).pivot), 9.167);
+
+		pivot.reset();
+		assert_eq!(pivot.next(&bar2).pivot, 0.0);
+		assert_eq!(round(pivot.next(&bar1).pivot), 10.167);
+	}
+
+	#[test]
+	fn test_default() {
+		let pivot = PivotPoints::default();
+		assert_eq!(pivot.variant(), PivotVariant::Classic);
+	}
+
+	#[test]
+	fn test_display() {
+		let pivot = PivotPoints::new(PivotVariant::Classic);
+		assert_eq!(format!("{}", pivot), "PIVOT");
+
+		let pivot = PivotPoints::new(PivotVariant::Camarilla);
+		assert_eq!(format!("{}", pivot), "PIVOT(CAMARILLA)");
+	}
+}
@@ -0,0 +1,163 @@
+use core::fmt;
+
+use crate::{Close, Next, Period, Ready, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+define_indicator! {
+	/// Price Volume Trend (PVT).
+	///
+	/// A cumulative volume indicator. Unlike the [OBV](struct.OnBalanceVolume.html), which adds
+	/// the full volume on any uptick, the PVT adds a fraction of the volume proportional to the
+	/// percentage change of the price.
+	///
+	/// # Formula
+	///
+	/// PVT<sub>t</sub> = PVT<sub>t-1</sub> + V<sub>t</sub> × (C<sub>t</sub> - C<sub>t-1</sub>) / C<sub>t-1</sub>
+	///
+	/// Where:
+	///
+	/// * _C<sub>t</sub>_ - close price of the current period
+	/// * _V<sub>t</sub>_ - volume of the current period
+	///
+	/// The first period has no previous close, so 0 is returned. If the previous close is 0 or
+	/// negative, nothing is added for that period.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ta::indicators::PriceVolumeTrend;
+	/// use ta::{DataItem, Next};
+	///
+	/// let mut pvt = PriceVolumeTrend::new();
+	///
+	/// let di1 = DataItem::builder()
+	///             .high(11.0)
+	///             .low(9.0)
+	///             .close(10.0)
+	///             .open(10.0)
+	///             .volume(1000.0)
+	///             .build().unwrap();
+	///
+	/// let di2 = DataItem::builder()
+	///             .high(12.0)
+	///             .low(10.0)
+	///             .close(11.0)
+	///             .open(10.0)
+	///             .volume(500.0)
+	///             .build().unwrap();
+	///
+	/// assert_eq!(pvt.next(&di1), 0.0);
+	/// assert_eq!(pvt.next(&di2), 50.0);
+	/// ```
+	///
+	/// # Links
+	///
+	/// * [Volume-price trend, Wikipedia](https://en.wikipedia.org/wiki/Volume%E2%80%93price_trend)
+	#[doc(alias = "PVT")]
+	pub struct PriceVolumeTrend {
+		pvt: f64 = 0.0,
+		prev_close: Option<f64> = None,
+	}
+	display(indicator) = ("PVT");
+	default = Self::new();
+}
+
+impl PriceVolumeTrend {
+	pub fn new() -> Self {
+		Self {
+			pvt: 0.0,
+			prev_close: None,
+		}
+	}
+}
+
+impl<T: Close + Volume> Next<&T> for PriceVolumeTrend {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		let close = input.close();
+		match self.prev_close.replace(close) {
+			Some(prev_close) if prev_close > 0.0 => {
+				self.pvt += input.volume() * (close - prev_close) / prev_close;
+			}
+			// The first period has nothing to compare with and a previous close which is
+			// not positive gives no meaningful change, nothing is added
+			_ => {}
+		}
+		self.pvt
+	}
+}
+
+impl Period for PriceVolumeTrend {
+	fn period(&self) -> usize {
+		1
+	}
+}
+
+impl Ready for PriceVolumeTrend {
+	fn is_ready(&self) -> bool {
+		true
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	#[test]
+	fn test_next_bar() {
+		let mut pvt = PriceVolumeTrend::new();
+
+		let bar1 = Bar::new().close(10).volume(1000.0);
+		let bar2 = Bar::new().close(11).volume(500.0);
+		let bar3 = Bar::new().close(8.8).volume(1000.0);
+		let bar4 = Bar::new().close(8.8).volume(4000.0);
+
+		assert_eq!(pvt.next(&bar1), 0.0);
+		assert_eq!(pvt.next(&bar2), 50.0);
+		assert_eq!(round(pvt.next(&bar3)), -150.0);
+		assert_eq!(round(pvt.next(&bar4)), -150.0);
+	}
+
+	#[test]
+	fn test_next_non_positive_close() {
+		let mut pvt = PriceVolumeTrend::new();
+
+		let bar1 = Bar::new().close(0).volume(1000.0);
+		let bar2 = Bar::new().close(5).volume(1000.0);
+		let bar3 = Bar::new().close(10).volume(1000.0);
+
+		assert_eq!(pvt.next(&bar1), 0.0);
+		assert_eq!(pvt.next(&bar2), 0.0);
+		assert_eq!(pvt.next(&bar3), 1000.0);
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut pvt = PriceVolumeTrend::new();
+
+		let bar1 = Bar::new().close(10).volume(1000.0);
+		let bar2 = Bar::new().close(11).volume(500.0);
+
+		assert_eq!(pvt.next(&bar1), 0.0);
+		assert_eq!(pvt.next(&bar2), 50.0);
+
+		pvt.reset();
+
+		assert_eq!(pvt.next(&bar1), 0.0);
+		assert_eq!(pvt.next(&bar2), 50.0);
+	}
+
+	#[test]
+	fn test_default() {
+		PriceVolumeTrend::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let pvt = PriceVolumeTrend::new();
+		assert_eq!(format!("{}", pvt), "PVT");
+	}
+}
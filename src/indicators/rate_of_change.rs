@@ -1,7 +1,9 @@
-use std::fmt;
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::traits::{Close, Next, Period, Reset};
+use crate::traits::{Close, Next, Period, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -17,6 +19,8 @@ use serde::{Deserialize, Serialize};
 /// * P<sub>t</sub> - price at the moment
 /// * P<sub>t-n</sub> - price _n_ periods ago
 ///
+/// If the price _n_ periods ago is 0, the ROC is undefined and `f64::NAN` is returned.
+///
 /// # Parameters
 ///
 /// * _period_ - number of periods integer greater than 0
@@ -40,7 +44,7 @@ use serde::{Deserialize, Serialize};
 ///
 #[doc(alias = "ROC")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct RateOfChange {
 	period: usize,
 	index: usize,
@@ -68,6 +72,12 @@ impl Period for RateOfChange {
 	}
 }
 
+impl Ready for RateOfChange {
+	fn is_ready(&self) -> bool {
+		self.count > self.period
+	}
+}
+
 impl Next<f64> for RateOfChange {
 	type Output = f64;
 
@@ -90,6 +100,9 @@ impl Next<f64> for RateOfChange {
 			0
 		};
 
+		if previous == 0.0 {
+			return f64::NAN;
+		}
 		(input - previous) / previous * 100.0
 	}
 }
@@ -150,6 +163,15 @@ mod tests {
 		assert_eq!(round(roc.next(10.0)), -5.393);
 	}
 
+	#[test]
+	fn test_next_zero_previous() {
+		let mut roc = RateOfChange::new(1).unwrap();
+
+		assert!(roc.next(0.0).is_nan());
+		assert!(roc.next(5.0).is_nan());
+		assert_eq!(roc.next(10.0), 100.0);
+	}
+
 	#[test]
 	fn test_next_bar() {
 		fn bar(close: f64) -> Bar {
@@ -176,4 +198,17 @@ mod tests {
 		assert_eq!(round(roc.next(10.4)), 4.0);
 		assert_eq!(round(roc.next(10.57)), 5.7);
 	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut roc = RateOfChange::new(2).unwrap();
+		roc.next(10.0);
+		roc.next(11.0);
+		assert!(!roc.is_ready());
+		roc.next(12.0);
+		assert!(roc.is_ready());
+
+		roc.reset();
+		assert!(!roc.is_ready());
+	}
 }
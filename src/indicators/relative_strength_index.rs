@@ -1,8 +1,8 @@
-use std::fmt;
+use core::fmt;
 
 use crate::errors::Result;
 use crate::indicators::ExponentialMovingAverage as Ema;
-use crate::{Close, Next, Period, Reset};
+use crate::{Close, Next, Period, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -28,6 +28,10 @@ use serde::{Deserialize, Serialize};
 /// * EMA<sub>Ut</sub> - value of [EMA](struct.ExponentialMovingAverage.html) of up periods in a moment of time _t_
 /// * EMA<sub>Dt</sub> - value of [EMA](struct.ExponentialMovingAverage.html) of down periods in a moment of time _t_
 ///
+/// The EMAs use the smoothing factor α = 1 / period of Wilder's original definition, also
+/// used by TradingView. Some sources like stockcharts.com use the standard α = 2 / (period + 1)
+/// instead, which reacts faster: see [RsiSmoothing](enum.RsiSmoothing.html).
+///
 /// If current period has value higher than previous period, than:
 ///
 /// U = p<sub>t</sub> - p<sub>t-1</sub>
@@ -50,6 +54,8 @@ use serde::{Deserialize, Serialize};
 /// # Parameters
 ///
 /// * _period_ - number of periods (integer greater than 0). Default value is 14.
+/// * _smoothing_ - smoothing of the up and down periods. Default is
+///   [Wilder](enum.RsiSmoothing.html#variant.Wilder).
 ///
 /// # Example
 ///
@@ -59,9 +65,9 @@ use serde::{Deserialize, Serialize};
 ///
 /// let mut rsi = RelativeStrengthIndex::new(3).unwrap();
 /// assert_eq!(rsi.next(10.0), 50.0);
-/// assert_eq!(rsi.next(10.5).round(), 86.0);
-/// assert_eq!(rsi.next(10.0).round(), 35.0);
-/// assert_eq!(rsi.next(9.5).round(), 16.0);
+/// assert_eq!(rsi.next(10.5).round(), 78.0);
+/// assert_eq!(rsi.next(10.0).round(), 42.0);
+/// assert_eq!(rsi.next(9.5).round(), 25.0);
 /// ```
 ///
 /// # Links
@@ -70,25 +76,53 @@ use serde::{Deserialize, Serialize};
 ///
 #[doc(alias = "RSI")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct RelativeStrengthIndex {
 	period: usize,
+	smoothing: RsiSmoothing,
 	up_ema_indicator: Ema,
 	down_ema_indicator: Ema,
 	prev_val: f64,
-	is_new: bool,
+	count: usize,
+}
+
+/// Smoothing of the up and down periods of the
+/// [RelativeStrengthIndex](struct.RelativeStrengthIndex.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RsiSmoothing {
+	/// Wilder's smoothed moving average, an EMA with α = 1 / period. This is the default.
+	#[default]
+	Wilder,
+	/// Standard EMA with α = 2 / (period + 1).
+	Ema,
 }
 
 impl RelativeStrengthIndex {
 	pub fn new(period: usize) -> Result<Self> {
+		Self::new_with_smoothing(period, RsiSmoothing::Wilder)
+	}
+
+	pub fn new_with_smoothing(period: usize, smoothing: RsiSmoothing) -> Result<Self> {
+		// α = 1 / n is the smoothing factor of an EMA of period 2n - 1
+		let ema_period = match smoothing {
+			RsiSmoothing::Wilder if period > 0 => 2 * period - 1,
+			_ => period,
+		};
+
 		Ok(Self {
 			period,
-			up_ema_indicator: Ema::new(period)?,
-			down_ema_indicator: Ema::new(period)?,
+			smoothing,
+			up_ema_indicator: Ema::new(ema_period)?,
+			down_ema_indicator: Ema::new(ema_period)?,
 			prev_val: 0.0,
-			is_new: true,
+			count: 0,
 		})
 	}
+
+	pub fn smoothing(&self) -> RsiSmoothing {
+		self.smoothing
+	}
 }
 
 impl Period for RelativeStrengthIndex {
@@ -97,6 +131,12 @@ impl Period for RelativeStrengthIndex {
 	}
 }
 
+impl Ready for RelativeStrengthIndex {
+	fn is_ready(&self) -> bool {
+		self.count == self.period
+	}
+}
+
 impl Next<f64> for RelativeStrengthIndex {
 	type Output = f64;
 
@@ -104,8 +144,7 @@ impl Next<f64> for RelativeStrengthIndex {
 		let mut up = 0.0;
 		let mut down = 0.0;
 
-		if self.is_new {
-			self.is_new = false;
+		if self.count == 0 {
 			// Initialize with some small seed numbers to avoid division by zero
 			up = 0.1;
 			down = 0.1;
@@ -115,10 +154,15 @@ impl Next<f64> for RelativeStrengthIndex {
 			down = self.prev_val - input;
 		}
 
+		if self.count < self.period {
+			self.count += 1;
+		}
+
 		self.prev_val = input;
 		let up_ema = self.up_ema_indicator.next(up);
 		let down_ema = self.down_ema_indicator.next(down);
-		100.0 * up_ema / (up_ema + down_ema)
+		// divide first so that equal EMAs give exactly 50
+		100.0 * (up_ema / (up_ema + down_ema))
 	}
 }
 
@@ -132,7 +176,7 @@ impl<T: Close> Next<&T> for RelativeStrengthIndex {
 
 impl Reset for RelativeStrengthIndex {
 	fn reset(&mut self) {
-		self.is_new = true;
+		self.count = 0;
 		self.prev_val = 0.0;
 		self.up_ema_indicator.reset();
 		self.down_ema_indicator.reset();
@@ -147,7 +191,10 @@ impl Default for RelativeStrengthIndex {
 
 impl fmt::Display for RelativeStrengthIndex {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		write!(f, "RSI({})", self.period)
+		match self.smoothing {
+			RsiSmoothing::Wilder => write!(f, "RSI({})", self.period),
+			RsiSmoothing::Ema => write!(f, "RSI({}, EMA)", self.period),
+		}
 	}
 }
 
@@ -164,24 +211,68 @@ mod tests {
 		assert!(RelativeStrengthIndex::new(1).is_ok());
 	}
 
+	#[test]
+	fn test_new_with_smoothing() {
+		assert!(RelativeStrengthIndex::new_with_smoothing(0, RsiSmoothing::Wilder).is_err());
+		assert!(RelativeStrengthIndex::new_with_smoothing(0, RsiSmoothing::Ema).is_err());
+
+		let rsi = RelativeStrengthIndex::new_with_smoothing(3, RsiSmoothing::Ema).unwrap();
+		assert_eq!(rsi.smoothing(), RsiSmoothing::Ema);
+		assert_eq!(rsi.period(), 3);
+		assert_eq!(
+			RelativeStrengthIndex::new(3).unwrap().smoothing(),
+			RsiSmoothing::Wilder
+		);
+	}
+
 	#[test]
 	fn test_next() {
 		let mut rsi = RelativeStrengthIndex::new(3).unwrap();
 		assert_eq!(rsi.next(10.0), 50.0);
+		assert_eq!(round(rsi.next(10.5)), 77.778);
+		assert_eq!(round(rsi.next(10.0)), 42.424);
+		assert_eq!(round(rsi.next(9.5)), 25.225);
+	}
+
+	#[test]
+	fn test_next_ema_smoothing() {
+		let mut rsi = RelativeStrengthIndex::new_with_smoothing(3, RsiSmoothing::Ema).unwrap();
+		assert_eq!(rsi.next(10.0), 50.0);
 		assert_eq!(rsi.next(10.5).round(), 86.0);
 		assert_eq!(rsi.next(10.0).round(), 35.0);
 		assert_eq!(rsi.next(9.5).round(), 16.0);
 	}
 
+	#[test]
+	fn test_next_flat() {
+		let mut rsi = RelativeStrengthIndex::new(3).unwrap();
+		for _ in 0..10 {
+			assert_eq!(rsi.next(5.0), 50.0);
+		}
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut rsi = RelativeStrengthIndex::new(3).unwrap();
+		rsi.next(1.0);
+		rsi.next(2.0);
+		assert!(!rsi.is_ready());
+		rsi.next(3.0);
+		assert!(rsi.is_ready());
+
+		rsi.reset();
+		assert!(!rsi.is_ready());
+	}
+
 	#[test]
 	fn test_reset() {
 		let mut rsi = RelativeStrengthIndex::new(3).unwrap();
 		assert_eq!(rsi.next(10.0), 50.0);
-		assert_eq!(rsi.next(10.5).round(), 86.0);
+		assert_eq!(rsi.next(10.5).round(), 78.0);
 
 		rsi.reset();
 		assert_eq!(rsi.next(10.0).round(), 50.0);
-		assert_eq!(rsi.next(10.5).round(), 86.0);
+		assert_eq!(rsi.next(10.5).round(), 78.0);
 	}
 
 	#[test]
@@ -193,5 +284,7 @@ mod tests {
 	fn test_display() {
 		let rsi = RelativeStrengthIndex::new(16).unwrap();
 		assert_eq!(format!("{}", rsi), "RSI(16)");
+		let rsi = RelativeStrengthIndex::new_with_smoothing(16, RsiSmoothing::Ema).unwrap();
+		assert_eq!(format!("{}", rsi), "RSI(16, EMA)");
 	}
 }
@@ -0,0 +1,259 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling sum of the last _n_ inputs.
+///
+/// A building block for indicators which sum a value over a window, e.g. a money flow or
+/// a true range.
+///
+/// # Formula
+///
+/// SUM<sub>t</sub> = SUM<sub>t-1</sub> + p<sub>t</sub> - p<sub>t-n</sub>
+///
+/// Where:
+///
+/// * _p<sub>t</sub>_ - input value at a point of time _t_
+/// * _n_ - number of periods
+///
+/// Until _n_ inputs have been consumed, the sum of the inputs so far is returned. The running
+/// sum is updated with Kahan compensated summation, so its rounding error stays small on long
+/// series, and it is exactly 0 once the window only holds zeros, e.g. the ranges of flat bars.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::RollingSum;
+/// use ta::Next;
+///
+/// let mut sum = RollingSum::new(3).unwrap();
+/// assert_eq!(sum.next(1.0), 1.0);
+/// assert_eq!(sum.next(2.0), 3.0);
+/// assert_eq!(sum.next(3.0), 6.0);
+/// assert_eq!(sum.next(4.0), 9.0);
+/// ```
+#[doc(alias = "SUM")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RollingSum {
+	period: usize,
+	index: usize,
+	count: usize,
+	nonzero: usize,
+	sum: f64,
+	compensation: f64,
+	deque: Box<[f64]>,
+}
+
+impl RollingSum {
+	pub fn new(period: usize) -> Result<Self> {
+		match period {
+			0 => Err(TaError::InvalidParameter),
+			_ => Ok(Self {
+				period,
+				index: 0,
+				count: 0,
+				nonzero: 0,
+				sum: 0.0,
+				compensation: 0.0,
+				deque: vec![0.0; period].into_boxed_slice(),
+			}),
+		}
+	}
+
+	/// Returns the current sum, without consuming an input.
+	pub fn sum(&self) -> f64 {
+		self.sum
+	}
+}
+
+impl Period for RollingSum {
+	fn period(&self) -> usize {
+		self.period
+	}
+}
+
+impl Ready for RollingSum {
+	fn is_ready(&self) -> bool {
+		self.count == self.period
+	}
+}
+
+impl Next<f64> for RollingSum {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		let old_val = self.deque[self.index];
+		self.deque[self.index] = input;
+
+		self.index = if self.index + 1 < self.period {
+			self.index + 1
+		} else {
+			0
+		};
+
+		if self.count < self.period {
+			self.count += 1;
+		}
+
+		if old_val != 0.0 {
+			self.nonzero -= 1;
+		}
+		if input != 0.0 {
+			self.nonzero += 1;
+		}
+
+		if self.nonzero == 0 {
+			// the rounding error left by the values which have left the window
+			self.sum = 0.0;
+			self.compensation = 0.0;
+		} else {
+			let delta = (input - old_val) - self.compensation;
+			let sum = self.sum + delta;
+			self.compensation = (sum - self.sum) - delta;
+			self.sum = sum;
+		}
+
+		self.sum
+	}
+}
+
+impl<T: Close> Next<&T> for RollingSum {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl Reset for RollingSum {
+	fn reset(&mut self) {
+		self.index = 0;
+		self.count = 0;
+		self.nonzero = 0;
+		self.sum = 0.0;
+		self.compensation = 0.0;
+		for i in 0..self.period {
+			self.deque[i] = 0.0;
+		}
+	}
+}
+
+impl Default for RollingSum {
+	fn default() -> Self {
+		Self::new(9).unwrap()
+	}
+}
+
+impl fmt::Display for RollingSum {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "SUM({})", self.period)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	test_indicator!(RollingSum);
+
+	#[test]
+	fn test_new() {
+		assert!(RollingSum::new(0).is_err());
+		assert!(RollingSum::new(1).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let mut sum = RollingSum::new(3).unwrap();
+		assert_eq!(sum.next(4.0), 4.0);
+		assert_eq!(sum.next(5.0), 9.0);
+		assert_eq!(sum.next(6.0), 15.0);
+		assert_eq!(sum.next(-6.0), 5.0);
+		assert_eq!(sum.next(0.0), 0.0);
+		assert_eq!(sum.sum(), 0.0);
+	}
+
+	#[test]
+	fn test_next_period_one() {
+		let mut sum = RollingSum::new(1).unwrap();
+		assert_eq!(sum.next(4.0), 4.0);
+		assert_eq!(sum.next(-2.5), -2.5);
+	}
+
+	#[test]
+	fn test_next_with_bars() {
+		let mut sum = RollingSum::new(2).unwrap();
+		assert_eq!(sum.next(&Bar::new().close(4)), 4.0);
+		assert_eq!(sum.next(&Bar::new().close(7)), 11.0);
+		assert_eq!(sum.next(&Bar::new().close(1)), 8.0);
+	}
+
+	#[test]
+	fn test_next_no_drift() {
+		// the compensation cancels the rounding error of adding and removing 0.1
+		let mut sum = RollingSum::new(10).unwrap();
+		for _ in 0..100_000 {
+			sum.next(0.1);
+		}
+		assert!((sum.next(0.1) - 1.0).abs() < 1e-14);
+	}
+
+	#[test]
+	fn test_next_zeros() {
+		let mut sum = RollingSum::new(3).unwrap();
+		// the rounding errors of these do not cancel out
+		for x in &[0.2, 0.1, 1.7, 0.9] {
+			sum.next(*x);
+		}
+		for _ in 0..3 {
+			sum.next(0.0);
+		}
+		assert_eq!(sum.sum(), 0.0);
+		assert_eq!(sum.next(0.5), 0.5);
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut sum = RollingSum::new(2).unwrap();
+		sum.next(1.0);
+		assert!(!sum.is_ready());
+		sum.next(2.0);
+		assert!(sum.is_ready());
+
+		sum.reset();
+		assert!(!sum.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut sum = RollingSum::new(3).unwrap();
+		sum.next(4.0);
+		sum.next(5.0);
+
+		sum.reset();
+		assert_eq!(sum.next(1.0), 1.0);
+		assert_eq!(sum.next(2.0), 3.0);
+	}
+
+	#[test]
+	fn test_default() {
+		assert_eq!(RollingSum::default().period(), 9);
+	}
+
+	#[test]
+	fn test_display() {
+		let sum = RollingSum::new(5).unwrap();
+		assert_eq!(format!("{}", sum), "SUM(5)");
+	}
+}
@@ -0,0 +1,255 @@
+use core::fmt;
+
+use num_traits::Float;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{LogReturn, StandardDeviation};
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Sharpe Ratio (SHARPE).
+///
+/// Returns the annualized Sharpe ratio of the last n periods: the mean excess log return
+/// divided by its standard deviation. It measures the return earned per unit of risk.
+///
+/// # Formula
+///
+/// R<sub>t</sub> = ln(C<sub>t</sub> / C<sub>t-1</sub>) - r<sub>f</sub> / d
+///
+/// SHARPE = mean(R, n) / SD(R, n) × √d
+///
+/// Where:
+///
+/// * _R<sub>t</sub>_ - excess log return of the current period
+/// * _SD_ - [standard deviation](struct.StandardDeviation.html) of the last _n_ excess returns
+/// * _r<sub>f</sub>_ - annual risk free rate
+/// * _d_ - number of trading days per year
+///
+/// The ratio is undefined when the standard deviation is 0, for example for a flat price
+/// or until two log returns have been consumed, and `f64::NAN` is returned. A period whose
+/// current or previous price is not positive has no log return; it is skipped and the
+/// previous value is returned.
+///
+/// # Parameters
+///
+/// * _period_ - number of log returns (integer greater than 0). Default is 252.
+/// * _risk_free_rate_ - annual risk free rate (finite number). Default is 0.
+/// * _trading_days_per_year_ - annualization factor (integer greater than 0). Default is 252.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SharpeRatio;
+/// use ta::Next;
+///
+/// let mut sharpe = SharpeRatio::new(3, 0.0, 252).unwrap();
+/// assert!(sharpe.next(100.0).is_nan());
+/// assert!(sharpe.next(102.0).is_nan());
+/// assert!(sharpe.next(101.0) > 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Sharpe ratio, Wikipedia](https://en.wikipedia.org/wiki/Sharpe_ratio)
+#[doc(alias = "SHARPE")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SharpeRatio {
+	sd: StandardDeviation,
+	risk_free_rate: f64,
+	trading_days_per_year: usize,
+	log_return: LogReturn,
+	current: f64,
+}
+
+impl SharpeRatio {
+	pub fn new(period: usize, risk_free_rate: f64, trading_days_per_year: usize) -> Result<Self> {
+		if !risk_free_rate.is_finite() || trading_days_per_year == 0 {
+			return Err(TaError::InvalidParameter);
+		}
+
+		Ok(Self {
+			sd: StandardDeviation::new(period)?,
+			risk_free_rate,
+			trading_days_per_year,
+			log_return: LogReturn::new(),
+			current: f64::NAN,
+		})
+	}
+
+	pub fn risk_free_rate(&self) -> f64 {
+		self.risk_free_rate
+	}
+
+	pub fn trading_days_per_year(&self) -> usize {
+		self.trading_days_per_year
+	}
+}
+
+impl Period for SharpeRatio {
+	fn period(&self) -> usize {
+		self.sd.period()
+	}
+}
+
+impl Ready for SharpeRatio {
+	fn is_ready(&self) -> bool {
+		self.sd.is_ready()
+	}
+}
+
+impl Next<f64> for SharpeRatio {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		// the first period has no log return and a non positive price gives NaN
+		let had_prev = self.log_return.is_ready();
+		let log_return = self.log_return.next(input);
+		if had_prev && !log_return.is_nan() {
+			let days = self.trading_days_per_year as f64;
+			let sd = self.sd.next(log_return - self.risk_free_rate / days);
+			self.current = if sd == 0.0 {
+				f64::NAN
+			} else {
+				self.sd.mean() / sd * Float::sqrt(days)
+			};
+		}
+
+		self.current
+	}
+}
+
+impl<T: Close> Next<&T> for SharpeRatio {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl Reset for SharpeRatio {
+	fn reset(&mut self) {
+		self.sd.reset();
+		self.log_return.reset();
+		self.current = f64::NAN;
+	}
+}
+
+impl Default for SharpeRatio {
+	fn default() -> Self {
+		Self::new(252, 0.0, 252).unwrap()
+	}
+}
+
+impl fmt::Display for SharpeRatio {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if self.risk_free_rate == 0.0 && self.trading_days_per_year == 252 {
+			write!(f, "SHARPE({})", self.sd.period())
+		} else {
+			write!(
+				f,
+				"SHARPE({}, {}, {})",
+				self.sd.period(),
+				self.risk_free_rate,
+				self.trading_days_per_year
+			)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	#[test]
+	fn test_new() {
+		assert!(SharpeRatio::new(0, 0.0, 252).is_err());
+		assert!(SharpeRatio::new(20, f64::NAN, 252).is_err());
+		assert!(SharpeRatio::new(20, f64::INFINITY, 252).is_err());
+		assert!(SharpeRatio::new(20, 0.0, 0).is_err());
+		assert!(SharpeRatio::new(1, -0.01, 1).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let test_data = vec![
+			// close, rf = 0, rf = 0.05
+			(101.0, 5.326, 5.114),
+			(106.0, 12.982, 12.85),
+			(103.0, 1.575, 1.479),
+			(104.0, 4.925, 4.825),
+		];
+
+		let mut sharpe = SharpeRatio::new(3, 0.0, 252).unwrap();
+		let mut sharpe_rf = SharpeRatio::new(3, 0.05, 252).unwrap();
+		assert!(sharpe.next(100.0).is_nan());
+		assert!(sharpe_rf.next(100.0).is_nan());
+		assert!(sharpe.next(102.0).is_nan());
+		assert!(sharpe_rf.next(102.0).is_nan());
+
+		for (close, expected, expected_rf) in test_data {
+			assert_eq!(round(sharpe.next(close)), expected);
+			assert_eq!(round(sharpe_rf.next(close)), expected_rf);
+		}
+	}
+
+	#[test]
+	fn test_next_flat() {
+		let mut sharpe = SharpeRatio::new(3, 0.0, 252).unwrap();
+		for _ in 0..5 {
+			assert!(sharpe.next(100.0).is_nan());
+		}
+	}
+
+	#[test]
+	fn test_next_non_positive_price() {
+		let mut sharpe = SharpeRatio::new(3, 0.0, 252).unwrap();
+		sharpe.next(100.0);
+		sharpe.next(102.0);
+		assert_eq!(round(sharpe.next(101.0)), 5.326);
+		assert_eq!(round(sharpe.next(0.0)), 5.326);
+		assert_eq!(round(sharpe.next(106.0)), 5.326);
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut sharpe = SharpeRatio::new(2, 0.0, 252).unwrap();
+		sharpe.next(100.0);
+		sharpe.next(101.0);
+		assert!(!sharpe.is_ready());
+		sharpe.next(102.0);
+		assert!(sharpe.is_ready());
+
+		sharpe.reset();
+		assert!(!sharpe.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut sharpe = SharpeRatio::new(3, 0.0, 252).unwrap();
+		sharpe.next(100.0);
+		sharpe.next(102.0);
+		assert_eq!(round(sharpe.next(101.0)), 5.326);
+
+		sharpe.reset();
+		assert!(sharpe.next(100.0).is_nan());
+		assert!(sharpe.next(102.0).is_nan());
+		assert_eq!(round(sharpe.next(101.0)), 5.326);
+	}
+
+	#[test]
+	fn test_default() {
+		SharpeRatio::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let sharpe = SharpeRatio::new(252, 0.0, 252).unwrap();
+		assert_eq!(format!("{}", sharpe), "SHARPE(252)");
+
+		let sharpe = SharpeRatio::new(20, 0.05, 365).unwrap();
+		assert_eq!(format!("{}", sharpe), "SHARPE(20, 0.05, 365)");
+	}
+}
@@ -1,10 +1,18 @@
-use std::fmt;
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
 
-use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
+use num_traits::Float;
+
+use crate::errors::Result;
+use crate::types;
+use crate::{Close, Next, Period, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// Number of inputs after which the running sum is recomputed from the window.
+const RECOMPUTE_INTERVAL: usize = 1000;
+
 /// Simple moving average (SMA).
 ///
 /// # Formula
@@ -17,6 +25,9 @@ use serde::{Deserialize, Serialize};
 /// * _period_ - number of periods (period)
 /// * _p<sub>t</sub>_ - input value at a point of time _t_
 ///
+/// The running sum is updated with Kahan compensated summation and recomputed from the
+/// window every 1000 inputs, so its rounding error stays bounded however long the series is.
+///
 /// # Parameters
 ///
 /// * _period_ - number of periods (integer greater than 0)
@@ -37,43 +48,149 @@ use serde::{Deserialize, Serialize};
 /// # Links
 ///
 /// * [Simple Moving Average, Wikipedia](https://en.wikipedia.org/wiki/Moving_average#Simple_moving_average)
-///
 #[doc(alias = "SMA")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone)]
-pub struct SimpleMovingAverage {
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimpleMovingAverage<T = f64> {
 	period: usize,
 	index: usize,
 	count: usize,
-	sum: f64,
-	deque: Box<[f64]>,
+	sum: T,
+	compensation: T,
+	recompute_counter: usize,
+	deque: Box<[T]>,
 }
 
 impl SimpleMovingAverage {
 	pub fn new(period: usize) -> Result<Self> {
-		match period {
-			0 => Err(TaError::InvalidParameter),
-			_ => Ok(Self {
-				period,
-				index: 0,
-				count: 0,
-				sum: 0.0,
-				deque: vec![0.0; period].into_boxed_slice(),
-			}),
+		Self::new_generic(period)
+	}
+
+	/// Creates the indicator from an already validated period, which cannot fail.
+	pub fn new_with_period(period: types::PeriodValue) -> Self {
+		Self::new_generic_with_period(period)
+	}
+
+	/// Computes the SMA of a whole slice at once, writing one value per input into `output`.
+	///
+	/// The result and the state left behind are the same as calling [`next`](Next::next) on
+	/// each input, up to floating point rounding. Once the window is full, the remaining
+	/// inputs are split into 4 contiguous chunks which are rolled in parallel in the lanes of
+	/// an `f64x4`, breaking the dependency chain of the running sum.
+	///
+	/// Available with the `simd` feature.
+	///
+	/// # Panics
+	///
+	/// Panics if `input` and `output` have different lengths.
+	#[cfg(feature = "simd")]
+	pub fn next_slice_simd(&mut self, input: &[f64], output: &mut [f64]) {
+		use wide::f64x4;
+
+		assert_eq!(input.len(), output.len());
+		let n = self.period;
+
+		// fill the window until every value leaving it is part of `input`
+		let start = n.min(input.len());
+		for i in 0..start {
+			output[i] = self.next(input[i]);
+		}
+
+		let chunk = (input.len() - start) / 4;
+		let end = start + 4 * chunk;
+		if chunk > 0 {
+			let lanes = [start, start + chunk, start + 2 * chunk, start + 3 * chunk];
+			let mut sums = f64x4::new(lanes.map(|s| input[s - n..s].iter().sum()));
+			let divisor = f64x4::splat(n as f64);
+
+			for t in 0..chunk {
+				let added = f64x4::new(lanes.map(|s| input[s + t]));
+				let removed = f64x4::new(lanes.map(|s| input[s + t - n]));
+				sums = sums - removed + added;
+				let averages = (sums / divisor).to_array();
+				for (lane, s) in lanes.iter().enumerate() {
+					output[s + t] = averages[lane];
+				}
+			}
+
+			// leave the ring buffer as if every input had been passed to `next`
+			for &x in &input[end - n..end] {
+				self.deque[self.index] = x;
+				self.index = if self.index + 1 < n {
+					self.index + 1
+				} else {
+					0
+				};
+			}
+			self.recompute_sum();
+		}
+
+		for i in end..input.len() {
+			output[i] = self.next(input[i]);
 		}
 	}
 }
 
-impl Period for SimpleMovingAverage {
+impl<T: Float> SimpleMovingAverage<T> {
+	/// Creates the indicator for another float type than `f64`, for example
+	/// `SimpleMovingAverage::<f32>::new_generic(3)`.
+	pub fn new_generic(period: usize) -> Result<Self> {
+		Ok(Self::new_generic_with_period(types::PeriodValue::try_from(
+			period,
+		)?))
+	}
+
+	/// Same as [new_with_period](#method.new_with_period) for another float type than `f64`.
+	pub fn new_generic_with_period(period: types::PeriodValue) -> Self {
+		let period = period.get();
+		Self {
+			period,
+			index: 0,
+			count: 0,
+			sum: T::zero(),
+			compensation: T::zero(),
+			recompute_counter: 0,
+			deque: vec![T::zero(); period].into_boxed_slice(),
+		}
+	}
+}
+
+impl<T: Float> SimpleMovingAverage<T> {
+	/// Sums the window from scratch, discarding the rounding error of the running sum.
+	fn recompute_sum(&mut self) {
+		self.sum = T::zero();
+		self.compensation = T::zero();
+		for i in 0..self.count {
+			self.add(self.deque[i]);
+		}
+		self.recompute_counter = 0;
+	}
+
+	/// Adds `value` to the running sum with Kahan compensated summation.
+	fn add(&mut self, value: T) {
+		let delta = value - self.compensation;
+		let sum = self.sum + delta;
+		self.compensation = (sum - self.sum) - delta;
+		self.sum = sum;
+	}
+}
+
+impl<T> Period for SimpleMovingAverage<T> {
 	fn period(&self) -> usize {
 		self.period
 	}
 }
 
-impl Next<f64> for SimpleMovingAverage {
-	type Output = f64;
+impl<T> Ready for SimpleMovingAverage<T> {
+	fn is_ready(&self) -> bool {
+		self.count == self.period
+	}
+}
 
-	fn next(&mut self, input: f64) -> Self::Output {
+impl<T: Float> Next<T> for SimpleMovingAverage<T> {
+	type Output = T;
+
+	fn next(&mut self, input: T) -> Self::Output {
 		let old_val = self.deque[self.index];
 		self.deque[self.index] = input;
 
@@ -87,26 +204,34 @@ impl Next<f64> for SimpleMovingAverage {
 			self.count += 1;
 		}
 
-		self.sum = self.sum - old_val + input;
-		self.sum / (self.count as f64)
+		// Kahan summation keeps the rounding error of the running sum bounded
+		self.add(input - old_val);
+		self.recompute_counter += 1;
+		if self.recompute_counter == RECOMPUTE_INTERVAL {
+			self.recompute_sum();
+		}
+
+		self.sum / T::from(self.count).unwrap()
 	}
 }
 
-impl<T: Close> Next<&T> for SimpleMovingAverage {
+impl<U: Close> Next<&U> for SimpleMovingAverage {
 	type Output = f64;
 
-	fn next(&mut self, input: &T) -> Self::Output {
+	fn next(&mut self, input: &U) -> Self::Output {
 		self.next(input.close())
 	}
 }
 
-impl Reset for SimpleMovingAverage {
+impl<T: Float> Reset for SimpleMovingAverage<T> {
 	fn reset(&mut self) {
 		self.index = 0;
 		self.count = 0;
-		self.sum = 0.0;
+		self.sum = T::zero();
+		self.compensation = T::zero();
+		self.recompute_counter = 0;
 		for i in 0..self.period {
-			self.deque[i] = 0.0;
+			self.deque[i] = T::zero();
 		}
 	}
 }
@@ -117,7 +242,7 @@ impl Default for SimpleMovingAverage {
 	}
 }
 
-impl fmt::Display for SimpleMovingAverage {
+impl<T> fmt::Display for SimpleMovingAverage<T> {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		write!(f, "SMA({})", self.period)
 	}
@@ -136,6 +261,16 @@ mod tests {
 		assert!(SimpleMovingAverage::new(1).is_ok());
 	}
 
+	#[test]
+	fn test_new_with_period() {
+		let period = types::PeriodValue::try_from(3_usize).unwrap();
+		assert_eq!(SimpleMovingAverage::new_with_period(period).period(), 3);
+		assert_eq!(
+			SimpleMovingAverage::<f32>::new_generic_with_period(period).period(),
+			3
+		);
+	}
+
 	#[test]
 	fn test_next() {
 		let mut sma = SimpleMovingAverage::new(4).unwrap();
@@ -148,6 +283,17 @@ mod tests {
 		assert_eq!(sma.next(2.0), 5.0);
 	}
 
+	#[test]
+	fn test_next_no_drift() {
+		let price = 1000000.123456789;
+		let mut sma = SimpleMovingAverage::new(20).unwrap();
+		let mut out = price;
+		for _ in 0..10_000_000 {
+			out = sma.next(price);
+		}
+		assert_eq!(out, price);
+	}
+
 	#[test]
 	fn test_next_with_bars() {
 		fn bar(close: f64) -> Bar {
@@ -172,6 +318,81 @@ mod tests {
 		assert_eq!(sma.next(99.0), 99.0);
 	}
 
+	#[test]
+	fn test_next_slice() {
+		let mut sma = SimpleMovingAverage::new(2).unwrap();
+		let mut out = vec![1.0];
+		let empty: &[f64] = &[];
+		sma.next_slice(empty, &mut out);
+		assert_eq!(out, vec![1.0]);
+
+		sma.next_slice(&[4.0, 6.0, 2.0], &mut out);
+		assert_eq!(out, vec![1.0, 4.0, 5.0, 4.0]);
+
+		assert_eq!(sma.next_slice_collect(&[8.0]), vec![5.0]);
+
+		let bars = [Bar::new().close(4.0), Bar::new().close(6.0)];
+		sma.reset();
+		assert_eq!(
+			sma.next_slice_collect(&[&bars[0], &bars[1]]),
+			vec![4.0, 5.0]
+		);
+	}
+
+	#[test]
+	fn test_peek() {
+		let mut sma = SimpleMovingAverage::new(2).unwrap();
+		assert_eq!(sma.next(4.0), 4.0);
+		assert_eq!(sma.peek(6.0), 5.0);
+		assert_eq!(sma.peek(8.0), 6.0);
+		assert_eq!(sma.next(2.0), 3.0);
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut sma = SimpleMovingAverage::new(3).unwrap();
+		sma.next(4.0);
+		sma.next(5.0);
+		assert!(!sma.is_ready());
+		sma.next(6.0);
+		assert!(sma.is_ready());
+
+		sma.reset();
+		assert!(!sma.is_ready());
+	}
+
+	#[cfg(feature = "simd")]
+	#[test]
+	fn test_next_slice_simd() {
+		let input: Vec<f64> = (0..103)
+			.map(|i| ((i * 37) % 17) as f64 + 0.25 * i as f64)
+			.collect();
+
+		for &period in &[1, 3, 4, 20, 103, 200] {
+			let mut scalar = SimpleMovingAverage::new(period).unwrap();
+			let mut simd = SimpleMovingAverage::new(period).unwrap();
+			let mut output = vec![0.0; input.len()];
+			simd.next_slice_simd(&input, &mut output);
+
+			for (x, y) in input.iter().zip(&output) {
+				assert!((scalar.next(*x) - y).abs() < 1e-9);
+			}
+
+			// the state carries on as with the scalar version
+			for x in &[3.0, 1000.0, -5.0] {
+				assert!((scalar.next(*x) - simd.next(*x)).abs() < 1e-9);
+			}
+		}
+	}
+
+	#[cfg(feature = "simd")]
+	#[test]
+	#[should_panic]
+	fn test_next_slice_simd_length_mismatch() {
+		let mut sma = SimpleMovingAverage::new(3).unwrap();
+		sma.next_slice_simd(&[1.0, 2.0], &mut [0.0]);
+	}
+
 	#[test]
 	fn test_default() {
 		SimpleMovingAverage::default();
@@ -0,0 +1,226 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
+
+use num_traits::Float;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Skewness (SKEW).
+///
+/// Returns the adjusted sample skewness of the last n values, a measure of the asymmetry of
+/// their distribution. A positive value means a longer right tail, a negative value a
+/// longer left tail. For return distributions, an absolute skewness above 1 is usually
+/// considered highly skewed.
+///
+/// The central moments are recomputed over the whole window on each period, which is
+/// O(n) but exact.
+///
+/// # Formula
+///
+/// SKEW = N × Σ (x<sub>i</sub> - μ)³ / ((N - 1)(N - 2) × s³)
+///
+/// Where:
+///
+/// * _μ_ - mean of the last _N_ values
+/// * _s_ - sample standard deviation of the last _N_ values
+/// * _N_ - number of values in the observation
+///
+/// Until 3 values have been consumed, or if all the values are equal, 0 is returned.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 2). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Skewness;
+/// use ta::Next;
+///
+/// let mut skew = Skewness::new(3).unwrap();
+/// assert_eq!(skew.next(1.0), 0.0);
+/// assert_eq!(skew.next(2.0), 0.0);
+/// assert_eq!(skew.next(3.0), 0.0);
+/// assert!(skew.next(10.0) > 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Skewness, Wikipedia](https://en.wikipedia.org/wiki/Skewness#Sample_skewness)
+#[doc(alias = "SKEW")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Skewness {
+	period: usize,
+	index: usize,
+	count: usize,
+	deque: Box<[f64]>,
+}
+
+impl Skewness {
+	pub fn new(period: usize) -> Result<Self> {
+		match period {
+			0..=2 => Err(TaError::InvalidParameter),
+			_ => Ok(Self {
+				period,
+				index: 0,
+				count: 0,
+				deque: vec![0.0; period].into_boxed_slice(),
+			}),
+		}
+	}
+}
+
+impl Period for Skewness {
+	fn period(&self) -> usize {
+		self.period
+	}
+}
+
+impl Ready for Skewness {
+	fn is_ready(&self) -> bool {
+		self.count == self.period
+	}
+}
+
+impl Next<f64> for Skewness {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		self.deque[self.index] = input;
+		self.index = if self.index + 1 < self.period {
+			self.index + 1
+		} else {
+			0
+		};
+		if self.count < self.period {
+			self.count += 1;
+		}
+
+		if self.count < 3 {
+			return 0.0;
+		}
+
+		let values = &self.deque[..self.count];
+		let n = self.count as f64;
+		let mean = values.iter().sum::<f64>() / n;
+		let (m2, m3) = values.iter().fold((0.0, 0.0), |(m2, m3), x| {
+			let d = x - mean;
+			(m2 + d * d, m3 + d * d * d)
+		});
+
+		if m2 == 0.0 {
+			return 0.0;
+		}
+
+		let sd = Float::sqrt(m2 / (n - 1.0));
+		n * m3 / ((n - 1.0) * (n - 2.0) * sd.powi(3))
+	}
+}
+
+impl<T: Close> Next<&T> for Skewness {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl Reset for Skewness {
+	fn reset(&mut self) {
+		self.index = 0;
+		self.count = 0;
+		for i in 0..self.period {
+			self.deque[i] = 0.0;
+		}
+	}
+}
+
+impl Default for Skewness {
+	fn default() -> Self {
+		Self::new(20).unwrap()
+	}
+}
+
+impl fmt::Display for Skewness {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "SKEW({})", self.period)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	test_indicator!(Skewness);
+
+	#[test]
+	fn test_new() {
+		assert!(Skewness::new(0).is_err());
+		assert!(Skewness::new(2).is_err());
+		assert!(Skewness::new(3).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let mut skew = Skewness::new(4).unwrap();
+		assert_eq!(skew.next(10.0), 0.0);
+		assert_eq!(skew.next(20.0), 0.0);
+		assert_eq!(round(skew.next(30.0)), 0.0);
+		assert_eq!(round(skew.next(20.0)), 0.0);
+		assert_eq!(round(skew.next(10.0)), 0.0);
+		assert_eq!(round(skew.next(100.0)), 1.764);
+		assert_eq!(round(skew.next(40.0)), 1.469);
+		assert_eq!(round(skew.next(50.0)), 0.764);
+	}
+
+	#[test]
+	fn test_next_same_values() {
+		let mut skew = Skewness::new(3).unwrap();
+		for _ in 0..5 {
+			assert_eq!(skew.next(4.2), 0.0);
+		}
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut skew = Skewness::new(3).unwrap();
+		skew.next(1.0);
+		skew.next(2.0);
+		assert!(!skew.is_ready());
+		skew.next(3.0);
+		assert!(skew.is_ready());
+
+		skew.reset();
+		assert!(!skew.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut skew = Skewness::new(3).unwrap();
+		skew.next(1.0);
+		skew.next(2.0);
+		assert_eq!(round(skew.next(10.0)), 1.652);
+
+		skew.reset();
+		assert_eq!(skew.next(10.0), 0.0);
+		skew.next(1.0);
+		assert_eq!(round(skew.next(2.0)), 1.652);
+	}
+
+	#[test]
+	fn test_default() {
+		Skewness::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let skew = Skewness::new(20).unwrap();
+		assert_eq!(format!("{}", skew), "SKEW(20)");
+	}
+}
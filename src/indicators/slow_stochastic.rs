@@ -1,8 +1,8 @@
-use std::fmt;
+use core::fmt;
 
 use crate::errors::Result;
 use crate::indicators::{ExponentialMovingAverage, FastStochastic};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -29,7 +29,7 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(stoch.next(55.0).round(), 77.0);
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct SlowStochastic {
 	fast_stochastic: FastStochastic,
 	ema: ExponentialMovingAverage,
@@ -60,6 +60,18 @@ impl<T: High + Low + Close> Next<&T> for SlowStochastic {
 	}
 }
 
+impl Period for SlowStochastic {
+	fn period(&self) -> usize {
+		self.fast_stochastic.period()
+	}
+}
+
+impl Ready for SlowStochastic {
+	fn is_ready(&self) -> bool {
+		self.fast_stochastic.is_ready() && self.ema.is_ready()
+	}
+}
+
 impl Reset for SlowStochastic {
 	fn reset(&mut self) {
 		self.fast_stochastic.reset();
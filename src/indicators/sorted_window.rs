@@ -0,0 +1,136 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::errors::{Result, TaError};
+use crate::{Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The last _n_ values, in sorted order, for the rank based indicators such as the
+/// [median](struct.Median.html).
+///
+/// The values are kept both in insertion order, to know which one to evict, and in a sorted
+/// copy, updated by insertion on each period in O(n). They are sorted with `f64::total_cmp`,
+/// so NaN is ranked above every other value.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SortedWindow {
+	period: usize,
+	index: usize,
+	deque: Box<[f64]>,
+	sorted: Vec<f64>,
+}
+
+impl SortedWindow {
+	pub(crate) fn new(period: usize) -> Result<Self> {
+		match period {
+			0 => Err(TaError::InvalidParameter),
+			_ => Ok(Self {
+				period,
+				index: 0,
+				deque: vec![0.0; period].into_boxed_slice(),
+				sorted: Vec::with_capacity(period),
+			}),
+		}
+	}
+
+	/// Adds a value, evicting the oldest one once the window is full.
+	pub(crate) fn push(&mut self, input: f64) {
+		if self.sorted.len() == self.period {
+			let old_val = self.deque[self.index];
+			let pos = self
+				.sorted
+				.partition_point(|v| v.total_cmp(&old_val).is_lt());
+			self.sorted.remove(pos);
+		}
+		let pos = self.sorted.partition_point(|v| v.total_cmp(&input).is_lt());
+		self.sorted.insert(pos, input);
+
+		self.deque[self.index] = input;
+		self.index = if self.index + 1 < self.period {
+			self.index + 1
+		} else {
+			0
+		};
+	}
+
+	/// Values of the window, from the lowest to the highest one.
+	pub(crate) fn sorted(&self) -> &[f64] {
+		&self.sorted
+	}
+}
+
+impl Period for SortedWindow {
+	fn period(&self) -> usize {
+		self.period
+	}
+}
+
+impl Ready for SortedWindow {
+	fn is_ready(&self) -> bool {
+		self.sorted.len() == self.period
+	}
+}
+
+impl Reset for SortedWindow {
+	fn reset(&mut self) {
+		self.index = 0;
+		self.sorted.clear();
+		for i in 0..self.period {
+			self.deque[i] = 0.0;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_new() {
+		assert!(SortedWindow::new(0).is_err());
+		assert!(SortedWindow::new(1).is_ok());
+	}
+
+	#[test]
+	fn test_push() {
+		let mut window = SortedWindow::new(3).unwrap();
+		window.push(5.0);
+		window.push(1.0);
+		assert_eq!(window.sorted(), &[1.0, 5.0]);
+		assert!(!window.is_ready());
+
+		window.push(3.0);
+		assert!(window.is_ready());
+		// 5 is evicted, not the lowest value
+		window.push(4.0);
+		assert_eq!(window.sorted(), &[1.0, 3.0, 4.0]);
+		window.push(4.0);
+		assert_eq!(window.sorted(), &[3.0, 4.0, 4.0]);
+	}
+
+	#[test]
+	fn test_push_nan() {
+		let mut window = SortedWindow::new(2).unwrap();
+		window.push(f64::NAN);
+		window.push(1.0);
+		assert_eq!(window.sorted()[0], 1.0);
+		assert!(window.sorted()[1].is_nan());
+
+		window.push(2.0);
+		assert_eq!(window.sorted(), &[1.0, 2.0]);
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut window = SortedWindow::new(2).unwrap();
+		window.push(2.0);
+		window.push(1.0);
+
+		window.reset();
+		assert_eq!(window, SortedWindow::new(2).unwrap());
+		window.push(3.0);
+		assert_eq!(window.sorted(), &[3.0]);
+	}
+}
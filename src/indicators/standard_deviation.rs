@@ -1,13 +1,17 @@
-use std::fmt;
+use core::fmt;
 
-use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
+use num_traits::Float;
+
+use crate::errors::Result;
+use crate::indicators::Variance;
+use crate::{Close, Next, Period, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Standard deviation (SD).
 ///
-/// Returns the standard deviation of the last n values.
+/// Returns the standard deviation of the last n values, the square root of the
+/// population [variance](struct.Variance.html).
 ///
 /// # Formula
 ///
@@ -40,39 +44,32 @@ use serde::{Deserialize, Serialize};
 ///
 #[doc(alias = "SD")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct StandardDeviation {
-	period: usize,
-	index: usize,
-	count: usize,
-	m: f64,
-	m2: f64,
-	deque: Box<[f64]>,
+	variance: Variance,
 }
 
 impl StandardDeviation {
 	pub fn new(period: usize) -> Result<Self> {
-		match period {
-			0 => Err(TaError::InvalidParameter),
-			_ => Ok(Self {
-				period,
-				index: 0,
-				count: 0,
-				m: 0.0,
-				m2: 0.0,
-				deque: vec![0.0; period].into_boxed_slice(),
-			}),
-		}
+		Ok(Self {
+			variance: Variance::new(period, 0)?,
+		})
 	}
 
 	pub(super) fn mean(&self) -> f64 {
-		self.m
+		self.variance.mean()
 	}
 }
 
 impl Period for StandardDeviation {
 	fn period(&self) -> usize {
-		self.period
+		self.variance.period()
+	}
+}
+
+impl Ready for StandardDeviation {
+	fn is_ready(&self) -> bool {
+		self.variance.is_ready()
 	}
 }
 
@@ -80,33 +77,7 @@ impl Next<f64> for StandardDeviation {
 	type Output = f64;
 
 	fn next(&mut self, input: f64) -> Self::Output {
-		let old_val = self.deque[self.index];
-		self.deque[self.index] = input;
-
-		self.index = if self.index + 1 < self.period {
-			self.index + 1
-		} else {
-			0
-		};
-
-		if self.count < self.period {
-			self.count += 1;
-			let delta = input - self.m;
-			self.m += delta / self.count as f64;
-			let delta2 = input - self.m;
-			self.m2 += delta * delta2;
-		} else {
-			let delta = input - old_val;
-			let old_m = self.m;
-			self.m += delta / self.period as f64;
-			let delta2 = input - self.m + old_val - old_m;
-			self.m2 += delta * delta2;
-		}
-		if self.m2 < 0.0 {
-			self.m2 = 0.0;
-		}
-
-		(self.m2 / self.count as f64).sqrt()
+		Float::sqrt(self.variance.next(input))
 	}
 }
 
@@ -120,13 +91,7 @@ impl<T: Close> Next<&T> for StandardDeviation {
 
 impl Reset for StandardDeviation {
 	fn reset(&mut self) {
-		self.index = 0;
-		self.count = 0;
-		self.m = 0.0;
-		self.m2 = 0.0;
-		for i in 0..self.period {
-			self.deque[i] = 0.0;
-		}
+		self.variance.reset();
 	}
 }
 
@@ -138,7 +103,7 @@ impl Default for StandardDeviation {
 
 impl fmt::Display for StandardDeviation {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		write!(f, "SD({})", self.period)
+		write!(f, "SD({})", self.variance.period())
 	}
 }
 
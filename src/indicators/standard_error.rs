@@ -0,0 +1,204 @@
+use core::fmt;
+
+use num_traits::Float;
+
+use crate::errors::Result;
+use crate::indicators::LinearRegression;
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Standard error of the rolling linear regression (STDERR).
+///
+/// Measures how far the last _n_ inputs are from the line fitted by the
+/// [linear regression](struct.LinearRegression.html). Linear regression channels are drawn
+/// at _forecast ± multiplier × SE_ around it.
+///
+/// # Formula
+///
+/// SE = √(Σ(y - a - b × t)² / (n - 2))
+///
+/// Where:
+///
+/// * _y_ - input value at the time index _t_ of the window
+/// * _a_, _b_ - intercept and slope of the linear regression of the last _n_ inputs
+///
+/// A line always fits 2 points, so `f64::NAN` is returned until 3 inputs have been consumed.
+/// If the inputs are on a line, e.g. they have not moved, 0 is returned.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 1). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::StandardError;
+/// use ta::Next;
+///
+/// let mut se = StandardError::new(3).unwrap();
+/// assert!(se.next(1.0).is_nan());
+/// assert!(se.next(3.0).is_nan());
+/// assert_eq!(se.next(5.0), 0.0);
+/// assert_eq!(se.next(4.0), 1.5_f64.sqrt());
+/// ```
+#[doc(alias = "STDERR")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct StandardError {
+	linear_regression: LinearRegression,
+}
+
+impl StandardError {
+	pub fn new(period: usize) -> Result<Self> {
+		Ok(Self {
+			linear_regression: LinearRegression::new(period)?,
+		})
+	}
+}
+
+impl Period for StandardError {
+	fn period(&self) -> usize {
+		self.linear_regression.period()
+	}
+}
+
+impl Ready for StandardError {
+	fn is_ready(&self) -> bool {
+		self.linear_regression.is_ready()
+	}
+}
+
+impl Next<f64> for StandardError {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		let regression = self.linear_regression.next(input);
+		let count = self.linear_regression.count();
+		if count <= 2 {
+			return f64::NAN;
+		}
+
+		let ss_res = self
+			.linear_regression
+			.residual_sum_of_squares(regression.slope, regression.intercept);
+		if ss_res == 0.0 {
+			// A perfect fit
+			return 0.0;
+		}
+
+		Float::sqrt(ss_res / (count - 2) as f64)
+	}
+}
+
+impl<T: Close> Next<&T> for StandardError {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl Reset for StandardError {
+	fn reset(&mut self) {
+		self.linear_regression.reset();
+	}
+}
+
+impl Default for StandardError {
+	fn default() -> Self {
+		Self::new(14).unwrap()
+	}
+}
+
+impl fmt::Display for StandardError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "STDERR({})", self.linear_regression.period())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	// test_indicator! compares the first outputs, which are NaN here
+
+	#[test]
+	fn test_new() {
+		assert!(StandardError::new(0).is_err());
+		assert!(StandardError::new(1).is_err());
+		assert!(StandardError::new(2).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let mut se = StandardError::new(3).unwrap();
+		assert!(se.next(1.0).is_nan());
+		assert!(se.next(3.0).is_nan());
+		assert_eq!(round(se.next(2.0)), 1.225);
+		assert_eq!(round(se.next(5.0)), 1.633);
+		assert_eq!(round(se.next(5.0)), 1.225);
+	}
+
+	#[test]
+	fn test_next_period_two() {
+		let mut se = StandardError::new(2).unwrap();
+		for x in &[1.0, 4.0, 2.0] {
+			assert!(se.next(*x).is_nan());
+		}
+	}
+
+	#[test]
+	fn test_next_flat() {
+		let mut se = StandardError::new(4).unwrap();
+		se.next(7.0);
+		se.next(7.0);
+		for _ in 0..5 {
+			assert_eq!(se.next(7.0), 0.0);
+		}
+	}
+
+	#[test]
+	fn test_next_linear() {
+		let mut se = StandardError::new(5).unwrap();
+		se.next(6.0);
+		se.next(4.0);
+		for i in 2..20 {
+			assert!(se.next(6.0 - 2.0 * i as f64).abs() < 1e-9);
+		}
+	}
+
+	#[test]
+	fn test_next_with_bars() {
+		let mut se = StandardError::new(3).unwrap();
+		se.next(&Bar::new().close(3));
+		se.next(&Bar::new().close(5));
+		assert_eq!(round(se.next(&Bar::new().close(4))), 1.225);
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut se = StandardError::new(3).unwrap();
+		se.next(1.0);
+		se.next(3.0);
+		se.next(2.0);
+
+		se.reset();
+		assert_eq!(se, StandardError::new(3).unwrap());
+		assert!(se.next(2.0).is_nan());
+		assert!(se.next(4.0).is_nan());
+		assert_eq!(se.next(6.0), 0.0);
+	}
+
+	#[test]
+	fn test_default() {
+		assert_eq!(StandardError::default().period(), 14);
+	}
+
+	#[test]
+	fn test_display() {
+		let se = StandardError::new(10).unwrap();
+		assert_eq!(format!("{}", se), "STDERR(10)");
+	}
+}
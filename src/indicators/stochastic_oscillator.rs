@@ -0,0 +1,290 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{Maximum, Minimum, SimpleMovingAverage as Sma};
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Stochastic oscillator, with a smoothed _%K_ and a _%D_ line.
+///
+/// The stochastic oscillator compares the close price to the range of the prices over
+/// a certain period of time. Its fast, slow and full variants only differ by their smoothing
+/// parameters:
+///
+/// * fast stochastic - `new(n, 1, 3)`: _%K_ is the raw _%K_
+/// * slow stochastic - `new(n, 3, 3)`: _%K_ is the 3-period SMA of the raw _%K_
+/// * full stochastic - `new(n, k, d)`: any smoothing
+///
+/// # Formula
+///
+/// raw %K = (C<sub>t</sub> - L<sub>n</sub>) / (H<sub>n</sub> - L<sub>n</sub>) * 100
+///
+/// %K = SMA(raw %K, k)
+///
+/// %D = SMA(%K, d)
+///
+/// Where:
+///
+/// * C<sub>t</sub> - close price of the current period
+/// * L<sub>n</sub> - lowest price for the last _n_ periods
+/// * H<sub>n</sub> - highest price for the last _n_ periods
+///
+/// If the highest and lowest prices are equal, the raw %K is taken as 50.
+///
+/// # Parameters
+///
+/// * _k_period_ - number of periods of the raw %K (integer greater than 0). Default is 14.
+/// * _k_smooth_ - period of the %K smoothing (integer greater than 0). Default is 3.
+/// * _d_smooth_ - period of the %D smoothing (integer greater than 0). Default is 3.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::StochasticOscillator;
+/// use ta::Next;
+///
+/// let mut stoch = StochasticOscillator::new(3, 2, 2).unwrap();
+/// assert_eq!(stoch.next(0.0).k, 50.0);
+/// assert_eq!(stoch.next(200.0).k, 75.0);
+/// assert_eq!(stoch.next(100.0).d, 75.0);
+///
+/// let output = stoch.next(120.0);
+/// assert_eq!((output.k, output.d), (35.0, 55.0));
+/// ```
+///
+/// # Links
+///
+/// * [Stochastic oscillator, Wikipedia](https://en.wikipedia.org/wiki/Stochastic_oscillator)
+/// * [Stochastic oscillator, stockcharts](https://chartschool.stockcharts.com/table-of-contents/technical-indicators-and-overlays/technical-indicators/stochastic-oscillator-fast-slow-and-full)
+#[doc(alias = "STOCH")]
+#[doc(alias = "KD")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct StochasticOscillator {
+	count: usize,
+	minimum: Minimum,
+	maximum: Maximum,
+	k_sma: Sma,
+	d_sma: Sma,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StochasticOutput {
+	pub k: f64,
+	pub d: f64,
+}
+
+impl From<StochasticOutput> for (f64, f64) {
+	fn from(so: StochasticOutput) -> Self {
+		(so.k, so.d)
+	}
+}
+
+impl StochasticOscillator {
+	pub fn new(k_period: usize, k_smooth: usize, d_smooth: usize) -> Result<Self> {
+		Ok(Self {
+			count: 0,
+			minimum: Minimum::new(k_period)?,
+			maximum: Maximum::new(k_period)?,
+			k_sma: Sma::new(k_smooth)?,
+			d_sma: Sma::new(d_smooth)?,
+		})
+	}
+
+	/// Fast stochastic, the %K line is not smoothed.
+	pub fn new_fast(k_period: usize) -> Result<Self> {
+		Self::new(k_period, 1, 3)
+	}
+
+	/// Slow stochastic, the %K line is smoothed over 3 periods.
+	pub fn new_slow(k_period: usize) -> Result<Self> {
+		Self::new(k_period, 3, 3)
+	}
+
+	fn ready_count(&self) -> usize {
+		self.maximum.period() + self.k_sma.period() + self.d_sma.period() - 2
+	}
+
+	fn next_prices(&mut self, high: f64, low: f64, close: f64) -> StochasticOutput {
+		if self.count < self.ready_count() {
+			self.count += 1;
+		}
+
+		let highest = self.maximum.next(high);
+		let lowest = self.minimum.next(low);
+		let raw_k = if highest == lowest {
+			// To avoid division by zero, return the middle of the range
+			50.0
+		} else {
+			(close - lowest) / (highest - lowest) * 100.0
+		};
+
+		let k = self.k_sma.next(raw_k);
+		let d = self.d_sma.next(k);
+
+		StochasticOutput { k, d }
+	}
+}
+
+impl Period for StochasticOscillator {
+	fn period(&self) -> usize {
+		self.maximum.period()
+	}
+}
+
+impl Ready for StochasticOscillator {
+	fn is_ready(&self) -> bool {
+		self.count == self.ready_count()
+	}
+}
+
+impl Next<f64> for StochasticOscillator {
+	type Output = StochasticOutput;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		self.next_prices(input, input, input)
+	}
+}
+
+impl<T: High + Low + Close> Next<&T> for StochasticOscillator {
+	type Output = StochasticOutput;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next_prices(input.high(), input.low(), input.close())
+	}
+}
+
+impl Reset for StochasticOscillator {
+	fn reset(&mut self) {
+		self.count = 0;
+		self.minimum.reset();
+		self.maximum.reset();
+		self.k_sma.reset();
+		self.d_sma.reset();
+	}
+}
+
+impl Default for StochasticOscillator {
+	fn default() -> Self {
+		Self::new(14, 3, 3).unwrap()
+	}
+}
+
+impl fmt::Display for StochasticOscillator {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"STOCH({}, {}, {})",
+			self.maximum.period(),
+			self.k_sma.period(),
+			self.d_sma.period()
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::indicators::FastStochastic;
+	use crate::test_helper::*;
+
+	test_indicator!(StochasticOscillator);
+
+	#[test]
+	fn test_new() {
+		assert!(StochasticOscillator::new(0, 3, 3).is_err());
+		assert!(StochasticOscillator::new(14, 0, 3).is_err());
+		assert!(StochasticOscillator::new(14, 3, 0).is_err());
+		assert!(StochasticOscillator::new(1, 1, 1).is_ok());
+		assert!(StochasticOscillator::new_fast(0).is_err());
+		assert!(StochasticOscillator::new_slow(0).is_err());
+	}
+
+	#[test]
+	fn test_next_with_f64() {
+		let mut stoch = StochasticOscillator::new_fast(3).unwrap();
+		let mut fast = FastStochastic::new(3).unwrap();
+
+		let test_data = vec![
+			// input, d
+			(0.0, 50.0),
+			(200.0, 75.0),
+			(100.0, 66.667),
+			(120.0, 56.667),
+			(115.0, 48.333),
+		];
+
+		for (input, d) in test_data {
+			let output = stoch.next(input);
+			assert_eq!(output.k, fast.next(input));
+			assert_eq!(round(output.d), d);
+		}
+	}
+
+	#[test]
+	fn test_next_with_bars() {
+		let test_data = vec![
+			// high, low, close, k, d
+			(20.0, 20.0, 20.0, 50.0, 50.0),  // raw %K = 50, min = 20, max = 20
+			(30.0, 10.0, 25.0, 62.5, 56.25), // raw %K = 75, min = 10, max = 30
+			(40.0, 20.0, 16.0, 47.5, 55.0),  // raw %K = 20, min = 10, max = 40
+			(35.0, 15.0, 19.0, 25.0, 36.25), // raw %K = 30, min = 10, max = 40
+			(30.0, 20.0, 25.0, 35.0, 30.0),  // raw %K = 40, min = 15, max = 40
+			(35.0, 25.0, 30.0, 57.5, 46.25), // raw %K = 75, min = 15, max = 35
+		];
+
+		let mut stoch = StochasticOscillator::new(3, 2, 2).unwrap();
+
+		for (high, low, close, k, d) in test_data {
+			let bar = Bar::new().high(high).low(low).close(close);
+			assert_eq!(stoch.next(&bar), StochasticOutput { k, d });
+		}
+	}
+
+	#[test]
+	fn test_next_flat() {
+		let mut stoch = StochasticOscillator::new_slow(3).unwrap();
+		for _ in 0..5 {
+			let output = stoch.next(&Bar::new().high(10).low(10).close(10));
+			assert_eq!((output.k, output.d), (50.0, 50.0));
+		}
+	}
+
+	#[test]
+	fn test_is_ready() {
+		// 3 + 2 + 2 - 2 inputs are needed for a full %D window
+		let mut stoch = StochasticOscillator::new(3, 2, 2).unwrap();
+		for _ in 0..4 {
+			stoch.next(1.0);
+			assert!(!stoch.is_ready());
+		}
+		stoch.next(1.0);
+		assert!(stoch.is_ready());
+
+		stoch.reset();
+		assert!(!stoch.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut stoch = StochasticOscillator::new(3, 1, 2).unwrap();
+		stoch.next(10.0);
+		assert_eq!(stoch.next(20.0).k, 100.0);
+
+		stoch.reset();
+		assert_eq!(stoch.next(20.0), StochasticOutput { k: 50.0, d: 50.0 });
+	}
+
+	#[test]
+	fn test_default() {
+		let stoch = StochasticOscillator::default();
+		assert_eq!(stoch.period(), 14);
+	}
+
+	#[test]
+	fn test_display() {
+		let stoch = StochasticOscillator::new(14, 3, 3).unwrap();
+		assert_eq!(format!("{}", stoch), "STOCH(14, 3, 3)");
+	}
+}
@@ -0,0 +1,269 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{
+	Maximum, Minimum, RelativeStrengthIndex as Rsi, SimpleMovingAverage as Sma,
+};
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Stochastic RSI (StochRSI).
+///
+/// The stochastic oscillator applied to the [RSI](struct.RelativeStrengthIndex.html) instead
+/// of the price. It shows where the RSI is relative to its range, and is smoothed into
+/// a _%K_ and a _%D_ line. Both lines range from 0 to 1.
+///
+/// # Formula
+///
+/// StochRSI = (RSI - min(RSI, m)) / (max(RSI, m) - min(RSI, m))
+///
+/// %K = SMA(StochRSI, k)
+///
+/// %D = SMA(%K, d)
+///
+/// Where:
+///
+/// * _RSI_ - RSI of the input value with period _n_
+/// * _m_ - stochastic period
+///
+/// If the RSI has not moved within the last _m_ periods, StochRSI is taken as 0.5.
+///
+/// # Parameters
+///
+/// * _rsi_period_ - period of the RSI (integer greater than 0). Default is 14.
+/// * _stochastic_period_ - period of the stochastic (integer greater than 0). Default is 14.
+/// * _k_period_ - period of the %K smoothing (integer greater than 0). Default is 3.
+/// * _d_period_ - period of the %D smoothing (integer greater than 0). Default is 3.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::StochasticRsi;
+/// use ta::Next;
+///
+/// let mut stoch_rsi = StochasticRsi::new(3, 3, 1, 1).unwrap();
+/// assert_eq!(stoch_rsi.next(10.0).k, 0.5);
+/// assert_eq!(stoch_rsi.next(10.5).k, 1.0);
+/// assert_eq!(stoch_rsi.next(10.0).k, 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Stochastic RSI, investopedia](https://www.investopedia.com/terms/s/stochrsi.asp)
+#[doc(alias = "StochRSI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct StochasticRsi {
+	count: usize,
+	rsi: Rsi,
+	minimum: Minimum,
+	maximum: Maximum,
+	k_sma: Sma,
+	d_sma: Sma,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StochasticRsiOutput {
+	pub k: f64,
+	pub d: f64,
+}
+
+impl From<StochasticRsiOutput> for (f64, f64) {
+	fn from(sr: StochasticRsiOutput) -> Self {
+		(sr.k, sr.d)
+	}
+}
+
+impl StochasticRsi {
+	pub fn new(
+		rsi_period: usize,
+		stochastic_period: usize,
+		k_period: usize,
+		d_period: usize,
+	) -> Result<Self> {
+		Ok(Self {
+			count: 0,
+			rsi: Rsi::new(rsi_period)?,
+			minimum: Minimum::new(stochastic_period)?,
+			maximum: Maximum::new(stochastic_period)?,
+			k_sma: Sma::new(k_period)?,
+			d_sma: Sma::new(d_period)?,
+		})
+	}
+
+	fn ready_count(&self) -> usize {
+		self.rsi.period() + self.maximum.period() + self.k_sma.period() + self.d_sma.period() - 3
+	}
+}
+
+impl Period for StochasticRsi {
+	fn period(&self) -> usize {
+		self.rsi.period()
+	}
+}
+
+impl Ready for StochasticRsi {
+	fn is_ready(&self) -> bool {
+		self.count == self.ready_count()
+	}
+}
+
+impl Next<f64> for StochasticRsi {
+	type Output = StochasticRsiOutput;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		if self.count < self.ready_count() {
+			self.count += 1;
+		}
+
+		let rsi = self.rsi.next(input);
+		let min = self.minimum.next(rsi);
+		let max = self.maximum.next(rsi);
+		let stoch_rsi = if max == min {
+			// The RSI has not moved, return the middle of the range
+			0.5
+		} else {
+			(rsi - min) / (max - min)
+		};
+
+		let k = self.k_sma.next(stoch_rsi);
+		let d = self.d_sma.next(k);
+
+		StochasticRsiOutput { k, d }
+	}
+}
+
+impl<T: Close> Next<&T> for StochasticRsi {
+	type Output = StochasticRsiOutput;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl Reset for StochasticRsi {
+	fn reset(&mut self) {
+		self.count = 0;
+		self.rsi.reset();
+		self.minimum.reset();
+		self.maximum.reset();
+		self.k_sma.reset();
+		self.d_sma.reset();
+	}
+}
+
+impl Default for StochasticRsi {
+	fn default() -> Self {
+		Self::new(14, 14, 3, 3).unwrap()
+	}
+}
+
+impl fmt::Display for StochasticRsi {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"STOCH_RSI({}, {}, {}, {})",
+			self.rsi.period(),
+			self.maximum.period(),
+			self.k_sma.period(),
+			self.d_sma.period()
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	test_indicator!(StochasticRsi);
+
+	#[test]
+	fn test_new() {
+		assert!(StochasticRsi::new(0, 14, 3, 3).is_err());
+		assert!(StochasticRsi::new(14, 0, 3, 3).is_err());
+		assert!(StochasticRsi::new(14, 14, 0, 3).is_err());
+		assert!(StochasticRsi::new(14, 14, 3, 0).is_err());
+		assert!(StochasticRsi::new(14, 14, 3, 3).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let test_data = vec![
+			// input, k, d
+			(10.0, 0.5, 0.5),
+			(10.5, 0.75, 0.625),
+			(10.0, 0.5, 0.625),
+			(11.0, 0.47, 0.485),
+			(11.5, 0.97, 0.72),
+			(11.0, 0.5, 0.735),
+			(12.0, 0.4, 0.45),
+			(11.5, 0.401, 0.401),
+		];
+
+		let mut stoch_rsi = StochasticRsi::new(3, 3, 2, 2).unwrap();
+
+		for (input, k, d) in test_data {
+			let out = stoch_rsi.next(input);
+			assert_eq!(round(out.k), k);
+			assert_eq!(round(out.d), d);
+		}
+
+		let mut stoch_rsi = StochasticRsi::new(3, 3, 2, 2).unwrap();
+		let bar1 = Bar::new().close(10);
+		let bar2 = Bar::new().close(10.5);
+		assert_eq!(
+			stoch_rsi.next(&bar1),
+			StochasticRsiOutput { k: 0.5, d: 0.5 }
+		);
+		let (k, d) = stoch_rsi.next(&bar2).into();
+		assert_eq!((k, d), (0.75, 0.625));
+	}
+
+	#[test]
+	fn test_next_flat() {
+		let mut stoch_rsi = StochasticRsi::new(3, 3, 2, 2).unwrap();
+		for _ in 0..10 {
+			let out = stoch_rsi.next(5.0);
+			assert_eq!(out.k, 0.5);
+			assert_eq!(out.d, 0.5);
+		}
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut stoch_rsi = StochasticRsi::new(3, 3, 2, 2).unwrap();
+		for _ in 0..6 {
+			stoch_rsi.next(1.0);
+			assert!(!stoch_rsi.is_ready());
+		}
+		stoch_rsi.next(1.0);
+		assert!(stoch_rsi.is_ready());
+
+		stoch_rsi.reset();
+		assert!(!stoch_rsi.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut stoch_rsi = StochasticRsi::new(3, 3, 2, 2).unwrap();
+		stoch_rsi.next(10.0);
+		assert_eq!(stoch_rsi.next(10.5).k, 0.75);
+
+		stoch_rsi.reset();
+		assert_eq!(stoch_rsi.next(10.0).k, 0.5);
+		assert_eq!(stoch_rsi.next(10.5).k, 0.75);
+	}
+
+	#[test]
+	fn test_default() {
+		StochasticRsi::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let indicator = StochasticRsi::new(14, 14, 3, 3).unwrap();
+		assert_eq!(format!("{}", indicator), "STOCH_RSI(14, 14, 3, 3)");
+	}
+}
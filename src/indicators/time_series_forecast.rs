@@ -0,0 +1,164 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::LinearRegression;
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Time series forecast (TSF).
+///
+/// The [linear regression](struct.LinearRegression.html) of the last _n_ inputs projected
+/// one period forward. Unlike a moving average, it does not lag behind a trending series.
+///
+/// # Formula
+///
+/// TSF = a + b × n
+///
+/// Where:
+///
+/// * _a_, _b_ - intercept and slope of the linear regression of the last _n_ inputs, whose
+///   time index goes from 0 to _n - 1_
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 1). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::TimeSeriesForecast;
+/// use ta::Next;
+///
+/// let mut tsf = TimeSeriesForecast::new(3).unwrap();
+/// assert_eq!(tsf.next(1.0), 1.0);
+/// assert_eq!(tsf.next(3.0), 5.0);
+/// assert_eq!(tsf.next(2.0), 3.0);
+/// ```
+#[doc(alias = "TSF")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeSeriesForecast {
+	linear_regression: LinearRegression,
+}
+
+impl TimeSeriesForecast {
+	pub fn new(period: usize) -> Result<Self> {
+		Ok(Self {
+			linear_regression: LinearRegression::new(period)?,
+		})
+	}
+}
+
+impl Period for TimeSeriesForecast {
+	fn period(&self) -> usize {
+		self.linear_regression.period()
+	}
+}
+
+impl Ready for TimeSeriesForecast {
+	fn is_ready(&self) -> bool {
+		self.linear_regression.is_ready()
+	}
+}
+
+impl Next<f64> for TimeSeriesForecast {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		// the forecast of the regression is the fitted value at the current bar
+		let regression = self.linear_regression.next(input);
+		regression.forecast + regression.slope
+	}
+}
+
+impl<T: Close> Next<&T> for TimeSeriesForecast {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl Reset for TimeSeriesForecast {
+	fn reset(&mut self) {
+		self.linear_regression.reset();
+	}
+}
+
+impl Default for TimeSeriesForecast {
+	fn default() -> Self {
+		Self::new(14).unwrap()
+	}
+}
+
+impl fmt::Display for TimeSeriesForecast {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "TSF({})", self.linear_regression.period())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	test_indicator!(TimeSeriesForecast);
+
+	#[test]
+	fn test_new() {
+		assert!(TimeSeriesForecast::new(0).is_err());
+		assert!(TimeSeriesForecast::new(1).is_err());
+		assert!(TimeSeriesForecast::new(2).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let mut tsf = TimeSeriesForecast::new(3).unwrap();
+		assert_eq!(tsf.next(1.0), 1.0);
+		assert_eq!(tsf.next(3.0), 5.0);
+		assert_eq!(tsf.next(2.0), 3.0);
+		assert_eq!(round(tsf.next(5.0)), 5.333);
+		assert_eq!(tsf.next(5.0), 7.0);
+	}
+
+	#[test]
+	fn test_next_linear() {
+		// a linear series is forecast exactly
+		let mut tsf = TimeSeriesForecast::new(4).unwrap();
+		let price = |i: usize| 10.0 + 0.3 * i as f64;
+		tsf.next(price(0));
+		for i in 1..30 {
+			assert!((tsf.next(price(i)) - price(i + 1)).abs() < 1e-9);
+		}
+	}
+
+	#[test]
+	fn test_next_with_bars() {
+		let mut tsf = TimeSeriesForecast::new(2).unwrap();
+		tsf.next(&Bar::new().close(4));
+		assert_eq!(tsf.next(&Bar::new().close(7)), 10.0);
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut tsf = TimeSeriesForecast::new(3).unwrap();
+		tsf.next(1.0);
+		tsf.next(3.0);
+
+		tsf.reset();
+		assert_eq!(tsf.next(5.0), 5.0);
+		assert_eq!(tsf.next(4.0), 3.0);
+	}
+
+	#[test]
+	fn test_default() {
+		assert_eq!(TimeSeriesForecast::default().period(), 14);
+	}
+
+	#[test]
+	fn test_display() {
+		let tsf = TimeSeriesForecast::new(10).unwrap();
+		assert_eq!(format!("{}", tsf), "TSF(10)");
+	}
+}
@@ -0,0 +1,340 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Triple exponential average (TRIX).
+///
+/// The percentage rate of change of a triple smoothed EMA. Signals are generated when
+/// TRIX crosses zero or its [signal line](struct.TripleExponentialAverageSignal.html).
+///
+/// # Formula
+///
+/// TRIX<sub>t</sub> = (EMA3<sub>t</sub> - EMA3<sub>t-1</sub>) / EMA3<sub>t-1</sub> × 100
+///
+/// Where:
+///
+/// * _EMA3_ = EMA(EMA(EMA(p, n), n), n)
+/// * _p_ - input value
+/// * _n_ - period
+///
+/// The first TRIX value is 0, as well as any value for which EMA3<sub>t-1</sub> is 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 15.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::TripleExponentialAverage;
+/// use ta::Next;
+///
+/// let mut trix = TripleExponentialAverage::new(3).unwrap();
+/// assert_eq!(trix.next(2.0), 0.0);
+/// assert_eq!(trix.next(5.0), 18.75);
+/// ```
+///
+/// # Links
+///
+/// * [Trix, Wikipedia](https://en.wikipedia.org/wiki/Trix_(technical_analysis))
+#[doc(alias = "TRIX")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TripleExponentialAverage {
+	period: usize,
+	count: usize,
+	ema1: Ema,
+	ema2: Ema,
+	ema3: Ema,
+	prev_ema3: f64,
+}
+
+impl TripleExponentialAverage {
+	pub fn new(period: usize) -> Result<Self> {
+		Ok(Self {
+			period,
+			count: 0,
+			ema1: Ema::new(period)?,
+			ema2: Ema::new(period)?,
+			ema3: Ema::new(period)?,
+			prev_ema3: 0.0,
+		})
+	}
+}
+
+impl Period for TripleExponentialAverage {
+	fn period(&self) -> usize {
+		self.period
+	}
+}
+
+impl Ready for TripleExponentialAverage {
+	fn is_ready(&self) -> bool {
+		self.count == 3 * (self.period - 1) + 2
+	}
+}
+
+impl Next<f64> for TripleExponentialAverage {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		if self.count < 3 * (self.period - 1) + 2 {
+			self.count += 1;
+		}
+
+		let ema1 = self.ema1.next(input);
+		let ema2 = self.ema2.next(ema1);
+		let ema3 = self.ema3.next(ema2);
+
+		let trix = if self.prev_ema3 == 0.0 {
+			0.0
+		} else {
+			(ema3 - self.prev_ema3) / self.prev_ema3 * 100.0
+		};
+		self.prev_ema3 = ema3;
+		trix
+	}
+}
+
+impl<T: Close> Next<&T> for TripleExponentialAverage {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl Reset for TripleExponentialAverage {
+	fn reset(&mut self) {
+		self.count = 0;
+		self.ema1.reset();
+		self.ema2.reset();
+		self.ema3.reset();
+		self.prev_ema3 = 0.0;
+	}
+}
+
+impl Default for TripleExponentialAverage {
+	fn default() -> Self {
+		Self::new(15).unwrap()
+	}
+}
+
+impl fmt::Display for TripleExponentialAverage {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "TRIX({})", self.period)
+	}
+}
+
+/// Triple exponential average (TRIX) with a signal line.
+///
+/// Returns the [TRIX](struct.TripleExponentialAverage.html) together with its signal line,
+/// an EMA of the TRIX.
+///
+/// # Parameters
+///
+/// * _period_ - period of the TRIX (integer greater than 0). Default is 15.
+/// * _signal_period_ - period of the signal EMA (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::TripleExponentialAverageSignal;
+/// use ta::Next;
+///
+/// let mut trix = TripleExponentialAverageSignal::new(3, 2).unwrap();
+/// trix.next(2.0);
+///
+/// let out = trix.next(5.0);
+/// assert_eq!(out.trix, 18.75);
+/// assert_eq!(out.signal, 12.5);
+/// ```
+#[doc(alias = "TRIX")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TripleExponentialAverageSignal {
+	trix: TripleExponentialAverage,
+	signal_ema: Ema,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TripleExponentialAverageSignalOutput {
+	pub trix: f64,
+	pub signal: f64,
+}
+
+impl From<TripleExponentialAverageSignalOutput> for (f64, f64) {
+	fn from(to: TripleExponentialAverageSignalOutput) -> Self {
+		(to.trix, to.signal)
+	}
+}
+
+impl TripleExponentialAverageSignal {
+	pub fn new(period: usize, signal_period: usize) -> Result<Self> {
+		Ok(Self {
+			trix: TripleExponentialAverage::new(period)?,
+			signal_ema: Ema::new(signal_period)?,
+		})
+	}
+}
+
+impl Period for TripleExponentialAverageSignal {
+	fn period(&self) -> usize {
+		self.trix.period()
+	}
+}
+
+impl Ready for TripleExponentialAverageSignal {
+	fn is_ready(&self) -> bool {
+		self.trix.is_ready() && self.signal_ema.is_ready()
+	}
+}
+
+impl Next<f64> for TripleExponentialAverageSignal {
+	type Output = TripleExponentialAverageSignalOutput;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		let trix = self.trix.next(input);
+		let signal = self.signal_ema.next(trix);
+
+		Self::Output { trix, signal }
+	}
+}
+
+impl<T: Close> Next<&T> for TripleExponentialAverageSignal {
+	type Output = TripleExponentialAverageSignalOutput;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl Reset for TripleExponentialAverageSignal {
+	fn reset(&mut self) {
+		self.trix.reset();
+		self.signal_ema.reset();
+	}
+}
+
+impl Default for TripleExponentialAverageSignal {
+	fn default() -> Self {
+		Self::new(15, 9).unwrap()
+	}
+}
+
+impl fmt::Display for TripleExponentialAverageSignal {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"TRIX({}, {})",
+			self.trix.period(),
+			self.signal_ema.period()
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	type Trix = TripleExponentialAverage;
+	type TrixSignal = TripleExponentialAverageSignal;
+
+	test_indicator!(Trix);
+
+	mod signal {
+		use super::*;
+
+		test_indicator!(TrixSignal);
+	}
+
+	#[test]
+	fn test_new() {
+		assert!(Trix::new(0).is_err());
+		assert!(Trix::new(1).is_ok());
+
+		assert!(TrixSignal::new(0, 9).is_err());
+		assert!(TrixSignal::new(15, 0).is_err());
+		assert!(TrixSignal::new(15, 9).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let mut trix = Trix::new(3).unwrap();
+
+		assert_eq!(trix.next(2.0), 0.0);
+		assert_eq!(trix.next(5.0), 18.75);
+		assert_eq!(round(trix.next(1.0)), 2.632);
+		assert_eq!(round(trix.next(6.25)), 19.231);
+
+		let mut trix = Trix::new(3).unwrap();
+		let bar1 = Bar::new().close(2);
+		let bar2 = Bar::new().close(5);
+		assert_eq!(trix.next(&bar1), 0.0);
+		assert_eq!(trix.next(&bar2), 18.75);
+	}
+
+	#[test]
+	fn test_next_zero_guard() {
+		let mut trix = Trix::new(3).unwrap();
+		assert_eq!(trix.next(0.0), 0.0);
+		assert_eq!(trix.next(4.0), 0.0);
+	}
+
+	#[test]
+	fn test_next_signal() {
+		let mut trix = TrixSignal::new(3, 2).unwrap();
+
+		let values =
+			|out: TripleExponentialAverageSignalOutput| (round(out.trix), round(out.signal));
+		assert_eq!(values(trix.next(2.0)), (0.0, 0.0));
+		assert_eq!(values(trix.next(5.0)), (18.75, 12.5));
+		assert_eq!(values(trix.next(1.0)), (2.632, 5.921));
+		assert_eq!(values(trix.next(6.25)), (19.231, 14.794));
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut trix = Trix::new(2).unwrap();
+		for _ in 0..4 {
+			trix.next(1.0);
+			assert!(!trix.is_ready());
+		}
+		trix.next(1.0);
+		assert!(trix.is_ready());
+
+		trix.reset();
+		assert!(!trix.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut trix = TrixSignal::new(3, 2).unwrap();
+		trix.next(2.0);
+		assert_eq!(trix.next(5.0).trix, 18.75);
+
+		trix.reset();
+		assert_eq!(trix.next(2.0).trix, 0.0);
+		assert_eq!(trix.next(5.0).trix, 18.75);
+	}
+
+	#[test]
+	fn test_default() {
+		Trix::default();
+		assert_eq!(TrixSignal::default().period(), 15);
+	}
+
+	#[test]
+	fn test_display() {
+		let indicator = Trix::new(15).unwrap();
+		assert_eq!(format!("{}", indicator), "TRIX(15)");
+
+		let indicator = TrixSignal::new(15, 9).unwrap();
+		assert_eq!(format!("{}", indicator), "TRIX(15, 9)");
+	}
+}
@@ -0,0 +1,221 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Triple exponential moving average (TEMA).
+///
+/// Reduces the lag of an EMA even further than the [DEMA](struct.DoubleExponentialMovingAverage.html)
+/// by combining the EMA with the EMA of the EMA and the EMA of that.
+///
+/// # Formula
+///
+/// TEMA = 3 × EMA1 - 3 × EMA2 + EMA3
+///
+/// Where:
+///
+/// * _EMA1_ = EMA(p, n)
+/// * _EMA2_ = EMA(EMA1, n)
+/// * _EMA3_ = EMA(EMA2, n)
+/// * _p_ - input value
+/// * _n_ - period
+///
+/// The TEMA is ready once _3 × (n - 1) + 1_ values have been consumed.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::TripleExponentialMovingAverage;
+/// use ta::Next;
+///
+/// let mut tema = TripleExponentialMovingAverage::new(3).unwrap();
+/// assert_eq!(tema.next(2.0), 2.0);
+/// assert_eq!(tema.next(5.0), 4.625);
+/// assert_eq!(tema.next(1.0), 1.6875);
+/// ```
+///
+/// # Links
+///
+/// * [Triple exponential moving average, Wikipedia](https://en.wikipedia.org/wiki/Triple_exponential_moving_average)
+#[doc(alias = "TEMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TripleExponentialMovingAverage {
+	period: usize,
+	count: usize,
+	ema1: Ema,
+	ema2: Ema,
+	ema3: Ema,
+}
+
+impl TripleExponentialMovingAverage {
+	pub fn new(period: usize) -> Result<Self> {
+		Ok(Self {
+			period,
+			count: 0,
+			ema1: Ema::new(period)?,
+			ema2: Ema::new(period)?,
+			ema3: Ema::new(period)?,
+		})
+	}
+}
+
+impl Period for TripleExponentialMovingAverage {
+	fn period(&self) -> usize {
+		self.period
+	}
+}
+
+impl Ready for TripleExponentialMovingAverage {
+	fn is_ready(&self) -> bool {
+		self.count == 3 * (self.period - 1) + 1
+	}
+}
+
+impl Next<f64> for TripleExponentialMovingAverage {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		if self.count < 3 * (self.period - 1) + 1 {
+			self.count += 1;
+		}
+
+		let ema1 = self.ema1.next(input);
+		let ema2 = self.ema2.next(ema1);
+		let ema3 = self.ema3.next(ema2);
+		3.0 * ema1 - 3.0 * ema2 + ema3
+	}
+}
+
+impl<T: Close> Next<&T> for TripleExponentialMovingAverage {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl Reset for TripleExponentialMovingAverage {
+	fn reset(&mut self) {
+		self.count = 0;
+		self.ema1.reset();
+		self.ema2.reset();
+		self.ema3.reset();
+	}
+}
+
+impl Default for TripleExponentialMovingAverage {
+	fn default() -> Self {
+		Self::new(9).unwrap()
+	}
+}
+
+impl fmt::Display for TripleExponentialMovingAverage {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "TEMA({})", self.period)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::indicators::DoubleExponentialMovingAverage;
+	use crate::test_helper::*;
+
+	type Tema = TripleExponentialMovingAverage;
+
+	test_indicator!(Tema);
+
+	#[test]
+	fn test_new() {
+		assert!(Tema::new(0).is_err());
+		assert!(Tema::new(1).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let mut tema = Tema::new(3).unwrap();
+
+		assert_eq!(tema.next(2.0), 2.0);
+		assert_eq!(tema.next(5.0), 4.625);
+		assert_eq!(tema.next(1.0), 1.6875);
+		assert_eq!(round(tema.next(6.25)), 5.531);
+
+		let mut tema = Tema::new(3).unwrap();
+		let bar1 = Bar::new().close(2);
+		let bar2 = Bar::new().close(5);
+		assert_eq!(tema.next(&bar1), 2.0);
+		assert_eq!(tema.next(&bar2), 4.625);
+	}
+
+	#[test]
+	fn test_next_step() {
+		// TEMA gets close to a new level faster than the DEMA
+		let mut tema = Tema::new(5).unwrap();
+		let mut dema = DoubleExponentialMovingAverage::new(5).unwrap();
+		tema.next(0.0);
+		dema.next(0.0);
+
+		for _ in 0..3 {
+			let tema_val = tema.next(10.0);
+			let dema_val = dema.next(10.0);
+			assert!(10.0 - tema_val < 10.0 - dema_val);
+		}
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut tema = Tema::new(3).unwrap();
+		for _ in 0..6 {
+			tema.next(1.0);
+			assert!(!tema.is_ready());
+		}
+		tema.next(1.0);
+		assert!(tema.is_ready());
+
+		tema.reset();
+		assert!(!tema.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut tema = Tema::new(3).unwrap();
+		assert_eq!(tema.next(2.0), 2.0);
+		assert_eq!(tema.next(5.0), 4.625);
+
+		tema.reset();
+		assert_eq!(tema.next(2.0), 2.0);
+		assert_eq!(tema.next(5.0), 4.625);
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn test_serde() {
+		let mut tema = Tema::new(3).unwrap();
+		tema.next(2.0);
+		tema.next(5.0);
+
+		let bytes = bincode::serialize(&tema).unwrap();
+		let mut restored: Tema = bincode::deserialize(&bytes).unwrap();
+		assert_eq!(restored.next(1.0), tema.next(1.0));
+		assert_eq!(restored.next(6.25), tema.next(6.25));
+	}
+
+	#[test]
+	fn test_default() {
+		Tema::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let indicator = Tema::new(7).unwrap();
+		assert_eq!(format!("{}", indicator), "TEMA(7)");
+	}
+}
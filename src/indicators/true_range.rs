@@ -1,7 +1,7 @@
-use std::fmt;
+use core::fmt;
 
 use crate::helpers::max3;
-use crate::{Close, High, Low, Next, Reset};
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -18,6 +18,14 @@ use serde::{Deserialize, Serialize};
 ///
 /// TR = max[(high - low), abs(high - close<sub>prev</sub>), abs(low - close<sub>prev</sub>)]
 ///
+/// For the first bar there is no previous close and _high - low_ is returned. Given plain
+/// prices, it returns the absolute change from the previous price.
+///
+/// The true range is useful on its own, e.g. for position sizing or stop-loss placement, and
+/// is the building block of [AverageTrueRange](struct.AverageTrueRange.html),
+/// [ChoppinessIndex](struct.ChoppinessIndex.html) and
+/// [VortexIndicator](struct.VortexIndicator.html) among others.
+///
 /// # Example
 ///
 /// ```
@@ -49,8 +57,9 @@ use serde::{Deserialize, Serialize};
 ///     }
 /// }
 /// ```
+#[doc(alias = "TR")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TrueRange {
 	prev_close: Option<f64>,
 }
@@ -104,6 +113,18 @@ impl<T: High + Low + Close> Next<&T> for TrueRange {
 	}
 }
 
+impl Period for TrueRange {
+	fn period(&self) -> usize {
+		1
+	}
+}
+
+impl Ready for TrueRange {
+	fn is_ready(&self) -> bool {
+		true
+	}
+}
+
 impl Reset for TrueRange {
 	fn reset(&mut self) {
 		self.prev_close = None;
@@ -138,6 +159,16 @@ mod tests {
 		assert_eq!(tr.next(&bar3), 4.5);
 	}
 
+	#[test]
+	fn test_next_bar_gaps() {
+		let mut tr = TrueRange::new();
+		assert_eq!(tr.next(&Bar::new().high(10).low(8).close(9)), 2.0);
+		// gap up: from the previous close to the high
+		assert_eq!(tr.next(&Bar::new().high(14).low(13).close(13.5)), 5.0);
+		// gap down: from the previous close to the low
+		assert_eq!(tr.next(&Bar::new().high(11).low(10).close(10.5)), 3.5);
+	}
+
 	#[test]
 	fn test_reset() {
 		let mut tr = TrueRange::new();
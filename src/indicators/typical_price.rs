@@ -0,0 +1,141 @@
+use core::fmt;
+
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Typical price (TYPPRICE).
+///
+/// The average of the high, low and close prices of a bar, a price transform used by
+/// the [CCI](struct.CommodityChannelIndex.html), the [MFI](struct.MoneyFlowIndex.html) and
+/// the [VWAP](struct.VolumeWeightedAveragePrice.html) among others. It has no state, so it is
+/// ready from the first input.
+///
+/// # Formula
+///
+/// TYPPRICE = (H<sub>t</sub> + L<sub>t</sub> + C<sub>t</sub>) / 3
+///
+/// Where:
+///
+/// * _H<sub>t</sub>_, _L<sub>t</sub>_, _C<sub>t</sub>_ - high, low and close prices of the current period
+///
+/// When a single `f64` value is given, it is the high, the low and the close, and is returned
+/// as is.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{SimpleMovingAverage as Sma, TypicalPrice};
+/// use ta::{DataItem, Next, Pipe};
+///
+/// let mut typical_price = TypicalPrice::new();
+///
+/// let di = DataItem::builder()
+///             .high(12.0)
+///             .low(9.0)
+///             .close(9.0)
+///             .open(11.0)
+///             .volume(1000.0)
+///             .build().unwrap();
+///
+/// assert_eq!(typical_price.next(&di), 10.0);
+///
+/// // moving average of the typical price
+/// let mut sma = TypicalPrice::new().pipe(Sma::new(14).unwrap());
+/// assert_eq!(sma.next(&di), 10.0);
+/// ```
+#[doc(alias = "TYPPRICE")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypicalPrice {}
+
+impl TypicalPrice {
+	pub fn new() -> Self {
+		Self {}
+	}
+}
+
+impl Period for TypicalPrice {
+	fn period(&self) -> usize {
+		1
+	}
+}
+
+impl Ready for TypicalPrice {
+	fn is_ready(&self) -> bool {
+		true
+	}
+}
+
+impl Next<f64> for TypicalPrice {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		input
+	}
+}
+
+impl<T: High + Low + Close> Next<&T> for TypicalPrice {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		(input.high() + input.low() + input.close()) / 3.0
+	}
+}
+
+impl Reset for TypicalPrice {
+	fn reset(&mut self) {}
+}
+
+impl Default for TypicalPrice {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl fmt::Display for TypicalPrice {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "TYPPRICE")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	test_indicator!(TypicalPrice);
+
+	#[test]
+	fn test_next_with_f64() {
+		let mut typical_price = TypicalPrice::new();
+		assert_eq!(typical_price.next(3.5), 3.5);
+		assert_eq!(typical_price.next(-1.0), -1.0);
+	}
+
+	#[test]
+	fn test_next_with_bars() {
+		let mut typical_price = TypicalPrice::new();
+		let bar = Bar::new().high(12).low(9).close(9);
+		assert_eq!(typical_price.next(&bar), 10.0);
+		let bar = Bar::new().high(5).low(5).close(5);
+		assert_eq!(typical_price.next(&bar), 5.0);
+		let bar = Bar::new().high(4).low(1).close(2);
+		assert_eq!(typical_price.next(&bar), 7.0 / 3.0);
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut typical_price = TypicalPrice::new();
+		let bar = Bar::new().high(4).low(2).close(3);
+		typical_price.next(&bar);
+		typical_price.reset();
+		assert_eq!(typical_price, TypicalPrice::default());
+		assert_eq!(typical_price.next(&bar), 3.0);
+	}
+
+	#[test]
+	fn test_display() {
+		assert_eq!(format!("{}", TypicalPrice::new()), "TYPPRICE");
+	}
+}
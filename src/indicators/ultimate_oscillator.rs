@@ -0,0 +1,253 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::RollingSum;
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Ultimate Oscillator (UO).
+///
+/// A momentum oscillator combining the buying pressure over three timeframes.
+/// It ranges from 0 to 100.
+///
+/// # Formula
+///
+/// BP = close - min(low, previous close)
+///
+/// TR = max(high, previous close) - min(low, previous close)
+///
+/// Avg<sub>k</sub> = Σ BP / Σ TR over the last _k_ periods
+///
+/// UO = 100 × (4 × Avg<sub>short</sub> + 2 × Avg<sub>medium</sub> + Avg<sub>long</sub>) / 7
+///
+/// Where:
+///
+/// * _BP_ - buying pressure
+/// * _TR_ - true range
+///
+/// If the sum of the true ranges of a timeframe is 0, its average is taken as 0.5.
+///
+/// # Parameters
+///
+/// * _short_period_ - short period (integer greater than 0). Default is 7.
+/// * _medium_period_ - medium period (integer greater than _short_period_). Default is 14.
+/// * _long_period_ - long period (integer greater than _medium_period_). Default is 28.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::UltimateOscillator;
+/// use ta::{DataItem, Next};
+///
+/// let mut uo = UltimateOscillator::new(1, 2, 4).unwrap();
+///
+/// let di = DataItem::builder()
+///             .high(10.0)
+///             .low(8.0)
+///             .close(9.0)
+///             .open(9.0)
+///             .volume(100.0)
+///             .build().unwrap();
+///
+/// assert_eq!(uo.next(&di), 50.0);
+/// ```
+///
+/// # Links
+///
+/// * [Ultimate oscillator, Wikipedia](https://en.wikipedia.org/wiki/Ultimate_oscillator)
+#[doc(alias = "UO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct UltimateOscillator {
+	prev_close: Option<f64>,
+	/// Sums over the short, medium and long periods.
+	bp_sums: [RollingSum; 3],
+	tr_sums: [RollingSum; 3],
+}
+
+impl UltimateOscillator {
+	pub fn new(short_period: usize, medium_period: usize, long_period: usize) -> Result<Self> {
+		if short_period == 0 || short_period >= medium_period || medium_period >= long_period {
+			return Err(TaError::InvalidParameter);
+		}
+
+		let sums = || -> Result<[RollingSum; 3]> {
+			Ok([
+				RollingSum::new(short_period)?,
+				RollingSum::new(medium_period)?,
+				RollingSum::new(long_period)?,
+			])
+		};
+		Ok(Self {
+			prev_close: None,
+			bp_sums: sums()?,
+			tr_sums: sums()?,
+		})
+	}
+}
+
+impl Period for UltimateOscillator {
+	fn period(&self) -> usize {
+		self.tr_sums[2].period()
+	}
+}
+
+impl Ready for UltimateOscillator {
+	fn is_ready(&self) -> bool {
+		self.tr_sums[2].is_ready()
+	}
+}
+
+impl<T: High + Low + Close> Next<&T> for UltimateOscillator {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		let close = input.close();
+		let prev_close = self.prev_close.unwrap_or(close);
+		let low = input.low().min(prev_close);
+		let high = input.high().max(prev_close);
+		let bp = close - low;
+		let tr = high - low;
+		self.prev_close = Some(close);
+
+		let mut averages = [0.0; 3];
+		for (i, average) in averages.iter_mut().enumerate() {
+			let bp_sum = self.bp_sums[i].next(bp);
+			let tr_sum = self.tr_sums[i].next(tr);
+			*average = if tr_sum == 0.0 { 0.5 } else { bp_sum / tr_sum };
+		}
+
+		100.0 * (4.0 * averages[0] + 2.0 * averages[1] + averages[2]) / 7.0
+	}
+}
+
+impl Reset for UltimateOscillator {
+	fn reset(&mut self) {
+		self.prev_close = None;
+		for sum in self.bp_sums.iter_mut().chain(&mut self.tr_sums) {
+			sum.reset();
+		}
+	}
+}
+
+impl Default for UltimateOscillator {
+	fn default() -> Self {
+		Self::new(7, 14, 28).unwrap()
+	}
+}
+
+impl fmt::Display for UltimateOscillator {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"UO({}, {}, {})",
+			self.tr_sums[0].period(),
+			self.tr_sums[1].period(),
+			self.tr_sums[2].period()
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	#[test]
+	fn test_new() {
+		assert!(UltimateOscillator::new(0, 14, 28).is_err());
+		assert!(UltimateOscillator::new(14, 14, 28).is_err());
+		assert!(UltimateOscillator::new(7, 28, 28).is_err());
+		assert!(UltimateOscillator::new(7, 28, 14).is_err());
+		assert!(UltimateOscillator::new(7, 14, 28).is_ok());
+	}
+
+	#[test]
+	fn test_next_bar() {
+		let test_data = vec![
+			// high, low, close, expected
+			(10.0, 8.0, 9.0, 50.0),
+			(11.0, 9.0, 10.5, 69.643),
+			(12.0, 10.0, 11.0, 54.762),
+			(11.0, 8.0, 9.0, 37.619),
+			(10.0, 7.0, 7.5, 22.381),
+			(9.0, 7.0, 8.5, 60.0),
+		];
+
+		let mut uo = UltimateOscillator::new(1, 2, 4).unwrap();
+
+		for (high, low, close, expected) in test_data {
+			let bar = Bar::new().high(high).low(low).close(close);
+			assert_eq!(round(uo.next(&bar)), expected);
+		}
+	}
+
+	#[test]
+	fn test_flat_market() {
+		let mut uo = UltimateOscillator::new(1, 2, 3).unwrap();
+		let bar = Bar::new().high(5).low(5).close(5);
+		assert_eq!(uo.next(&bar), 50.0);
+		assert_eq!(uo.next(&bar), 50.0);
+	}
+
+	#[test]
+	fn test_flat_after_movement() {
+		// the sums of the ranges do not cancel out exactly
+		let mut uo = UltimateOscillator::new(1, 2, 3).unwrap();
+		let moving = [
+			(2.1, 1.4, 2.0),
+			(2.6, 1.9, 2.5),
+			(1.0, 0.5, 1.0),
+			(2.4, 1.5, 1.6),
+			(1.4, 1.2, 1.3),
+		];
+		for (high, low, close) in moving {
+			uo.next(&Bar::new().high(high).low(low).close(close));
+		}
+		let flat = Bar::new().high(1.3).low(1.3).close(1.3);
+		uo.next(&flat);
+		uo.next(&flat);
+		for _ in 0..3 {
+			assert_eq!(uo.next(&flat), 50.0);
+		}
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut uo = UltimateOscillator::new(1, 2, 3).unwrap();
+		let bar = Bar::new().high(5).low(4).close(5);
+		uo.next(&bar);
+		uo.next(&bar);
+		assert!(!uo.is_ready());
+		uo.next(&bar);
+		assert!(uo.is_ready());
+
+		uo.reset();
+		assert!(!uo.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut uo = UltimateOscillator::new(1, 2, 4).unwrap();
+		let bar1 = Bar::new().high(10).low(8).close(9);
+		let bar2 = Bar::new().high(11).low(9).close(10.5);
+
+		assert_eq!(uo.next(&bar1), 50.0);
+		assert_eq!(round(uo.next(&bar2)), 69.643);
+
+		uo.reset();
+		assert_eq!(round(uo.next(&bar2)), 75.0);
+	}
+
+	#[test]
+	fn test_default() {
+		UltimateOscillator::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let indicator = UltimateOscillator::new(7, 14, 28).unwrap();
+		assert_eq!(format!("{}", indicator), "UO(7, 14, 28)");
+	}
+}
@@ -0,0 +1,257 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Variance (VAR).
+///
+/// Returns the variance of the last n values. It is updated with Welford's online
+/// algorithm, which stays numerically stable for nearly constant series, unlike the naive
+/// Σx² - (Σx)² / n.
+///
+/// # Formula
+///
+/// VAR = Σ (x<sub>i</sub> - μ)² / (N - ddof)
+///
+/// Where:
+///
+/// * _μ_ - mean of the last _N_ values
+/// * _N_ - number of values in the observation
+/// * _ddof_ - delta degrees of freedom, 0 for the population and 1 for the sample variance
+///
+/// Until more than _ddof_ values have been consumed, 0 is returned.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
+/// * _ddof_ - delta degrees of freedom (integer lower than _period_). Default is 0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Variance;
+/// use ta::Next;
+///
+/// let mut var = Variance::new(3, 0).unwrap();
+/// assert_eq!(var.next(10.0), 0.0);
+/// assert_eq!(var.next(20.0), 25.0);
+///
+/// let mut var = Variance::new(3, 1).unwrap();
+/// assert_eq!(var.next(10.0), 0.0);
+/// assert_eq!(var.next(20.0), 50.0);
+/// ```
+///
+/// # Links
+///
+/// * [Variance, Wikipedia](https://en.wikipedia.org/wiki/Variance)
+/// * [Welford's online algorithm, Wikipedia](https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Welford's_online_algorithm)
+#[doc(alias = "VAR")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Variance {
+	period: usize,
+	ddof: usize,
+	index: usize,
+	count: usize,
+	m: f64,
+	m2: f64,
+	deque: Box<[f64]>,
+}
+
+impl Variance {
+	pub fn new(period: usize, ddof: usize) -> Result<Self> {
+		if period == 0 || ddof >= period {
+			return Err(TaError::InvalidParameter);
+		}
+
+		Ok(Self {
+			period,
+			ddof,
+			index: 0,
+			count: 0,
+			m: 0.0,
+			m2: 0.0,
+			deque: vec![0.0; period].into_boxed_slice(),
+		})
+	}
+
+	pub fn ddof(&self) -> usize {
+		self.ddof
+	}
+
+	pub(super) fn mean(&self) -> f64 {
+		self.m
+	}
+}
+
+impl Period for Variance {
+	fn period(&self) -> usize {
+		self.period
+	}
+}
+
+impl Ready for Variance {
+	fn is_ready(&self) -> bool {
+		self.count == self.period
+	}
+}
+
+impl Next<f64> for Variance {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		let old_val = self.deque[self.index];
+		self.deque[self.index] = input;
+
+		self.index = if self.index + 1 < self.period {
+			self.index + 1
+		} else {
+			0
+		};
+
+		if self.count < self.period {
+			self.count += 1;
+			let delta = input - self.m;
+			self.m += delta / self.count as f64;
+			let delta2 = input - self.m;
+			self.m2 += delta * delta2;
+		} else {
+			let delta = input - old_val;
+			let old_m = self.m;
+			self.m += delta / self.period as f64;
+			let delta2 = input - self.m + old_val - old_m;
+			self.m2 += delta * delta2;
+		}
+		if self.m2 < 0.0 {
+			self.m2 = 0.0;
+		}
+
+		if self.count <= self.ddof {
+			0.0
+		} else {
+			self.m2 / (self.count - self.ddof) as f64
+		}
+	}
+}
+
+impl<T: Close> Next<&T> for Variance {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl Reset for Variance {
+	fn reset(&mut self) {
+		self.index = 0;
+		self.count = 0;
+		self.m = 0.0;
+		self.m2 = 0.0;
+		for i in 0..self.period {
+			self.deque[i] = 0.0;
+		}
+	}
+}
+
+impl Default for Variance {
+	fn default() -> Self {
+		Self::new(9, 0).unwrap()
+	}
+}
+
+impl fmt::Display for Variance {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self.ddof {
+			0 => write!(f, "VAR({})", self.period),
+			ddof => write!(f, "VAR({}, {})", self.period, ddof),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	test_indicator!(Variance);
+
+	#[test]
+	fn test_new() {
+		assert!(Variance::new(0, 0).is_err());
+		assert!(Variance::new(1, 1).is_err());
+		assert!(Variance::new(3, 4).is_err());
+		assert!(Variance::new(1, 0).is_ok());
+		assert!(Variance::new(2, 1).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let mut var = Variance::new(4, 0).unwrap();
+		assert_eq!(var.next(10.0), 0.0);
+		assert_eq!(var.next(20.0), 25.0);
+		assert_eq!(round(var.next(30.0)), 66.667);
+		assert_eq!(round(var.next(20.0)), 50.0);
+		assert_eq!(round(var.next(10.0)), 50.0);
+		assert_eq!(round(var.next(100.0)), 1250.0);
+	}
+
+	#[test]
+	fn test_next_sample() {
+		let mut var = Variance::new(4, 1).unwrap();
+		assert_eq!(var.next(10.0), 0.0);
+		assert_eq!(var.next(20.0), 50.0);
+		assert_eq!(round(var.next(30.0)), 100.0);
+		assert_eq!(round(var.next(20.0)), 66.667);
+		assert_eq!(round(var.next(10.0)), 66.667);
+		assert_eq!(round(var.next(100.0)), 1666.667);
+	}
+
+	#[test]
+	fn test_next_nearly_constant() {
+		// the naive formula loses all precision with such a large offset
+		let mut var = Variance::new(3, 1).unwrap();
+		let offset = 1e9;
+		var.next(offset + 4.0);
+		var.next(offset + 7.0);
+		assert_eq!(var.next(offset + 13.0), 21.0);
+		assert_eq!(var.next(offset + 16.0), 21.0);
+	}
+
+	#[test]
+	fn test_next_same_values() {
+		let mut var = Variance::new(3, 1).unwrap();
+		for _ in 0..5 {
+			assert_eq!(var.next(4.2), 0.0);
+		}
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut var = Variance::new(4, 0).unwrap();
+		assert_eq!(var.next(10.0), 0.0);
+		assert_eq!(var.next(20.0), 25.0);
+
+		var.reset();
+		assert_eq!(var.next(20.0), 0.0);
+		assert_eq!(var.next(10.0), 25.0);
+	}
+
+	#[test]
+	fn test_default() {
+		Variance::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let var = Variance::new(5, 0).unwrap();
+		assert_eq!(format!("{}", var), "VAR(5)");
+
+		let var = Variance::new(5, 1).unwrap();
+		assert_eq!(format!("{}", var), "VAR(5, 1)");
+	}
+}
@@ -0,0 +1,174 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::RelativeStrengthIndex;
+use crate::{Next, Period, Ready, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Volume relative strength index (VRSI).
+///
+/// The [RSI](struct.RelativeStrengthIndex.html) formula applied to the volume instead of
+/// the price: a period whose volume is higher than the previous one counts as an up period,
+/// a lower volume as a down period. Values above 70 or below 30 can precede significant
+/// price moves and are used to confirm signals.
+///
+/// The oscillator returns output in the range of 0..100.
+///
+/// # Formula
+///
+/// VRSI<sub>t</sub> = RSI(V)<sub>t</sub>
+///
+/// Where:
+///
+/// * _RSI_ - [relative strength index](struct.RelativeStrengthIndex.html)
+/// * _V_ - volume
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default value is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::VolumeRsi;
+/// use ta::Next;
+///
+/// let mut vrsi = VolumeRsi::new(3).unwrap();
+/// assert_eq!(vrsi.next(1000.0), 50.0);
+/// assert!(vrsi.next(1500.0) > 70.0);
+/// assert!(vrsi.next(600.0) < 30.0);
+/// ```
+///
+/// # Links
+///
+/// * [Volume RSI, Marketvolume](https://www.marketvolume.com/technicalanalysis/volumersi.asp)
+#[doc(alias = "VRSI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolumeRsi {
+	rsi: RelativeStrengthIndex,
+}
+
+impl VolumeRsi {
+	pub fn new(period: usize) -> Result<Self> {
+		Ok(Self {
+			rsi: RelativeStrengthIndex::new(period)?,
+		})
+	}
+}
+
+impl Period for VolumeRsi {
+	fn period(&self) -> usize {
+		self.rsi.period()
+	}
+}
+
+impl Ready for VolumeRsi {
+	fn is_ready(&self) -> bool {
+		self.rsi.is_ready()
+	}
+}
+
+impl Next<f64> for VolumeRsi {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		self.rsi.next(input)
+	}
+}
+
+impl<T: Volume> Next<&T> for VolumeRsi {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.rsi.next(input.volume())
+	}
+}
+
+impl Reset for VolumeRsi {
+	fn reset(&mut self) {
+		self.rsi.reset();
+	}
+}
+
+impl Default for VolumeRsi {
+	fn default() -> Self {
+		Self::new(14).unwrap()
+	}
+}
+
+impl fmt::Display for VolumeRsi {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "VRSI({})", self.rsi.period())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	test_indicator!(VolumeRsi);
+
+	#[test]
+	fn test_new() {
+		assert!(VolumeRsi::new(0).is_err());
+		assert!(VolumeRsi::new(1).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let mut vrsi = VolumeRsi::new(3).unwrap();
+		assert_eq!(vrsi.next(1000.0), 50.0);
+		assert_eq!(round(vrsi.next(1500.0)), 99.96);
+		assert_eq!(round(vrsi.next(1200.0)), 52.63);
+		assert_eq!(round(vrsi.next(900.0)), 30.774);
+		assert_eq!(round(vrsi.next(2000.0)), 78.921);
+	}
+
+	#[test]
+	fn test_next_bar() {
+		let mut vrsi = VolumeRsi::new(3).unwrap();
+		// the price is ignored, only the volume matters
+		let bar1 = Bar::new().close(10).volume(1000.0);
+		let bar2 = Bar::new().close(5).volume(1500.0);
+		assert_eq!(vrsi.next(&bar1), 50.0);
+		assert_eq!(round(vrsi.next(&bar2)), 99.96);
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut vrsi = VolumeRsi::new(3).unwrap();
+		vrsi.next(1000.0);
+		vrsi.next(2000.0);
+		assert!(!vrsi.is_ready());
+		vrsi.next(1500.0);
+		assert!(vrsi.is_ready());
+
+		vrsi.reset();
+		assert!(!vrsi.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut vrsi = VolumeRsi::new(3).unwrap();
+		assert_eq!(vrsi.next(1000.0), 50.0);
+		assert_eq!(round(vrsi.next(1500.0)), 99.96);
+
+		vrsi.reset();
+		assert_eq!(vrsi.next(1000.0), 50.0);
+		assert_eq!(round(vrsi.next(1500.0)), 99.96);
+	}
+
+	#[test]
+	fn test_default() {
+		VolumeRsi::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let vrsi = VolumeRsi::new(14).unwrap();
+		assert_eq!(format!("{}", vrsi), "VRSI(14)");
+	}
+}
@@ -0,0 +1,327 @@
+use core::fmt;
+
+use num_traits::Float;
+
+use crate::{Close, High, Low, Next, Period, Ready, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Volume Weighted Average Price (VWAP).
+///
+/// The average price weighted by volume since the beginning of the session.
+/// Unlike windowed indicators, VWAP is cumulative: use `reset()` to start a new session.
+///
+/// # Formula
+///
+/// VWAP = Σ(TP × volume) / Σ(volume)
+///
+/// Where:
+///
+/// * _TP_ - typical price, (high + low + close) / 3
+///
+/// If the total volume of the session is zero, the typical price of the current bar is returned.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::VolumeWeightedAveragePrice;
+/// use ta::{Next, DataItem};
+///
+/// let mut vwap = VolumeWeightedAveragePrice::new();
+///
+/// let di1 = DataItem::builder()
+///             .high(3.0)
+///             .low(1.0)
+///             .close(2.0)
+///             .open(1.5)
+///             .volume(100.0)
+///             .build().unwrap();
+///
+/// let di2 = DataItem::builder()
+///             .high(6.0)
+///             .low(4.0)
+///             .close(5.0)
+///             .open(4.5)
+///             .volume(300.0)
+///             .build().unwrap();
+///
+/// assert_eq!(vwap.next(&di1), 2.0);
+/// assert_eq!(vwap.next(&di2), 4.25);
+/// ```
+///
+/// # Links
+///
+/// * [Volume-weighted average price, Wikipedia](https://en.wikipedia.org/wiki/Volume-weighted_average_price)
+#[doc(alias = "VWAP")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolumeWeightedAveragePrice {
+	numerator_sum: f64,
+	denominator_sum: f64,
+	value: f64,
+}
+
+impl VolumeWeightedAveragePrice {
+	pub fn new() -> Self {
+		Self {
+			numerator_sum: 0.0,
+			denominator_sum: 0.0,
+			value: 0.0,
+		}
+	}
+}
+
+impl Period for VolumeWeightedAveragePrice {
+	fn period(&self) -> usize {
+		1
+	}
+}
+
+impl Ready for VolumeWeightedAveragePrice {
+	fn is_ready(&self) -> bool {
+		true
+	}
+}
+
+impl<T: High + Low + Close + Volume> Next<&T> for VolumeWeightedAveragePrice {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		let tp = (input.high() + input.low() + input.close()) / 3.0;
+
+		self.numerator_sum += tp * input.volume();
+		self.denominator_sum += input.volume();
+
+		self.value = if self.denominator_sum == 0.0 {
+			tp
+		} else {
+			self.numerator_sum / self.denominator_sum
+		};
+		self.value
+	}
+}
+
+impl Reset for VolumeWeightedAveragePrice {
+	fn reset(&mut self) {
+		self.numerator_sum = 0.0;
+		self.denominator_sum = 0.0;
+		self.value = 0.0;
+	}
+}
+
+impl Default for VolumeWeightedAveragePrice {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl fmt::Display for VolumeWeightedAveragePrice {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "VWAP")
+	}
+}
+
+/// Volume Weighted Average Price (VWAP) with standard deviation bands.
+///
+/// Returns the [VWAP](struct.VolumeWeightedAveragePrice.html) together with bands placed one and
+/// two volume weighted standard deviations away from it.
+///
+/// # Formula
+///
+/// σ = sqrt(Σ(TP² × volume) / Σ(volume) - VWAP²)
+///
+/// * _upper1_ = VWAP + σ, _lower1_ = VWAP - σ
+/// * _upper2_ = VWAP + 2σ, _lower2_ = VWAP - 2σ
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::VolumeWeightedAveragePriceBands;
+/// use ta::{Next, DataItem};
+///
+/// let mut vwap = VolumeWeightedAveragePriceBands::new();
+///
+/// let di1 = DataItem::builder()
+///             .high(3.0).low(1.0).close(2.0).open(1.5).volume(100.0)
+///             .build().unwrap();
+/// let di2 = DataItem::builder()
+///             .high(6.0).low(4.0).close(5.0).open(4.5).volume(300.0)
+///             .build().unwrap();
+///
+/// vwap.next(&di1);
+/// let out = vwap.next(&di2);
+/// assert_eq!(out.average, 4.25);
+/// assert!((out.upper1 - 5.549).abs() < 0.001);
+/// assert!((out.lower2 - 1.652).abs() < 0.001);
+/// ```
+#[doc(alias = "VWAP")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolumeWeightedAveragePriceBands {
+	vwap: VolumeWeightedAveragePrice,
+	squared_sum: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolumeWeightedAveragePriceBandsOutput {
+	pub average: f64,
+	pub upper1: f64,
+	pub lower1: f64,
+	pub upper2: f64,
+	pub lower2: f64,
+}
+
+impl VolumeWeightedAveragePriceBands {
+	pub fn new() -> Self {
+		Self {
+			vwap: VolumeWeightedAveragePrice::new(),
+			squared_sum: 0.0,
+		}
+	}
+}
+
+impl Period for VolumeWeightedAveragePriceBands {
+	fn period(&self) -> usize {
+		1
+	}
+}
+
+impl Ready for VolumeWeightedAveragePriceBands {
+	fn is_ready(&self) -> bool {
+		true
+	}
+}
+
+impl<T: High + Low + Close + Volume> Next<&T> for VolumeWeightedAveragePriceBands {
+	type Output = VolumeWeightedAveragePriceBandsOutput;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		let tp = (input.high() + input.low() + input.close()) / 3.0;
+		let average = self.vwap.next(input);
+		self.squared_sum += tp * tp * input.volume();
+
+		let sd = if self.vwap.denominator_sum == 0.0 {
+			0.0
+		} else {
+			let variance = self.squared_sum / self.vwap.denominator_sum - average * average;
+			Float::sqrt(variance.max(0.0))
+		};
+
+		Self::Output {
+			average,
+			upper1: average + sd,
+			lower1: average - sd,
+			upper2: average + 2.0 * sd,
+			lower2: average - 2.0 * sd,
+		}
+	}
+}
+
+impl Reset for VolumeWeightedAveragePriceBands {
+	fn reset(&mut self) {
+		self.vwap.reset();
+		self.squared_sum = 0.0;
+	}
+}
+
+impl Default for VolumeWeightedAveragePriceBands {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl fmt::Display for VolumeWeightedAveragePriceBands {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "VWAP_BANDS")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	#[test]
+	fn test_next_bar() {
+		let mut vwap = VolumeWeightedAveragePrice::new();
+
+		let bar1 = Bar::new().high(3).low(1).close(2).volume(100.0);
+		assert_eq!(vwap.next(&bar1), 2.0);
+
+		let bar2 = Bar::new().high(6).low(4).close(5).volume(300.0);
+		assert_eq!(vwap.next(&bar2), 4.25);
+
+		let bar3 = Bar::new().high(5).low(3).close(4).volume(0.0);
+		assert_eq!(vwap.next(&bar3), 4.25);
+
+		let bar4 = Bar::new().high(2).low(1).close(1.5).volume(400.0);
+		assert_eq!(vwap.next(&bar4), 2.875);
+	}
+
+	#[test]
+	fn test_next_zero_volume() {
+		let mut vwap = VolumeWeightedAveragePrice::new();
+
+		let bar1 = Bar::new().high(3).low(1).close(2).volume(0.0);
+		assert_eq!(vwap.next(&bar1), 2.0);
+
+		let bar2 = Bar::new().high(6).low(4).close(5).volume(0.0);
+		assert_eq!(vwap.next(&bar2), 5.0);
+	}
+
+	#[test]
+	fn test_next_bands() {
+		let mut vwap = VolumeWeightedAveragePriceBands::new();
+
+		let bar1 = Bar::new().high(3).low(1).close(2).volume(100.0);
+		let out = vwap.next(&bar1);
+		assert_eq!(out.average, 2.0);
+		assert_eq!(out.upper1, 2.0);
+		assert_eq!(out.lower2, 2.0);
+
+		let bar2 = Bar::new().high(6).low(4).close(5).volume(300.0);
+		let out = vwap.next(&bar2);
+		assert_eq!(out.average, 4.25);
+		assert_eq!(round(out.upper1), 5.549);
+		assert_eq!(round(out.lower1), 2.951);
+		assert_eq!(round(out.upper2), 6.848);
+		assert_eq!(round(out.lower2), 1.652);
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut vwap = VolumeWeightedAveragePrice::new();
+		let bar1 = Bar::new().high(3).low(1).close(2).volume(100.0);
+		let bar2 = Bar::new().high(6).low(4).close(5).volume(300.0);
+
+		assert_eq!(vwap.next(&bar1), 2.0);
+		assert_eq!(vwap.next(&bar2), 4.25);
+
+		vwap.reset();
+		assert_eq!(vwap.next(&bar2), 5.0);
+		assert_eq!(vwap.next(&bar1), 4.25);
+
+		let mut bands = VolumeWeightedAveragePriceBands::new();
+		bands.next(&bar1);
+		bands.next(&bar2);
+		bands.reset();
+		let out = bands.next(&bar2);
+		assert_eq!(out.average, 5.0);
+		assert_eq!(out.upper2, 5.0);
+	}
+
+	#[test]
+	fn test_default() {
+		VolumeWeightedAveragePrice::default();
+		VolumeWeightedAveragePriceBands::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let vwap = VolumeWeightedAveragePrice::new();
+		assert_eq!(format!("{}", vwap), "VWAP");
+
+		let bands = VolumeWeightedAveragePriceBands::new();
+		assert_eq!(format!("{}", bands), "VWAP_BANDS");
+	}
+}
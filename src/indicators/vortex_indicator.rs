@@ -0,0 +1,278 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{RollingSum, TrueRange};
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Vortex Indicator (VI).
+///
+/// Consists of two lines capturing the positive and the negative trend movement.
+/// A crossover of VI+ above VI- is a bullish signal.
+///
+/// # Formula
+///
+/// VM+<sub>t</sub> = |H<sub>t</sub> - L<sub>t-1</sub>|
+///
+/// VM-<sub>t</sub> = |L<sub>t</sub> - H<sub>t-1</sub>|
+///
+/// VI+ = Σ VM+ / Σ TR over the last _n_ periods
+///
+/// VI- = Σ VM- / Σ TR over the last _n_ periods
+///
+/// Where:
+///
+/// * _H_ - high price
+/// * _L_ - low price
+/// * _TR_ - [true range](struct.TrueRange.html)
+///
+/// The first period has no previous high and low, so 0 is returned for both lines and the
+/// indicator is ready once _n + 1_ values have been consumed. If the sum of the true ranges
+/// is 0, both lines are 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::VortexIndicator;
+/// use ta::{DataItem, Next};
+///
+/// let mut vi = VortexIndicator::new(2).unwrap();
+///
+/// let di1 = DataItem::builder()
+///             .high(10.0)
+///             .low(8.0)
+///             .close(9.0)
+///             .open(9.0)
+///             .volume(100.0)
+///             .build().unwrap();
+///
+/// let di2 = DataItem::builder()
+///             .high(11.0)
+///             .low(9.0)
+///             .close(10.5)
+///             .open(9.0)
+///             .volume(100.0)
+///             .build().unwrap();
+///
+/// vi.next(&di1);
+/// let out = vi.next(&di2);
+/// assert_eq!(out.vi_plus, 1.5);
+/// assert_eq!(out.vi_minus, 0.5);
+/// ```
+///
+/// # Links
+///
+/// * [Vortex indicator, Wikipedia](https://en.wikipedia.org/wiki/Vortex_indicator)
+#[doc(alias = "VI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct VortexIndicator {
+	true_range: TrueRange,
+	prev: Option<(f64, f64)>,
+	vm_plus_sum: RollingSum,
+	vm_minus_sum: RollingSum,
+	tr_sum: RollingSum,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VortexIndicatorOutput {
+	pub vi_plus: f64,
+	pub vi_minus: f64,
+}
+
+impl From<VortexIndicatorOutput> for (f64, f64) {
+	fn from(vi: VortexIndicatorOutput) -> Self {
+		(vi.vi_plus, vi.vi_minus)
+	}
+}
+
+impl VortexIndicator {
+	pub fn new(period: usize) -> Result<Self> {
+		Ok(Self {
+			true_range: TrueRange::new(),
+			prev: None,
+			vm_plus_sum: RollingSum::new(period)?,
+			vm_minus_sum: RollingSum::new(period)?,
+			tr_sum: RollingSum::new(period)?,
+		})
+	}
+}
+
+impl Period for VortexIndicator {
+	fn period(&self) -> usize {
+		self.tr_sum.period()
+	}
+}
+
+impl Ready for VortexIndicator {
+	fn is_ready(&self) -> bool {
+		self.tr_sum.is_ready()
+	}
+}
+
+impl<T: High + Low + Close> Next<&T> for VortexIndicator {
+	type Output = VortexIndicatorOutput;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		let (high, low) = (input.high(), input.low());
+		let tr = self.true_range.next(input);
+
+		let (prev_high, prev_low) = match self.prev.replace((high, low)) {
+			Some(prev) => prev,
+			None => {
+				return VortexIndicatorOutput {
+					vi_plus: 0.0,
+					vi_minus: 0.0,
+				}
+			}
+		};
+
+		let vm_plus = (high - prev_low).abs();
+		let vm_minus = (low - prev_high).abs();
+
+		let vm_plus_sum = self.vm_plus_sum.next(vm_plus);
+		let vm_minus_sum = self.vm_minus_sum.next(vm_minus);
+		let tr_sum = self.tr_sum.next(tr);
+
+		if tr_sum == 0.0 {
+			VortexIndicatorOutput {
+				vi_plus: 0.0,
+				vi_minus: 0.0,
+			}
+		} else {
+			VortexIndicatorOutput {
+				vi_plus: vm_plus_sum / tr_sum,
+				vi_minus: vm_minus_sum / tr_sum,
+			}
+		}
+	}
+}
+
+impl Reset for VortexIndicator {
+	fn reset(&mut self) {
+		self.true_range.reset();
+		self.prev = None;
+		self.vm_plus_sum.reset();
+		self.vm_minus_sum.reset();
+		self.tr_sum.reset();
+	}
+}
+
+impl Default for VortexIndicator {
+	fn default() -> Self {
+		Self::new(14).unwrap()
+	}
+}
+
+impl fmt::Display for VortexIndicator {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "VI({})", self.tr_sum.period())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	#[test]
+	fn test_new() {
+		assert!(VortexIndicator::new(0).is_err());
+		assert!(VortexIndicator::new(1).is_ok());
+	}
+
+	#[test]
+	fn test_next_bar() {
+		let test_data = vec![
+			// high, low, close, vi+, vi-
+			(10.0, 8.0, 9.0, 0.0, 0.0),
+			(11.0, 9.0, 10.5, 1.5, 0.5),
+			(12.0, 10.0, 11.0, 1.5, 0.5),
+			(11.0, 8.0, 9.0, 0.8, 1.0),
+			(10.0, 7.0, 7.5, 0.5, 1.333),
+			(9.0, 7.0, 8.5, 0.8, 1.4),
+		];
+
+		let mut vi = VortexIndicator::new(2).unwrap();
+
+		for (high, low, close, vi_plus, vi_minus) in test_data {
+			let bar = Bar::new().high(high).low(low).close(close);
+			let (plus, minus) = vi.next(&bar).into();
+			assert_eq!(round(plus), vi_plus);
+			assert_eq!(round(minus), vi_minus);
+		}
+	}
+
+	#[test]
+	fn test_flat_market() {
+		let mut vi = VortexIndicator::new(2).unwrap();
+		let bar = Bar::new().high(5).low(5).close(5);
+		for _ in 0..3 {
+			let out = vi.next(&bar);
+			assert_eq!(out.vi_plus, 0.0);
+			assert_eq!(out.vi_minus, 0.0);
+		}
+	}
+
+	#[test]
+	fn test_flat_after_movement() {
+		// the sums of the moving bars do not cancel out exactly
+		let mut vi = VortexIndicator::new(3).unwrap();
+		for (high, low) in &[(1.0, 0.9), (2.8, 1.8), (1.4, 0.6), (1.3, 1.1)] {
+			vi.next(&Bar::new().high(*high).low(*low).close(*low));
+		}
+		let flat = Bar::new().high(1.1).low(1.1).close(1.1);
+		for _ in 0..5 {
+			let out = vi.next(&flat);
+			assert!(out.vi_plus >= 0.0);
+			assert!(out.vi_minus >= 0.0);
+		}
+		let out = vi.next(&flat);
+		assert_eq!(out.vi_plus, 0.0);
+		assert_eq!(out.vi_minus, 0.0);
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut vi = VortexIndicator::new(2).unwrap();
+		let bar = Bar::new().high(5).low(4).close(5);
+		vi.next(&bar);
+		vi.next(&bar);
+		assert!(!vi.is_ready());
+		vi.next(&bar);
+		assert!(vi.is_ready());
+
+		vi.reset();
+		assert!(!vi.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut vi = VortexIndicator::new(2).unwrap();
+		let bar1 = Bar::new().high(10).low(8).close(9);
+		let bar2 = Bar::new().high(11).low(9).close(10.5);
+
+		vi.next(&bar1);
+		assert_eq!(vi.next(&bar2).vi_plus, 1.5);
+
+		vi.reset();
+		assert_eq!(vi.next(&bar2).vi_plus, 0.0);
+		assert_eq!(vi.next(&bar1).vi_plus, 0.4);
+	}
+
+	#[test]
+	fn test_default() {
+		VortexIndicator::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let indicator = VortexIndicator::new(14).unwrap();
+		assert_eq!(format!("{}", indicator), "VI(14)");
+	}
+}
@@ -0,0 +1,141 @@
+use core::fmt;
+
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Weighted close price (WCLPRICE).
+///
+/// The average of the high, low and close prices of a bar, with the close counted twice.
+/// Together with the [median price](struct.MedianPrice.html) and the
+/// [typical price](struct.TypicalPrice.html), it is one of the usual price transforms. It has
+/// no state, so it is ready from the first input.
+///
+/// # Formula
+///
+/// WCLPRICE = (H<sub>t</sub> + L<sub>t</sub> + 2 × C<sub>t</sub>) / 4
+///
+/// Where:
+///
+/// * _H<sub>t</sub>_, _L<sub>t</sub>_, _C<sub>t</sub>_ - high, low and close prices of the current period
+///
+/// When a single `f64` value is given, it is the high, the low and the close, and is returned
+/// as is.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{SimpleMovingAverage as Sma, WeightedClose};
+/// use ta::{DataItem, Next, Pipe};
+///
+/// let mut weighted_close = WeightedClose::new();
+///
+/// let di = DataItem::builder()
+///             .high(12.0)
+///             .low(9.0)
+///             .close(10.5)
+///             .open(11.0)
+///             .volume(1000.0)
+///             .build().unwrap();
+///
+/// assert_eq!(weighted_close.next(&di), 10.5);
+///
+/// // moving average of the weighted close price
+/// let mut sma = WeightedClose::new().pipe(Sma::new(14).unwrap());
+/// assert_eq!(sma.next(&di), 10.5);
+/// ```
+#[doc(alias = "WCLPRICE")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeightedClose {}
+
+impl WeightedClose {
+	pub fn new() -> Self {
+		Self {}
+	}
+}
+
+impl Period for WeightedClose {
+	fn period(&self) -> usize {
+		1
+	}
+}
+
+impl Ready for WeightedClose {
+	fn is_ready(&self) -> bool {
+		true
+	}
+}
+
+impl Next<f64> for WeightedClose {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		input
+	}
+}
+
+impl<T: High + Low + Close> Next<&T> for WeightedClose {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		(input.high() + input.low() + 2.0 * input.close()) / 4.0
+	}
+}
+
+impl Reset for WeightedClose {
+	fn reset(&mut self) {}
+}
+
+impl Default for WeightedClose {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl fmt::Display for WeightedClose {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "WCLPRICE")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	test_indicator!(WeightedClose);
+
+	#[test]
+	fn test_next_with_f64() {
+		let mut weighted_close = WeightedClose::new();
+		assert_eq!(weighted_close.next(3.5), 3.5);
+		assert_eq!(weighted_close.next(-1.0), -1.0);
+	}
+
+	#[test]
+	fn test_next_with_bars() {
+		let mut weighted_close = WeightedClose::new();
+		let bar = Bar::new().high(12).low(9).close(9);
+		assert_eq!(weighted_close.next(&bar), 9.75);
+		let bar = Bar::new().high(5).low(5).close(5);
+		assert_eq!(weighted_close.next(&bar), 5.0);
+		let bar = Bar::new().high(4).low(1).close(2);
+		assert_eq!(weighted_close.next(&bar), 2.25);
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut weighted_close = WeightedClose::new();
+		let bar = Bar::new().high(4).low(2).close(3);
+		weighted_close.next(&bar);
+		weighted_close.reset();
+		assert_eq!(weighted_close, WeightedClose::default());
+		assert_eq!(weighted_close.next(&bar), 3.0);
+	}
+
+	#[test]
+	fn test_display() {
+		assert_eq!(format!("{}", WeightedClose::new()), "WCLPRICE");
+	}
+}
@@ -1,7 +1,9 @@
-use std::fmt;
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
+use crate::{Close, Next, Period, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -20,6 +22,12 @@ use serde::{Deserialize, Serialize};
 /// * _n_ - is the period.
 /// * _p<sub>M</sub>_ - is the input value at a time period t.
 ///
+/// The weighted sum is updated incrementally, so `next` is O(1) regardless of the period.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
+///
 /// # Example
 ///
 /// ```
@@ -39,7 +47,7 @@ use serde::{Deserialize, Serialize};
 ///
 #[doc(alias = "WMA")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct WeightedMovingAverage {
 	period: usize,
 	index: usize,
@@ -73,6 +81,12 @@ impl Period for WeightedMovingAverage {
 	}
 }
 
+impl Ready for WeightedMovingAverage {
+	fn is_ready(&self) -> bool {
+		self.count == self.period
+	}
+}
+
 impl Next<f64> for WeightedMovingAverage {
 	type Output = f64;
 
@@ -160,6 +174,22 @@ mod tests {
 		assert_eq!(wma.next(&bar2), 4.0);
 	}
 
+	#[test]
+	fn test_next_matches_dot_product() {
+		let period = 4;
+		let inputs = [3.0, 8.5, 1.25, 7.0, 6.0, 2.5, 9.75, 4.0, 5.5, 0.5];
+		let mut wma = WeightedMovingAverage::new(period).unwrap();
+
+		for (i, &input) in inputs.iter().enumerate() {
+			let window = &inputs[(i + 1).saturating_sub(period)..=i];
+			let weights = (1..=window.len()).map(|w| w as f64);
+			let dot: f64 = window.iter().zip(weights).map(|(p, w)| p * w).sum();
+			let norm = (window.len() * (window.len() + 1) / 2) as f64;
+
+			assert_eq!(round(wma.next(input)), round(dot / norm));
+		}
+	}
+
 	#[test]
 	fn test_reset() {
 		let mut wma = WeightedMovingAverage::new(5).unwrap();
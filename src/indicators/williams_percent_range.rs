@@ -0,0 +1,187 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{Maximum, Minimum};
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Williams %R.
+///
+/// A momentum indicator showing the level of the close relative to the highest high
+/// over the last _n_ periods. It ranges from -100 to 0.
+///
+/// # Formula
+///
+/// %R = (H<sub>n</sub> - C<sub>t</sub>) / (H<sub>n</sub> - L<sub>n</sub>) × -100
+///
+/// Where:
+///
+/// * C<sub>t</sub> - close price of the current period
+/// * L<sub>n</sub> - lowest price for the last _n_ periods
+/// * H<sub>n</sub> - highest price for the last _n_ periods
+///
+/// If the highest and the lowest prices are equal (flat market), -50 is returned.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::WilliamsPercentRange;
+/// use ta::Next;
+///
+/// let mut wr = WilliamsPercentRange::new(3).unwrap();
+/// assert_eq!(wr.next(10.0), -50.0);
+/// assert_eq!(wr.next(20.0), 0.0);
+/// assert_eq!(wr.next(15.0), -50.0);
+/// assert_eq!(wr.next(12.0), -100.0);
+/// ```
+///
+/// # Links
+///
+/// * [Williams %R, Wikipedia](https://en.wikipedia.org/wiki/Williams_%25R)
+#[doc(alias = "WILLR")]
+#[doc(alias = "%R")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct WilliamsPercentRange {
+	period: usize,
+	minimum: Minimum,
+	maximum: Maximum,
+}
+
+impl WilliamsPercentRange {
+	pub fn new(period: usize) -> Result<Self> {
+		Ok(Self {
+			period,
+			minimum: Minimum::new(period)?,
+			maximum: Maximum::new(period)?,
+		})
+	}
+
+	fn calc(&mut self, high: f64, low: f64, close: f64) -> f64 {
+		let highest = self.maximum.next(high);
+		let lowest = self.minimum.next(low);
+
+		if highest == lowest {
+			// To avoid division by zero, return the middle of the range
+			-50.0
+		} else {
+			(highest - close) / (highest - lowest) * -100.0
+		}
+	}
+}
+
+impl Period for WilliamsPercentRange {
+	fn period(&self) -> usize {
+		self.period
+	}
+}
+
+impl Ready for WilliamsPercentRange {
+	fn is_ready(&self) -> bool {
+		self.minimum.is_ready()
+	}
+}
+
+impl Next<f64> for WilliamsPercentRange {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		self.calc(input, input, input)
+	}
+}
+
+impl<T: High + Low + Close> Next<&T> for WilliamsPercentRange {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.calc(input.high(), input.low(), input.close())
+	}
+}
+
+impl Reset for WilliamsPercentRange {
+	fn reset(&mut self) {
+		self.minimum.reset();
+		self.maximum.reset();
+	}
+}
+
+impl Default for WilliamsPercentRange {
+	fn default() -> Self {
+		Self::new(14).unwrap()
+	}
+}
+
+impl fmt::Display for WilliamsPercentRange {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "WILLR({})", self.period)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	test_indicator!(WilliamsPercentRange);
+
+	#[test]
+	fn test_new() {
+		assert!(WilliamsPercentRange::new(0).is_err());
+		assert!(WilliamsPercentRange::new(1).is_ok());
+	}
+
+	#[test]
+	fn test_next_with_bars() {
+		let test_data = vec![
+			// high, low , close, expected
+			(20.0, 20.0, 20.0, -50.0), // min = 20, max = 20
+			(30.0, 10.0, 25.0, -25.0), // min = 10, max = 30
+			(40.0, 20.0, 16.0, -80.0), // min = 10, max = 40
+			(35.0, 15.0, 19.0, -70.0), // min = 10, max = 40
+			(30.0, 20.0, 25.0, -60.0), // min = 15, max = 40
+			(35.0, 25.0, 30.0, -25.0), // min = 15, max = 35
+		];
+
+		let mut wr = WilliamsPercentRange::new(3).unwrap();
+
+		for (high, low, close, expected) in test_data {
+			let input_bar = Bar::new().high(high).low(low).close(close);
+			assert_eq!(wr.next(&input_bar), expected);
+		}
+	}
+
+	#[test]
+	fn test_flat_market() {
+		let mut wr = WilliamsPercentRange::new(2).unwrap();
+		let bar = Bar::new().high(5).low(5).close(5);
+		assert_eq!(wr.next(&bar), -50.0);
+		assert_eq!(wr.next(&bar), -50.0);
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut wr = WilliamsPercentRange::new(3).unwrap();
+		assert_eq!(wr.next(10.0), -50.0);
+		assert_eq!(wr.next(20.0), 0.0);
+
+		wr.reset();
+		assert_eq!(wr.next(20.0), -50.0);
+		assert_eq!(wr.next(10.0), -100.0);
+	}
+
+	#[test]
+	fn test_default() {
+		WilliamsPercentRange::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let indicator = WilliamsPercentRange::new(21).unwrap();
+		assert_eq!(format!("{}", indicator), "WILLR(21)");
+	}
+}
@@ -0,0 +1,227 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Zero lag exponential moving average (ZLEMA).
+///
+/// An EMA of the price adjusted by its momentum over the last _lag_ periods, which removes
+/// most of the lag of a plain EMA.
+///
+/// # Formula
+///
+/// ZLEMA = EMA(p<sub>t</sub> + (p<sub>t</sub> - p<sub>t-lag</sub>), n)
+///
+/// Where:
+///
+/// * _lag_ = (n - 1) / 2, rounded down
+/// * _p<sub>t</sub>_ - input value at a time period t
+///
+/// Until _lag_ values have been seen, the oldest available value is used as p<sub>t-lag</sub>.
+/// The ZLEMA is ready once _n + lag_ values have been consumed.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ZeroLagExponentialMovingAverage;
+/// use ta::Next;
+///
+/// let mut zlema = ZeroLagExponentialMovingAverage::new(5).unwrap();
+/// assert_eq!(zlema.next(2.0), 2.0);
+/// assert_eq!(zlema.next(5.0), 4.0);
+/// ```
+///
+/// # Links
+///
+/// * [Zero lag exponential moving average, Wikipedia](https://en.wikipedia.org/wiki/Zero_lag_exponential_moving_average)
+#[doc(alias = "ZLEMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZeroLagExponentialMovingAverage {
+	period: usize,
+	lag: usize,
+	index: usize,
+	count: usize,
+	ema: Ema,
+	deque: Box<[f64]>,
+}
+
+impl ZeroLagExponentialMovingAverage {
+	pub fn new(period: usize) -> Result<Self> {
+		let ema = Ema::new(period)?;
+		let lag = (period - 1) / 2;
+
+		Ok(Self {
+			period,
+			lag,
+			index: 0,
+			count: 0,
+			ema,
+			deque: vec![0.0; lag].into_boxed_slice(),
+		})
+	}
+}
+
+impl Period for ZeroLagExponentialMovingAverage {
+	fn period(&self) -> usize {
+		self.period
+	}
+}
+
+impl Ready for ZeroLagExponentialMovingAverage {
+	fn is_ready(&self) -> bool {
+		self.count == self.period + self.lag
+	}
+}
+
+impl Next<f64> for ZeroLagExponentialMovingAverage {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		let lagged = if self.lag == 0 {
+			input
+		} else {
+			let lagged = if self.count >= self.lag {
+				self.deque[self.index]
+			} else if self.count == 0 {
+				input
+			} else {
+				self.deque[0]
+			};
+
+			self.deque[self.index] = input;
+			self.index = if self.index + 1 < self.lag {
+				self.index + 1
+			} else {
+				0
+			};
+			lagged
+		};
+
+		if self.count < self.period + self.lag {
+			self.count += 1;
+		}
+
+		self.ema.next(2.0 * input - lagged)
+	}
+}
+
+impl<T: Close> Next<&T> for ZeroLagExponentialMovingAverage {
+	type Output = f64;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl Reset for ZeroLagExponentialMovingAverage {
+	fn reset(&mut self) {
+		self.index = 0;
+		self.count = 0;
+		self.ema.reset();
+		for i in 0..self.lag {
+			self.deque[i] = 0.0;
+		}
+	}
+}
+
+impl Default for ZeroLagExponentialMovingAverage {
+	fn default() -> Self {
+		Self::new(9).unwrap()
+	}
+}
+
+impl fmt::Display for ZeroLagExponentialMovingAverage {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "ZLEMA({})", self.period)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	type Zlema = ZeroLagExponentialMovingAverage;
+
+	test_indicator!(Zlema);
+
+	#[test]
+	fn test_new() {
+		assert!(Zlema::new(0).is_err());
+		assert!(Zlema::new(1).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let mut zlema = Zlema::new(5).unwrap();
+
+		assert_eq!(zlema.next(2.0), 2.0);
+		assert_eq!(round(zlema.next(4.0)), 3.333);
+		assert_eq!(round(zlema.next(6.0)), 5.556);
+		assert_eq!(round(zlema.next(8.0)), 7.704);
+		assert_eq!(round(zlema.next(10.0)), 9.802);
+		assert_eq!(round(zlema.next(9.0)), 9.868);
+
+		let mut zlema = Zlema::new(5).unwrap();
+		let bar1 = Bar::new().close(2);
+		let bar2 = Bar::new().close(4);
+		assert_eq!(zlema.next(&bar1), 2.0);
+		assert_eq!(round(zlema.next(&bar2)), 3.333);
+	}
+
+	#[test]
+	fn test_next_without_lag() {
+		// ZLEMA(2) has no lag, so it is an EMA(2)
+		let mut zlema = Zlema::new(2).unwrap();
+		let mut ema = Ema::new(2).unwrap();
+		for input in [2.0, 5.0, 1.0, 6.25] {
+			assert_eq!(zlema.next(input), ema.next(input));
+		}
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut zlema = Zlema::new(5).unwrap();
+		for _ in 0..6 {
+			zlema.next(1.0);
+			assert!(!zlema.is_ready());
+		}
+		zlema.next(1.0);
+		assert!(zlema.is_ready());
+
+		zlema.reset();
+		assert!(!zlema.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut zlema = Zlema::new(5).unwrap();
+		assert_eq!(zlema.next(2.0), 2.0);
+		assert_eq!(round(zlema.next(4.0)), 3.333);
+
+		zlema.reset();
+		assert_eq!(zlema.next(2.0), 2.0);
+		assert_eq!(round(zlema.next(4.0)), 3.333);
+	}
+
+	#[test]
+	fn test_default() {
+		Zlema::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let indicator = Zlema::new(20).unwrap();
+		assert_eq!(format!("{}", indicator), "ZLEMA(20)");
+	}
+}
@@ -0,0 +1,288 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Zigzag.
+///
+/// Filters out the price swings smaller than a deviation and keeps only the significant
+/// highs and lows. A high becomes a pivot once the price has fallen by the deviation from
+/// it, and a low becomes a pivot once the price has risen by the deviation from it.
+///
+/// The output is `Some(price)` on the period which confirms a pivot, with the price of that
+/// pivot, and `None` otherwise. The pivot itself was reached on an earlier period. Until it
+/// is confirmed, the extreme of the current swing is revised every time the price goes
+/// further in the same direction; it is available through
+/// [`extreme`](struct.Zigzag.html#method.extreme).
+///
+/// # Parameters
+///
+/// * _deviation_ - minimal swing (number greater than 0). Default is 5.
+/// * _use_percentage_ - whether the deviation is a percentage of the extreme price or an
+///   absolute price difference. Default is true.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Zigzag;
+/// use ta::Next;
+///
+/// let mut zigzag = Zigzag::new(10.0, true).unwrap();
+/// assert_eq!(zigzag.next(100.0), None);
+/// assert_eq!(zigzag.next(115.0), Some(100.0));
+/// assert_eq!(zigzag.next(120.0), None);
+/// assert_eq!(zigzag.next(107.0), Some(120.0));
+/// ```
+///
+/// # Links
+///
+/// * [Zig zag indicator, Investopedia](https://www.investopedia.com/terms/z/zig_zag_indicator.asp)
+#[doc(alias = "ZIGZAG")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Zigzag {
+	deviation: f64,
+	use_percentage: bool,
+	is_new: bool,
+	// None until the first pivot, then whether the current swing goes up
+	is_rising: Option<bool>,
+	high: f64,
+	low: f64,
+}
+
+impl Zigzag {
+	pub fn new(deviation: f64, use_percentage: bool) -> Result<Self> {
+		if deviation.is_nan() || deviation <= 0.0 {
+			return Err(TaError::InvalidParameter);
+		}
+
+		Ok(Self {
+			deviation,
+			use_percentage,
+			is_new: true,
+			is_rising: None,
+			high: 0.0,
+			low: 0.0,
+		})
+	}
+
+	/// Returns the unconfirmed extreme of the current swing: the highest price since the
+	/// last pivot low, or the lowest price since the last pivot high.
+	pub fn extreme(&self) -> Option<f64> {
+		match self.is_rising {
+			Some(true) => Some(self.high),
+			Some(false) => Some(self.low),
+			None => None,
+		}
+	}
+
+	fn has_fallen(&self, price: f64) -> bool {
+		if self.use_percentage {
+			price <= self.high * (1.0 - self.deviation / 100.0)
+		} else {
+			price <= self.high - self.deviation
+		}
+	}
+
+	fn has_risen(&self, price: f64) -> bool {
+		if self.use_percentage {
+			price >= self.low * (1.0 + self.deviation / 100.0)
+		} else {
+			price >= self.low + self.deviation
+		}
+	}
+}
+
+impl Period for Zigzag {
+	fn period(&self) -> usize {
+		1
+	}
+}
+
+impl Ready for Zigzag {
+	fn is_ready(&self) -> bool {
+		self.is_rising.is_some()
+	}
+}
+
+impl Next<f64> for Zigzag {
+	type Output = Option<f64>;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		if self.is_new {
+			self.is_new = false;
+			self.high = input;
+			self.low = input;
+			return None;
+		}
+
+		match self.is_rising {
+			Some(true) if input > self.high => {
+				self.high = input;
+				None
+			}
+			Some(false) if input < self.low => {
+				self.low = input;
+				None
+			}
+			Some(true) | None if self.has_fallen(input) => {
+				self.is_rising = Some(false);
+				self.low = input;
+				Some(self.high)
+			}
+			Some(false) | None if self.has_risen(input) => {
+				self.is_rising = Some(true);
+				self.high = input;
+				Some(self.low)
+			}
+			None => {
+				// no swing yet, track both extremes since the first price
+				self.high = self.high.max(input);
+				self.low = self.low.min(input);
+				None
+			}
+			_ => None,
+		}
+	}
+}
+
+impl<T: Close> Next<&T> for Zigzag {
+	type Output = Option<f64>;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl Reset for Zigzag {
+	fn reset(&mut self) {
+		self.is_new = true;
+		self.is_rising = None;
+		self.high = 0.0;
+		self.low = 0.0;
+	}
+}
+
+impl Default for Zigzag {
+	fn default() -> Self {
+		Self::new(5.0, true).unwrap()
+	}
+}
+
+impl fmt::Display for Zigzag {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if self.use_percentage {
+			write!(f, "ZIGZAG({}%)", self.deviation)
+		} else {
+			write!(f, "ZIGZAG({})", self.deviation)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helper::*;
+
+	test_indicator!(Zigzag);
+
+	#[test]
+	fn test_new() {
+		assert!(Zigzag::new(0.0, true).is_err());
+		assert!(Zigzag::new(-1.0, false).is_err());
+		assert!(Zigzag::new(f64::NAN, true).is_err());
+		assert!(Zigzag::new(5.0, true).is_ok());
+		assert!(Zigzag::new(0.5, false).is_ok());
+	}
+
+	#[test]
+	fn test_next_percentage() {
+		let test_data = vec![
+			(100.0, None),
+			(105.0, None),
+			(111.0, Some(100.0)),
+			(104.0, None),
+			(99.0, Some(111.0)),
+			(95.0, None),
+			(97.0, None),
+			(104.0, None),
+			(105.0, Some(95.0)),
+			(120.0, None),
+			(130.0, None),
+			(118.0, None),
+			(116.0, Some(130.0)),
+		];
+
+		let mut zigzag = Zigzag::new(10.0, true).unwrap();
+
+		for (input, expected) in test_data {
+			assert_eq!(zigzag.next(input), expected);
+		}
+	}
+
+	#[test]
+	fn test_next_absolute() {
+		let mut zigzag = Zigzag::new(2.0, false).unwrap();
+		assert_eq!(zigzag.next(10.0), None);
+		assert_eq!(zigzag.next(9.0), None);
+		assert_eq!(zigzag.next(8.0), Some(10.0));
+		assert_eq!(zigzag.next(7.0), None);
+		assert_eq!(zigzag.next(8.5), None);
+		assert_eq!(zigzag.next(9.0), Some(7.0));
+	}
+
+	#[test]
+	fn test_extreme() {
+		let mut zigzag = Zigzag::new(10.0, true).unwrap();
+		zigzag.next(100.0);
+		assert_eq!(zigzag.extreme(), None);
+		zigzag.next(111.0);
+		assert_eq!(zigzag.extreme(), Some(111.0));
+		zigzag.next(120.0);
+		assert_eq!(zigzag.extreme(), Some(120.0));
+		zigzag.next(115.0);
+		assert_eq!(zigzag.extreme(), Some(120.0));
+		zigzag.next(100.0);
+		assert_eq!(zigzag.extreme(), Some(100.0));
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut zigzag = Zigzag::new(10.0, true).unwrap();
+		zigzag.next(100.0);
+		zigzag.next(105.0);
+		assert!(!zigzag.is_ready());
+		zigzag.next(111.0);
+		assert!(zigzag.is_ready());
+
+		zigzag.reset();
+		assert!(!zigzag.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut zigzag = Zigzag::new(10.0, true).unwrap();
+		zigzag.next(100.0);
+		assert_eq!(zigzag.next(111.0), Some(100.0));
+
+		zigzag.reset();
+		assert_eq!(zigzag.next(110.0), None);
+		assert_eq!(zigzag.next(98.0), Some(110.0));
+	}
+
+	#[test]
+	fn test_default() {
+		Zigzag::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let zigzag = Zigzag::new(5.0, true).unwrap();
+		assert_eq!(format!("{}", zigzag), "ZIGZAG(5%)");
+
+		let zigzag = Zigzag::new(1.5, false).unwrap();
+		assert_eq!(format!("{}", zigzag), "ZIGZAG(1.5)");
+	}
+}
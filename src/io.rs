@@ -0,0 +1,478 @@
+//! Reading bars from CSV files and writing indicator outputs back to CSV.
+//!
+//! Available with the `csv` feature.
+//!
+//! # Example
+//!
+//! ```
+//! use ta::indicators::SimpleMovingAverage;
+//! use ta::io::{CsvBarReader, CsvBarWriter, CsvConfig};
+//! use ta::{Close, Next};
+//!
+//! let data = "\
+//! date,open,high,low,close,volume
+//! 2017-01-03,10.0,12.0,9.0,11.0,1000
+//! 2017-01-04,11.0,14.0,10.0,13.0,1500
+//! ";
+//!
+//! let mut sma = SimpleMovingAverage::new(2).unwrap();
+//! let mut writer = CsvBarWriter::new(vec![]);
+//! writer.write_header(&["close", "sma"]).unwrap();
+//!
+//! for bar in CsvBarReader::new(data.as_bytes(), CsvConfig::default()) {
+//!     let bar = bar.unwrap();
+//!     writer.write_row(vec![bar.close(), sma.next(&bar)]).unwrap();
+//! }
+//!
+//! let output = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+//! assert_eq!(output, "close,sma\n11.0,11.0\n13.0,12.0\n");
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+use crate::errors::TaError;
+use crate::DataItem;
+
+/// Column of a CSV file, referenced either by its name in the header or by its position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CsvColumn {
+	Name(String),
+	Index(usize),
+}
+
+impl From<&str> for CsvColumn {
+	fn from(name: &str) -> Self {
+		CsvColumn::Name(name.to_string())
+	}
+}
+
+impl From<usize> for CsvColumn {
+	fn from(index: usize) -> Self {
+		CsvColumn::Index(index)
+	}
+}
+
+impl fmt::Display for CsvColumn {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			CsvColumn::Name(name) => write!(f, "{}", name),
+			CsvColumn::Index(index) => write!(f, "#{}", index),
+		}
+	}
+}
+
+/// Describes where the prices and the volume of a bar are found in a CSV file.
+///
+/// The default configuration expects a header with the `open`, `high`, `low`, `close` and
+/// `volume` columns. Columns referenced by name require a header.
+///
+/// # Example
+///
+/// ```
+/// use ta::io::CsvConfig;
+///
+/// // a headerless file laid out as: date, open, high, low, close, volume
+/// let config = CsvConfig::default()
+///     .has_headers(false)
+///     .open(1)
+///     .high(2)
+///     .low(3)
+///     .close(4)
+///     .volume(5);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvConfig {
+	open: CsvColumn,
+	high: CsvColumn,
+	low: CsvColumn,
+	close: CsvColumn,
+	volume: CsvColumn,
+	has_headers: bool,
+}
+
+impl CsvConfig {
+	pub fn open(mut self, column: impl Into<CsvColumn>) -> Self {
+		self.open = column.into();
+		self
+	}
+
+	pub fn high(mut self, column: impl Into<CsvColumn>) -> Self {
+		self.high = column.into();
+		self
+	}
+
+	pub fn low(mut self, column: impl Into<CsvColumn>) -> Self {
+		self.low = column.into();
+		self
+	}
+
+	pub fn close(mut self, column: impl Into<CsvColumn>) -> Self {
+		self.close = column.into();
+		self
+	}
+
+	pub fn volume(mut self, column: impl Into<CsvColumn>) -> Self {
+		self.volume = column.into();
+		self
+	}
+
+	/// Sets whether the first row is a header. Default is `true`.
+	pub fn has_headers(mut self, has_headers: bool) -> Self {
+		self.has_headers = has_headers;
+		self
+	}
+
+	fn columns(&self) -> [&CsvColumn; 5] {
+		[&self.open, &self.high, &self.low, &self.close, &self.volume]
+	}
+}
+
+impl Default for CsvConfig {
+	fn default() -> Self {
+		Self {
+			open: "open".into(),
+			high: "high".into(),
+			low: "low".into(),
+			close: "close".into(),
+			volume: "volume".into(),
+			has_headers: true,
+		}
+	}
+}
+
+/// Error returned while reading or writing CSV data.
+#[derive(Debug)]
+pub enum CsvError {
+	/// The CSV data could not be read or written.
+	Csv(csv::Error),
+	/// The column is not part of the header, or the header is missing.
+	MissingColumn(CsvColumn),
+	/// A field is not a number.
+	InvalidNumber(String),
+	/// The fields do not make a valid bar, e.g. the low price is above the high price.
+	DataItem(TaError),
+}
+
+impl fmt::Display for CsvError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			CsvError::Csv(err) => write!(f, "{}", err),
+			CsvError::MissingColumn(column) => write!(f, "missing column {}", column),
+			CsvError::InvalidNumber(field) => write!(f, "invalid number {:?}", field),
+			CsvError::DataItem(err) => write!(f, "{}", err),
+		}
+	}
+}
+
+impl Error for CsvError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			CsvError::Csv(err) => Some(err),
+			CsvError::MissingColumn(_) => None,
+			CsvError::InvalidNumber(_) => None,
+			CsvError::DataItem(err) => Some(err),
+		}
+	}
+}
+
+impl From<csv::Error> for CsvError {
+	fn from(err: csv::Error) -> Self {
+		CsvError::Csv(err)
+	}
+}
+
+impl From<io::Error> for CsvError {
+	fn from(err: io::Error) -> Self {
+		CsvError::Csv(err.into())
+	}
+}
+
+impl From<TaError> for CsvError {
+	fn from(err: TaError) -> Self {
+		CsvError::DataItem(err)
+	}
+}
+
+/// An iterator over the bars of a CSV file.
+///
+/// Columns which are not part of the [configuration](struct.CsvConfig.html), e.g. dates, are
+/// ignored. The iterator stops after an error about the header, while an invalid row only
+/// yields an error for that row.
+#[derive(Debug)]
+pub struct CsvBarReader<R> {
+	reader: csv::Reader<R>,
+	config: CsvConfig,
+	indices: Option<[usize; 5]>,
+	done: bool,
+}
+
+impl<R: io::Read> CsvBarReader<R> {
+	pub fn new(reader: R, config: CsvConfig) -> Self {
+		Self {
+			reader: csv::ReaderBuilder::new()
+				.has_headers(config.has_headers)
+				.from_reader(reader),
+			config,
+			indices: None,
+			done: false,
+		}
+	}
+
+	fn resolve_indices(&mut self) -> Result<[usize; 5], CsvError> {
+		let headers = if self.config.has_headers {
+			Some(self.reader.headers()?.clone())
+		} else {
+			None
+		};
+
+		let mut indices = [0; 5];
+		for (index, column) in indices.iter_mut().zip(self.config.columns()) {
+			*index = match (column, &headers) {
+				(CsvColumn::Index(i), _) => *i,
+				(CsvColumn::Name(name), Some(headers)) => headers
+					.iter()
+					.position(|header| header.trim() == name)
+					.ok_or_else(|| CsvError::MissingColumn(column.clone()))?,
+				(CsvColumn::Name(_), None) => return Err(CsvError::MissingColumn(column.clone())),
+			};
+		}
+		Ok(indices)
+	}
+
+	fn read_bar(
+		&self,
+		record: &csv::StringRecord,
+		indices: [usize; 5],
+	) -> Result<DataItem, CsvError> {
+		let mut values = [0.0; 5];
+		for ((value, &index), column) in values.iter_mut().zip(&indices).zip(self.config.columns())
+		{
+			let field = record
+				.get(index)
+				.ok_or_else(|| CsvError::MissingColumn(column.clone()))?;
+			*value = field
+				.trim()
+				.parse()
+				.map_err(|_| CsvError::InvalidNumber(field.to_string()))?;
+		}
+
+		let [open, high, low, close, volume] = values;
+		Ok(DataItem::builder()
+			.open(open)
+			.high(high)
+			.low(low)
+			.close(close)
+			.volume(volume)
+			.build()?)
+	}
+}
+
+impl<R: io::Read> Iterator for CsvBarReader<R> {
+	type Item = Result<DataItem, CsvError>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+
+		let indices = match self.indices {
+			Some(indices) => indices,
+			None => match self.resolve_indices() {
+				Ok(indices) => *self.indices.insert(indices),
+				Err(err) => {
+					self.done = true;
+					return Some(Err(err));
+				}
+			},
+		};
+
+		let mut record = csv::StringRecord::new();
+		match self.reader.read_record(&mut record) {
+			Ok(true) => Some(self.read_bar(&record, indices)),
+			Ok(false) => {
+				self.done = true;
+				None
+			}
+			Err(err) => Some(Err(err.into())),
+		}
+	}
+}
+
+/// Writes indicator outputs as CSV rows.
+///
+/// Multi-value outputs can be written by converting them into tuples first, e.g.
+/// `let (upper, average, lower) = bb.next(price).into();`.
+#[derive(Debug)]
+pub struct CsvBarWriter<W: io::Write> {
+	writer: csv::Writer<W>,
+}
+
+impl<W: io::Write> CsvBarWriter<W> {
+	pub fn new(writer: W) -> Self {
+		Self {
+			writer: csv::Writer::from_writer(writer),
+		}
+	}
+
+	/// Writes a row of column names.
+	pub fn write_header<I, S>(&mut self, names: I) -> Result<(), CsvError>
+	where
+		I: IntoIterator<Item = S>,
+		S: AsRef<str>,
+	{
+		self.writer
+			.write_record(names.into_iter().map(|name| name.as_ref().to_string()))?;
+		Ok(())
+	}
+
+	/// Writes a row of values, e.g. the input price followed by the indicator output.
+	pub fn write_row<I: IntoIterator<Item = f64>>(&mut self, values: I) -> Result<(), CsvError> {
+		self.writer
+			.write_record(values.into_iter().map(|value| format!("{:?}", value)))?;
+		Ok(())
+	}
+
+	pub fn flush(&mut self) -> Result<(), CsvError> {
+		self.writer.flush()?;
+		Ok(())
+	}
+
+	/// Flushes the buffered rows and returns the underlying writer.
+	pub fn into_inner(self) -> Result<W, CsvError> {
+		self.writer
+			.into_inner()
+			.map_err(|err| err.into_error().into())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::indicators::SimpleMovingAverage;
+	use crate::{Close, High, Low, Next, Open, Volume};
+
+	const DATA: &str = "\
+date,open,high,low,close,volume
+2017-01-03,757.92,758.76,747.70,753.67,3521100
+2017-01-04,758.39,759.68,754.20,757.18,2510500
+2017-01-05,761.55,782.40,760.26,780.45,5830100
+";
+
+	fn read_all<R: io::Read>(reader: CsvBarReader<R>) -> Vec<DataItem> {
+		reader.map(|bar| bar.unwrap()).collect()
+	}
+
+	#[test]
+	fn test_reader() {
+		let bars = read_all(CsvBarReader::new(DATA.as_bytes(), CsvConfig::default()));
+		assert_eq!(bars.len(), 3);
+		assert_eq!(bars[0].open(), 757.92);
+		assert_eq!(bars[0].high(), 758.76);
+		assert_eq!(bars[0].low(), 747.70);
+		assert_eq!(bars[0].close(), 753.67);
+		assert_eq!(bars[0].volume(), 3521100.0);
+		assert_eq!(bars[2].close(), 780.45);
+	}
+
+	#[test]
+	fn test_reader_column_names() {
+		let data = "Volume,Close,Low,High,Open\n100,2.5,1,3,2\n";
+		let config = CsvConfig::default()
+			.open("Open")
+			.high("High")
+			.low("Low")
+			.close("Close")
+			.volume("Volume");
+		let bars = read_all(CsvBarReader::new(data.as_bytes(), config));
+		assert_eq!(bars.len(), 1);
+		assert_eq!(bars[0].open(), 2.0);
+		assert_eq!(bars[0].close(), 2.5);
+		assert_eq!(bars[0].volume(), 100.0);
+	}
+
+	#[test]
+	fn test_reader_without_headers() {
+		let data = "2017-01-03,2,3,1,2.5,100\n2017-01-04,2.5,4,2,3,200\n";
+		let config = CsvConfig::default()
+			.has_headers(false)
+			.open(1)
+			.high(2)
+			.low(3)
+			.close(4)
+			.volume(5);
+		let bars = read_all(CsvBarReader::new(data.as_bytes(), config));
+		assert_eq!(bars.len(), 2);
+		assert_eq!(bars[1].high(), 4.0);
+		assert_eq!(bars[1].volume(), 200.0);
+	}
+
+	#[test]
+	fn test_reader_missing_column() {
+		let data = "open,high,low,close\n2,3,1,2.5\n";
+		let mut reader = CsvBarReader::new(data.as_bytes(), CsvConfig::default());
+		match reader.next() {
+			Some(Err(CsvError::MissingColumn(column))) => assert_eq!(column, "volume".into()),
+			other => panic!("unexpected {:?}", other),
+		}
+		assert!(reader.next().is_none());
+
+		let config = CsvConfig::default().has_headers(false);
+		let mut reader = CsvBarReader::new(data.as_bytes(), config);
+		assert!(matches!(
+			reader.next(),
+			Some(Err(CsvError::MissingColumn(_)))
+		));
+	}
+
+	#[test]
+	fn test_reader_invalid_rows() {
+		let data = "open,high,low,close,volume\n2,3,1,abc,100\n2,1,3,2,100\n2,3,1,2.5,100\n";
+		let mut reader = CsvBarReader::new(data.as_bytes(), CsvConfig::default());
+		match reader.next() {
+			Some(Err(CsvError::InvalidNumber(field))) => assert_eq!(field, "abc"),
+			other => panic!("unexpected {:?}", other),
+		}
+		assert!(matches!(
+			reader.next(),
+			Some(Err(CsvError::DataItem(TaError::DataItemInvalid)))
+		));
+		assert_eq!(reader.next().unwrap().unwrap().close(), 2.5);
+		assert!(reader.next().is_none());
+	}
+
+	#[test]
+	fn test_pipeline() {
+		let mut sma = SimpleMovingAverage::new(2).unwrap();
+		let mut writer = CsvBarWriter::new(vec![]);
+		writer.write_header(["close", "sma"]).unwrap();
+
+		for bar in CsvBarReader::new(DATA.as_bytes(), CsvConfig::default()) {
+			let bar = bar.unwrap();
+			writer.write_row([bar.close(), sma.next(&bar)]).unwrap();
+		}
+
+		let output = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+		assert_eq!(
+			output,
+			"close,sma\n753.67,753.67\n757.18,755.425\n780.45,768.815\n"
+		);
+	}
+
+	#[test]
+	fn test_error_display() {
+		assert_eq!(
+			format!("{}", CsvError::MissingColumn("close".into())),
+			"missing column close"
+		);
+		assert_eq!(
+			format!("{}", CsvError::MissingColumn(3.into())),
+			"missing column #3"
+		);
+		assert_eq!(
+			format!("{}", CsvError::InvalidNumber("abc".to_string())),
+			"invalid number \"abc\""
+		);
+	}
+}
@@ -0,0 +1,107 @@
+use crate::Next;
+
+/// Extends iterators with the ability to drive an indicator.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Minimum;
+/// use ta::IndicatorExt;
+///
+/// let prices = vec![4.0, 2.0, 3.0, 5.0, 6.0];
+/// let mins: Vec<f64> = prices
+///     .iter()
+///     .copied()
+///     .indicator(Minimum::new(3).unwrap())
+///     .collect();
+///
+/// assert_eq!(mins, vec![4.0, 2.0, 2.0, 2.0, 3.0]);
+/// ```
+pub trait IndicatorExt: Iterator + Sized {
+	/// Feeds every item of the iterator into the indicator and yields its outputs.
+	fn indicator<I: Next<Self::Item>>(self, indicator: I) -> IndicatorIter<Self, I> {
+		IndicatorIter {
+			source: self,
+			indicator,
+		}
+	}
+}
+
+impl<S: Iterator> IndicatorExt for S {}
+
+/// An iterator that yields the outputs of an indicator fed by another iterator.
+///
+/// This struct is created by the [indicator](trait.IndicatorExt.html#method.indicator) method on
+/// [IndicatorExt](trait.IndicatorExt.html).
+#[derive(Debug, Clone)]
+pub struct IndicatorIter<S, I> {
+	source: S,
+	indicator: I,
+}
+
+impl<S, I> IndicatorIter<S, I> {
+	/// Returns the wrapped indicator, e.g. to continue feeding it once the iterator is exhausted.
+	pub fn into_indicator(self) -> I {
+		self.indicator
+	}
+}
+
+impl<S, I> Iterator for IndicatorIter<S, I>
+where
+	S: Iterator,
+	I: Next<S::Item>,
+{
+	type Item = I::Output;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let input = self.source.next()?;
+		Some(self.indicator.next(input))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.source.size_hint()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::indicators::{Maximum, SimpleMovingAverage};
+	use crate::test_helper::*;
+
+	#[test]
+	fn test_indicator_f64() {
+		let prices = [4.0, 5.0, 6.0, 6.0];
+		let out: Vec<f64> = prices
+			.iter()
+			.copied()
+			.indicator(SimpleMovingAverage::new(2).unwrap())
+			.collect();
+		assert_eq!(out, vec![4.0, 4.5, 5.5, 6.0]);
+	}
+
+	#[test]
+	fn test_indicator_bars() {
+		let bars = [
+			Bar::new().high(1.0),
+			Bar::new().high(3.0),
+			Bar::new().high(2.0),
+			Bar::new().high(1.5),
+		];
+		let out: Vec<f64> = bars.iter().indicator(Maximum::new(2).unwrap()).collect();
+		assert_eq!(out, vec![1.0, 3.0, 3.0, 2.0]);
+	}
+
+	#[test]
+	fn test_into_indicator() {
+		let mut iter = [4.0, 5.0]
+			.iter()
+			.copied()
+			.indicator(SimpleMovingAverage::new(3).unwrap());
+		assert_eq!(iter.size_hint(), (2, Some(2)));
+		assert_eq!(iter.by_ref().count(), 2);
+
+		let mut sma = iter.into_indicator();
+		assert_eq!(sma.next(6.0), 5.0);
+	}
+}
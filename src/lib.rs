@@ -7,6 +7,9 @@
 //! Every indicator implements [Next<T>](trait.Next.html) and [Reset](trait.Reset.html) traits,
 //! which are the core concept of the library.
 //!
+//! Indicators that operate on a window also implement [Ready](trait.Ready.html), which tells
+//! whether the warm-up period is over and the output is calculated over a complete window.
+//!
 //! Since `Next<T>` is a generic trait, most of the indicators can work with both input types: `f64` and more complex
 //! structures like [DataItem](struct.DataItem.html).
 //!
@@ -30,6 +33,17 @@
 //!   * [Exponential Moving Average (EMA)](crate::indicators::ExponentialMovingAverage)
 //!   * [Simple Moving Average (SMA)](crate::indicators::SimpleMovingAverage)
 //!   * [Weighted Moving Average (WMA)](crate::indicators::WeightedMovingAverage)
+//!   * [Ichimoku Cloud](indicators/struct.IchimokuCloud.html)
+//!   * [Parabolic SAR (PSAR)](indicators/struct.ParabolicSar.html)
+//!   * [Hull Moving Average (HMA)](indicators/struct.HullMovingAverage.html)
+//!   * [Kaufman's Adaptive Moving Average (KAMA)](indicators/struct.KaufmanAdaptiveMovingAverage.html)
+//!   * [Zero Lag Exponential Moving Average (ZLEMA)](indicators/struct.ZeroLagExponentialMovingAverage.html)
+//!   * [Double Exponential Moving Average (DEMA)](indicators/struct.DoubleExponentialMovingAverage.html)
+//!   * [Triple Exponential Moving Average (TEMA)](indicators/struct.TripleExponentialMovingAverage.html)
+//!   * [Vortex Indicator (VI)](indicators/struct.VortexIndicator.html)
+//!   * [Directional Movement Index (DMI)](indicators/struct.DirectionalMovementIndex.html)
+//!   * [Zigzag](indicators/struct.Zigzag.html)
+//!   * [Time Series Forecast (TSF)](indicators/struct.TimeSeriesForecast.html)
 //! * Oscillators
 //!   * [Relative Strength Index (RSI)](indicators/struct.RelativeStrengthIndex.html)
 //!   * [Fast Stochastic](indicators/struct.FastStochastic.html)
@@ -38,6 +52,24 @@
 //!   * [Percentage Price Oscillator (PPO)](indicators/struct.PercentagePriceOscillator.html)
 //!   * [Commodity Channel Index (CCI)](indicators/struct.CommodityChannelIndex.html)
 //!   * [Money Flow Index (MFI)](indicators/struct.MoneyFlowIndex.html)
+//!   * [Williams %R (WILLR)](indicators/struct.WilliamsPercentRange.html)
+//!   * [Chaikin Money Flow (CMF)](indicators/struct.ChaikinMoneyFlow.html)
+//!   * [Aroon](indicators/struct.Aroon.html)
+//!   * [Triple Exponential Average (TRIX)](indicators/struct.TripleExponentialAverage.html)
+//!   * [Ultimate Oscillator (UO)](indicators/struct.UltimateOscillator.html)
+//!   * [Detrended Price Oscillator (DPO)](indicators/struct.DetrendedPriceOscillator.html)
+//!   * [Chande Momentum Oscillator (CMO)](indicators/struct.ChandeMomentumOscillator.html)
+//!   * [Elder Ray Index (ERI)](indicators/struct.ElderRay.html)
+//!   * [Force Index (FI)](indicators/struct.ForceIndex.html)
+//!   * [Coppock Curve](indicators/struct.CoppockCurve.html)
+//!   * [Mass Index (MI)](indicators/struct.MassIndex.html)
+//!   * [Chaikin Oscillator](indicators/struct.ChaikinOscillator.html)
+//!   * [Stochastic RSI (StochRSI)](indicators/struct.StochasticRsi.html)
+//!   * [Ease of Movement (EOM)](indicators/struct.EaseOfMovement.html)
+//!   * [Balance of Power (BOP)](indicators/struct.BalanceOfPower.html)
+//!   * [Klinger Volume Oscillator (KVO)](indicators/struct.KlingerOscillator.html)
+//!   * [Volume Relative Strength Index (VRSI)](indicators/struct.VolumeRsi.html)
+//!   * [Stochastic Oscillator (STOCH)](indicators/struct.StochasticOscillator.html)
 //! * Other
 //!   * [Standard Deviation (SD)](indicators/struct.StandardDeviation.html)
 //!   * [Mean Absolute Deviation (MAD)](indicators/struct.MeanAbsoluteDeviation.html)
@@ -51,18 +83,94 @@
 //!   * [Efficiency Ratio (ER)](indicators/struct.EfficiencyRatio.html)
 //!   * [Rate of Change (ROC)](indicators/struct.RateOfChange.html)
 //!   * [On Balance Volume (OBV)](indicators/struct.OnBalanceVolume.html)
+//!   * [Volume Weighted Average Price (VWAP)](indicators/struct.VolumeWeightedAveragePrice.html)
+//!   * [Donchian Channel (DC)](indicators/struct.DonchianChannel.html)
+//!   * [Choppiness Index (CHOP)](indicators/struct.ChoppinessIndex.html)
+//!   * [Price Volume Trend (PVT)](indicators/struct.PriceVolumeTrend.html)
+//!   * [Accumulation/Distribution Line (A/D)](indicators/struct.AccumulationDistribution.html)
+//!   * [Variance (VAR)](indicators/struct.Variance.html)
+//!   * [Covariance (COV)](indicators/struct.Covariance.html)
+//!   * [Correlation (CORR)](indicators/struct.Correlation.html)
+//!   * [Skewness (SKEW)](indicators/struct.Skewness.html)
+//!   * [Kurtosis (KURT)](indicators/struct.Kurtosis.html)
+//!   * [Median (MEDIAN)](indicators/struct.Median.html)
+//!   * [Percentile (PERCENTILE)](indicators/struct.Percentile.html)
+//!   * [Historical Volatility (HV)](indicators/struct.HistoricalVolatility.html)
+//!   * [Maximum Drawdown (MDD)](indicators/struct.MaxDrawdown.html)
+//!   * [Sharpe Ratio (SHARPE)](indicators/struct.SharpeRatio.html)
+//!   * [Log Return](indicators/struct.LogReturn.html)
+//!   * [Cumulative Return](indicators/struct.CumulativeReturn.html)
+//!   * [Average True Range Percent (ATRP)](indicators/struct.AverageTrueRangePercent.html)
+//!   * [Pivot Points (PIVOT)](indicators/struct.PivotPoints.html)
+//!   * [Bollinger Band %B (BB%B)](indicators/struct.BollingerBandPctB.html)
+//!   * [Bollinger Band Width (BBW)](indicators/struct.BollingerBandWidth.html)
+//!   * [Rolling Sum (SUM)](indicators/struct.RollingSum.html)
+//!   * [Median Price (MEDPRICE)](indicators/struct.MedianPrice.html)
+//!   * [Typical Price (TYPPRICE)](indicators/struct.TypicalPrice.html)
+//!   * [Weighted Close Price (WCLPRICE)](indicators/struct.WeightedClose.html)
+//!   * [Linear Regression (LINREG)](indicators/struct.LinearRegression.html)
+//!   * [Standard Error (STDERR)](indicators/struct.StandardError.html)
+//!
+//! # Features
 //!
+//! * `std` - enabled by default. Disable the default features and enable `alloc` instead to
+//!   use the crate without the standard library, e.g. on embedded devices. The float math
+//!   then comes from [libm](https://crates.io/crates/libm).
+//! * `serde` - implements `Serialize` and `Deserialize` for the indicators.
+//! * `simd` - adds `SimpleMovingAverage::next_slice_simd`, a vectorized batch computation
+//!   based on the [wide](https://crates.io/crates/wide) crate.
+//! * `rayon` - adds `parallel_evaluate` to compute the indicators of many datasets, e.g. the
+//!   symbols of a portfolio, in parallel with [rayon](https://crates.io/crates/rayon).
+//! * `csv` - adds the [io](io/index.html) module to read bars from CSV files and write the
+//!   indicator outputs back.
+//!
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "alloc")))]
+compile_error!("either the `std` or the `alloc` feature must be enabled");
+
+extern crate alloc;
+
 #[cfg(test)]
 #[macro_use]
 mod test_helper;
 
+#[macro_use]
+mod macros;
+
 mod helpers;
 
 pub mod errors;
+pub mod factory;
 pub mod indicators;
+pub mod signals;
+pub mod types;
 
 mod traits;
 pub use crate::traits::*;
 
+mod iter;
+pub use crate::iter::{IndicatorExt, IndicatorIter};
+
 mod data_item;
 pub use crate::data_item::DataItem;
+
+mod clamp;
+pub use crate::clamp::Clamp;
+
+mod nan_guard;
+pub use crate::nan_guard::{NanGuard, NanPolicy};
+
+mod pipeline;
+pub use crate::pipeline::{Pipe, Pipeline};
+
+mod warmup;
+pub use crate::warmup::Warmup;
+
+#[cfg(feature = "csv")]
+pub mod io;
+
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "rayon")]
+pub use crate::parallel::parallel_evaluate;
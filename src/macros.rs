@@ -0,0 +1,106 @@
+/// Defines an indicator struct along with its `Reset`, `Default` and `Display` implementations.
+///
+/// Fields followed by `= value` are set back to that value on reset, the other fields hold
+/// the configuration of the indicator and are kept. The struct derives `Debug`, `Clone`,
+/// `PartialEq` and, with the `serde` feature, `Serialize` and `Deserialize`, which have to be
+/// imported like `fmt` and `Reset`.
+///
+/// The display format takes the arguments of `write!`, where the given name is bound to the
+/// indicator.
+///
+/// ```ignore
+/// define_indicator! {
+///     /// Documentation of the indicator.
+///     #[doc(alias = "OBV")]
+///     pub struct OnBalanceVolume {
+///         obv: f64 = 0.0,
+///         prev_close: Option<f64> = None,
+///     }
+///     display(obv) = ("OBV");
+///     default = Self::new();
+/// }
+/// ```
+macro_rules! define_indicator {
+	(
+		$(#[$attr:meta])*
+		$vis:vis struct $name:ident {
+			$($field:ident: $ty:ty $(= $reset:expr)?),* $(,)?
+		}
+		display($this:ident) = ($($format:tt)+);
+		default = $default:expr;
+	) => {
+		$(#[$attr])*
+		#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+		#[derive(Debug, Clone, PartialEq)]
+		$vis struct $name {
+			$($field: $ty,)*
+		}
+
+		impl Reset for $name {
+			fn reset(&mut self) {
+				$($(self.$field = $reset;)?)*
+			}
+		}
+
+		impl Default for $name {
+			fn default() -> Self {
+				$default
+			}
+		}
+
+		impl fmt::Display for $name {
+			fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				#[allow(unused_variables)]
+				let $this = self;
+				write!(f, $($format)+)
+			}
+		}
+	};
+}
+
+#[cfg(test)]
+mod tests {
+	use core::fmt;
+
+	use crate::{Next, Reset};
+	#[cfg(feature = "serde")]
+	use serde::{Deserialize, Serialize};
+
+	define_indicator! {
+		/// Running sum of the inputs, scaled by a factor.
+		struct ScaledSum {
+			factor: f64,
+			sum: f64 = 0.0,
+			count: usize = 0,
+		}
+		display(indicator) = ("SCALED_SUM({})", indicator.factor);
+		default = Self { factor: 2.0, sum: 0.0, count: 0 };
+	}
+
+	impl Next<f64> for ScaledSum {
+		type Output = f64;
+
+		fn next(&mut self, input: f64) -> Self::Output {
+			self.count += 1;
+			self.sum += input * self.factor;
+			self.sum
+		}
+	}
+
+	#[test]
+	fn test_define_indicator() {
+		let mut indicator = ScaledSum::default();
+		assert_eq!(indicator.next(1.0), 2.0);
+		assert_eq!(indicator.next(2.0), 6.0);
+		assert_eq!(indicator.count, 2);
+
+		let cloned = indicator.clone();
+		indicator.reset();
+		assert_eq!(indicator.sum, 0.0);
+		assert_eq!(indicator.count, 0);
+		assert_eq!(indicator.factor, 2.0);
+		assert_eq!(cloned.sum, 6.0);
+
+		assert_eq!(format!("{}", indicator), "SCALED_SUM(2)");
+	}
+}
@@ -0,0 +1,213 @@
+use core::fmt;
+
+use crate::{Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How a [NanGuard](struct.NanGuard.html) handles missing inputs, i.e. NaN or infinite values.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NanPolicy {
+	/// A missing input makes every output NaN until the guard is reset. This is the default.
+	#[default]
+	Propagate,
+	/// Missing inputs are ignored: the indicator is not advanced and the last output is
+	/// returned again, NaN if there is none yet.
+	Skip,
+	/// Missing inputs are replaced with the last valid input. NaN is returned while there is
+	/// no valid input yet.
+	ReplaceWithPrevious,
+}
+
+/// Wraps an indicator to handle NaN and infinite inputs, e.g. from feeds reporting missing or
+/// stale ticks, according to a [NanPolicy](enum.NanPolicy.html).
+///
+/// The wrapped indicator only ever sees finite inputs.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SimpleMovingAverage;
+/// use ta::{NanGuard, NanPolicy, Next};
+///
+/// let sma = SimpleMovingAverage::new(2).unwrap();
+/// let mut guard = NanGuard::new(sma, NanPolicy::Skip);
+///
+/// assert_eq!(guard.next(4.0), 4.0);
+/// assert_eq!(guard.next(6.0), 5.0);
+/// assert_eq!(guard.next(f64::NAN), 5.0);
+/// assert_eq!(guard.next(8.0), 7.0);
+///
+/// guard.set_nan_policy(NanPolicy::Propagate);
+/// assert!(guard.next(f64::INFINITY).is_nan());
+/// assert!(guard.next(8.0).is_nan());
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct NanGuard<I> {
+	indicator: I,
+	policy: NanPolicy,
+	prev_input: Option<f64>,
+	prev_output: f64,
+	poisoned: bool,
+}
+
+impl<I> NanGuard<I> {
+	pub fn new(indicator: I, policy: NanPolicy) -> Self {
+		Self {
+			indicator,
+			policy,
+			prev_input: None,
+			prev_output: f64::NAN,
+			poisoned: false,
+		}
+	}
+
+	pub fn nan_policy(&self) -> NanPolicy {
+		self.policy
+	}
+
+	/// Changes the policy for the following inputs, keeping the state of the indicator.
+	pub fn set_nan_policy(&mut self, policy: NanPolicy) {
+		self.policy = policy;
+	}
+
+	pub fn inner(&self) -> &I {
+		&self.indicator
+	}
+
+	/// Returns the wrapped indicator.
+	pub fn into_inner(self) -> I {
+		self.indicator
+	}
+}
+
+impl<I: Period> Period for NanGuard<I> {
+	fn period(&self) -> usize {
+		self.indicator.period()
+	}
+}
+
+impl<I: Ready> Ready for NanGuard<I> {
+	fn is_ready(&self) -> bool {
+		!self.poisoned && self.indicator.is_ready()
+	}
+}
+
+impl<I: Next<f64, Output = f64>> Next<f64> for NanGuard<I> {
+	type Output = f64;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		let input = if input.is_finite() {
+			input
+		} else {
+			match self.policy {
+				NanPolicy::Propagate => {
+					self.poisoned = true;
+					return f64::NAN;
+				}
+				NanPolicy::Skip => return self.prev_output,
+				NanPolicy::ReplaceWithPrevious => match self.prev_input {
+					Some(prev_input) => prev_input,
+					None => return f64::NAN,
+				},
+			}
+		};
+
+		self.prev_input = Some(input);
+		self.prev_output = self.indicator.next(input);
+		if self.poisoned {
+			f64::NAN
+		} else {
+			self.prev_output
+		}
+	}
+}
+
+impl<I: Reset> Reset for NanGuard<I> {
+	fn reset(&mut self) {
+		self.indicator.reset();
+		self.prev_input = None;
+		self.prev_output = f64::NAN;
+		self.poisoned = false;
+	}
+}
+
+impl<I: fmt::Display> fmt::Display for NanGuard<I> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}", self.indicator)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::indicators::{Minimum, SimpleMovingAverage};
+
+	#[test]
+	fn test_propagate() {
+		let mut guard = NanGuard::new(Minimum::new(2).unwrap(), NanPolicy::Propagate);
+		assert_eq!(guard.next(4.0), 4.0);
+		assert_eq!(guard.next(5.0), 4.0);
+		assert!(guard.is_ready());
+		assert!(guard.next(f64::NAN).is_nan());
+		assert!(guard.next(3.0).is_nan());
+		assert!(!guard.is_ready());
+
+		guard.reset();
+		assert_eq!(guard.next(5.0), 5.0);
+		assert_eq!(guard.next(6.0), 5.0);
+	}
+
+	#[test]
+	fn test_skip() {
+		let mut guard = NanGuard::new(SimpleMovingAverage::new(2).unwrap(), NanPolicy::Skip);
+		assert!(guard.next(f64::NAN).is_nan());
+		assert_eq!(guard.next(4.0), 4.0);
+		assert_eq!(guard.next(f64::NEG_INFINITY), 4.0);
+		assert_eq!(guard.next(6.0), 5.0);
+		assert_eq!(guard.next(f64::NAN), 5.0);
+		assert_eq!(guard.next(2.0), 4.0);
+	}
+
+	#[test]
+	fn test_replace_with_previous() {
+		let sma = SimpleMovingAverage::new(2).unwrap();
+		let mut guard = NanGuard::new(sma, NanPolicy::ReplaceWithPrevious);
+		assert!(guard.next(f64::NAN).is_nan());
+		assert_eq!(guard.next(4.0), 4.0);
+		assert_eq!(guard.next(8.0), 6.0);
+		assert_eq!(guard.next(f64::NAN), 8.0);
+		assert_eq!(guard.next(f64::INFINITY), 8.0);
+		assert_eq!(guard.next(2.0), 5.0);
+	}
+
+	#[test]
+	fn test_minimum_with_nan() {
+		// without a guard, NaN inputs corrupt the window of comparisons
+		let mut guard = NanGuard::new(Minimum::new(3).unwrap(), NanPolicy::Skip);
+		for &x in &[5.0, f64::NAN, 3.0, f64::NAN, 4.0] {
+			guard.next(x);
+		}
+		assert_eq!(guard.next(6.0), 3.0);
+		assert_eq!(guard.next(7.0), 4.0);
+	}
+
+	#[test]
+	fn test_set_nan_policy() {
+		let mut guard = NanGuard::new(Minimum::new(2).unwrap(), NanPolicy::default());
+		assert_eq!(guard.nan_policy(), NanPolicy::Propagate);
+		guard.set_nan_policy(NanPolicy::Skip);
+		assert_eq!(guard.nan_policy(), NanPolicy::Skip);
+		assert_eq!(guard.next(3.0), 3.0);
+		assert_eq!(guard.next(f64::NAN), 3.0);
+		assert_eq!(guard.inner().period(), 2);
+		assert_eq!(guard.into_inner().period(), 2);
+	}
+
+	#[test]
+	fn test_display() {
+		let guard = NanGuard::new(Minimum::new(5).unwrap(), NanPolicy::Skip);
+		assert_eq!(format!("{}", guard), "MIN(5)");
+	}
+}
@@ -0,0 +1,86 @@
+use rayon::prelude::*;
+
+use crate::Next;
+
+/// Feeds every dataset into its indicator on the rayon thread pool and returns the outputs.
+///
+/// The indicator at position _i_ consumes the dataset at position _i_, e.g. one indicator
+/// per symbol of a portfolio. Each indicator owns its own state, so they are evaluated
+/// independently and keep their state afterwards, as with [`Next::next_slice`].
+///
+/// Available with the `rayon` feature.
+///
+/// # Panics
+///
+/// Panics if `indicators` and `datasets` have different lengths.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Minimum;
+/// use ta::parallel_evaluate;
+///
+/// let mut indicators = vec![Minimum::new(2).unwrap(), Minimum::new(3).unwrap()];
+/// let datasets = [vec![3.0, 1.0, 2.0], vec![5.0, 6.0, 4.0]];
+///
+/// let outputs = parallel_evaluate(&mut indicators, &datasets);
+/// assert_eq!(outputs, vec![vec![3.0, 1.0, 1.0], vec![5.0, 5.0, 4.0]]);
+/// ```
+pub fn parallel_evaluate<I, D>(indicators: &mut [I], datasets: &[D]) -> Vec<Vec<I::Output>>
+where
+	I: Next<f64> + Send,
+	I::Output: Send,
+	D: AsRef<[f64]> + Sync,
+{
+	assert_eq!(indicators.len(), datasets.len());
+
+	indicators
+		.par_iter_mut()
+		.zip(datasets.par_iter())
+		.map(|(indicator, dataset)| indicator.next_slice_collect(dataset.as_ref()))
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::indicators::Minimum;
+
+	#[test]
+	fn test_parallel_evaluate() {
+		let datasets: Vec<Vec<f64>> = (0..100)
+			.map(|i| (0..50).map(|j| ((i * 7 + j * 13) % 29) as f64).collect())
+			.collect();
+		let mut indicators: Vec<Minimum> = (0..100)
+			.map(|i| Minimum::new(i % 10 + 1).unwrap())
+			.collect();
+		let mut expected_indicators = indicators.clone();
+
+		let outputs = parallel_evaluate(&mut indicators, &datasets);
+
+		assert_eq!(outputs.len(), 100);
+		for (i, dataset) in datasets.iter().enumerate() {
+			let expected = expected_indicators[i].next_slice_collect(dataset);
+			assert_eq!(outputs[i], expected);
+		}
+
+		// the indicators keep their state
+		for (indicator, expected) in indicators.iter_mut().zip(&mut expected_indicators) {
+			assert_eq!(indicator.next(-1.0), expected.next(-1.0));
+		}
+	}
+
+	#[test]
+	fn test_parallel_evaluate_empty() {
+		let mut indicators: Vec<Minimum> = vec![];
+		let datasets: [&[f64]; 0] = [];
+		assert!(parallel_evaluate(&mut indicators, &datasets).is_empty());
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_parallel_evaluate_length_mismatch() {
+		let mut indicators = vec![Minimum::new(2).unwrap()];
+		parallel_evaluate(&mut indicators, &[[1.0], [2.0]]);
+	}
+}
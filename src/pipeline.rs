@@ -0,0 +1,226 @@
+use core::fmt;
+
+use crate::{Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Chains indicators, see [Pipe::pipe](trait.Pipe.html#method.pipe).
+pub trait Pipe: Sized {
+	/// Feeds the outputs of `self` into `next`, e.g. to smooth an indicator.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ta::indicators::{ExponentialMovingAverage as Ema, LogReturn};
+	/// use ta::{Next, Pipe};
+	///
+	/// // EMA of the log returns
+	/// let mut pipeline = LogReturn::new().pipe(Ema::new(3).unwrap());
+	/// assert_eq!(pipeline.next(100.0), 0.0);
+	/// assert!(pipeline.next(110.0) > 0.0);
+	/// ```
+	fn pipe<B>(self, next: B) -> Pipeline<Self, B> {
+		Pipeline::new(self, next)
+	}
+}
+
+impl<A> Pipe for A {}
+
+/// Two indicators composed sequentially: the input goes through `A`, whose output goes
+/// through `B`.
+///
+/// This struct is created by the [pipe](trait.Pipe.html#method.pipe) method on
+/// [Pipe](trait.Pipe.html). Pipelines can be chained further, the result of
+/// `a.pipe(b).pipe(c)` computes `c(b(a(x)))`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pipeline<A, B> {
+	first: A,
+	second: B,
+	count: usize,
+}
+
+impl<A, B> Pipeline<A, B> {
+	pub fn new(first: A, second: B) -> Self {
+		Self {
+			first,
+			second,
+			count: 0,
+		}
+	}
+
+	pub fn first(&self) -> &A {
+		&self.first
+	}
+
+	pub fn second(&self) -> &B {
+		&self.second
+	}
+
+	/// Returns both indicators.
+	pub fn into_inner(self) -> (A, B) {
+		(self.first, self.second)
+	}
+}
+
+/// The combined warm-up period: the second indicator is fed once the first one outputs its
+/// first full-period value.
+impl<A: Period, B: Period> Period for Pipeline<A, B> {
+	fn period(&self) -> usize {
+		self.first.period() + self.second.period() - 1
+	}
+}
+
+/// Ready once both indicators are, and the second one has been fed the outputs of the first
+/// one for a full period. Indicators made of several stages, e.g. a DEMA, can take more than
+/// [period](#method.period) inputs to get there.
+impl<A: Period + Ready, B: Period + Ready> Ready for Pipeline<A, B> {
+	fn is_ready(&self) -> bool {
+		self.count >= self.period() && self.first.is_ready() && self.second.is_ready()
+	}
+}
+
+impl<T, A, B> Next<T> for Pipeline<A, B>
+where
+	A: Next<T>,
+	B: Next<A::Output>,
+{
+	type Output = B::Output;
+
+	fn next(&mut self, input: T) -> Self::Output {
+		self.count = self.count.saturating_add(1);
+		self.second.next(self.first.next(input))
+	}
+}
+
+impl<A: Reset, B: Reset> Reset for Pipeline<A, B> {
+	fn reset(&mut self) {
+		self.first.reset();
+		self.second.reset();
+		self.count = 0;
+	}
+}
+
+impl<A: Default, B: Default> Default for Pipeline<A, B> {
+	fn default() -> Self {
+		Self::new(A::default(), B::default())
+	}
+}
+
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for Pipeline<A, B> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{} | {}", self.first, self.second)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::indicators::{
+		DoubleExponentialMovingAverage as Dema, ExponentialMovingAverage as Ema, LogReturn,
+		Maximum, SimpleMovingAverage as Sma, TrueRange,
+	};
+	use crate::test_helper::*;
+
+	type TrueRangeSma = Pipeline<TrueRange, Sma>;
+	test_indicator!(TrueRangeSma);
+
+	#[test]
+	fn test_next() {
+		let mut pipeline = Sma::new(2).unwrap().pipe(Maximum::new(3).unwrap());
+		assert_eq!(pipeline.next(4.0), 4.0);
+		assert_eq!(pipeline.next(8.0), 6.0);
+		assert_eq!(pipeline.next(2.0), 6.0);
+		assert_eq!(pipeline.next(2.0), 6.0);
+		assert_eq!(pipeline.next(2.0), 5.0);
+		assert_eq!(pipeline.next(2.0), 2.0);
+	}
+
+	#[test]
+	fn test_next_bar() {
+		// average true range with a simple moving average
+		let mut pipeline = TrueRange::new().pipe(Sma::new(2).unwrap());
+		assert_eq!(pipeline.next(&Bar::new().high(10).low(8).close(9)), 2.0);
+		assert_eq!(pipeline.next(&Bar::new().high(12).low(11).close(11)), 2.5);
+	}
+
+	#[test]
+	fn test_chained() {
+		let mut pipeline = LogReturn::new()
+			.pipe(Ema::new(2).unwrap())
+			.pipe(Sma::new(2).unwrap());
+		let mut log_return = LogReturn::new();
+		let mut ema = Ema::new(2).unwrap();
+		let mut sma = Sma::new(2).unwrap();
+
+		for &price in &[100.0, 110.0, 105.0, 120.0] {
+			let expected = sma.next(ema.next(log_return.next(price)));
+			assert_eq!(pipeline.next(price), expected);
+		}
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut pipeline = Sma::new(2).unwrap().pipe(Sma::new(2).unwrap());
+		pipeline.next(1.0);
+		pipeline.next(1.0);
+		// the second SMA has only been fed one full-period output
+		assert!(pipeline.first().is_ready());
+		assert!(!pipeline.is_ready());
+		pipeline.next(1.0);
+		assert!(pipeline.is_ready());
+		assert_eq!(pipeline.period(), 3);
+
+		pipeline.reset();
+		assert!(!pipeline.is_ready());
+		assert!(!pipeline.second().is_ready());
+	}
+
+	#[test]
+	fn test_is_ready_layered() {
+		// the DEMA is ready after 5 inputs, later than the period of the pipeline
+		let mut pipeline = Dema::new(3).unwrap().pipe(Sma::new(2).unwrap());
+		assert_eq!(pipeline.period(), 4);
+		for _ in 0..4 {
+			pipeline.next(1.0);
+			assert!(!pipeline.is_ready());
+		}
+		pipeline.next(1.0);
+		assert!(pipeline.is_ready());
+
+		// and it is fed into a DEMA
+		let mut pipeline = Sma::new(2).unwrap().pipe(Dema::new(3).unwrap());
+		for _ in 0..4 {
+			pipeline.next(1.0);
+			assert!(!pipeline.is_ready());
+		}
+		pipeline.next(1.0);
+		assert!(pipeline.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut pipeline = Sma::new(2).unwrap().pipe(Maximum::new(2).unwrap());
+		assert_eq!(pipeline.next(8.0), 8.0);
+
+		pipeline.reset();
+		assert_eq!(pipeline.next(2.0), 2.0);
+		let (sma, max) = pipeline.into_inner();
+		assert_eq!(sma.period(), 2);
+		assert_eq!(max.period(), 2);
+	}
+
+	#[test]
+	fn test_period() {
+		let pipeline = Sma::new(3).unwrap().pipe(Maximum::new(4).unwrap());
+		assert_eq!(pipeline.period(), 6);
+		let pipeline = pipeline.pipe(Ema::new(2).unwrap());
+		assert_eq!(pipeline.period(), 7);
+	}
+
+	#[test]
+	fn test_display() {
+		let pipeline = LogReturn::new().pipe(Ema::new(14).unwrap());
+		assert_eq!(format!("{}", pipeline), "LOG_RETURN | EMA(14)");
+	}
+}
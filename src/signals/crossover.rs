@@ -0,0 +1,179 @@
+use core::fmt;
+
+use crate::{Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Crossover and crossunder of two series.
+///
+/// Fires when a series _A_, e.g. a fast moving average, crosses above or below a series _B_,
+/// e.g. a slow moving average or a fixed level. It is fed with `(a, b)` pairs.
+///
+/// # Signals
+///
+/// * _crossover_ - A<sub>t-1</sub> ≤ B<sub>t-1</sub> and A<sub>t</sub> > B<sub>t</sub>
+/// * _crossunder_ - A<sub>t-1</sub> ≥ B<sub>t-1</sub> and A<sub>t</sub> < B<sub>t</sub>
+///
+/// The first pair has no previous values, so neither signal fires.
+///
+/// # Example
+///
+/// ```
+/// use ta::signals::Crossover;
+/// use ta::Next;
+///
+/// let mut cross = Crossover::new();
+/// assert!(!cross.next((1.0, 2.0)).crossover);
+/// assert!(cross.next((3.0, 2.0)).crossover);
+/// assert!(cross.next((1.0, 2.0)).crossunder);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Crossover {
+	prev: Option<(f64, f64)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrossoverSignal {
+	pub crossover: bool,
+	pub crossunder: bool,
+}
+
+impl From<CrossoverSignal> for (bool, bool) {
+	fn from(cs: CrossoverSignal) -> Self {
+		(cs.crossover, cs.crossunder)
+	}
+}
+
+impl Crossover {
+	pub fn new() -> Self {
+		Self { prev: None }
+	}
+}
+
+impl Period for Crossover {
+	fn period(&self) -> usize {
+		1
+	}
+}
+
+impl Ready for Crossover {
+	fn is_ready(&self) -> bool {
+		self.prev.is_some()
+	}
+}
+
+impl Next<(f64, f64)> for Crossover {
+	type Output = CrossoverSignal;
+
+	fn next(&mut self, (a, b): (f64, f64)) -> Self::Output {
+		match self.prev.replace((a, b)) {
+			Some((prev_a, prev_b)) => CrossoverSignal {
+				crossover: prev_a <= prev_b && a > b,
+				crossunder: prev_a >= prev_b && a < b,
+			},
+			None => CrossoverSignal {
+				crossover: false,
+				crossunder: false,
+			},
+		}
+	}
+}
+
+impl Reset for Crossover {
+	fn reset(&mut self) {
+		self.prev = None;
+	}
+}
+
+impl Default for Crossover {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl fmt::Display for Crossover {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "CROSSOVER")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::indicators::{ExponentialMovingAverage as Ema, SimpleMovingAverage as Sma};
+
+	#[test]
+	fn test_next() {
+		let test_data = vec![
+			// a, b, crossover, crossunder
+			(1.0, 2.0, false, false),
+			(1.5, 2.0, false, false),
+			(2.0, 2.0, false, false),
+			(2.5, 2.0, true, false),
+			(3.0, 2.0, false, false),
+			(2.0, 2.0, false, false),
+			(1.0, 2.0, false, true),
+			(3.0, 2.0, true, false),
+			(1.0, 2.0, false, true),
+		];
+
+		let mut cross = Crossover::new();
+		for (a, b, crossover, crossunder) in test_data {
+			let signal: (bool, bool) = cross.next((a, b)).into();
+			assert_eq!(signal, (crossover, crossunder));
+		}
+	}
+
+	#[test]
+	fn test_moving_averages() {
+		let mut fast = Ema::new(2).unwrap();
+		let mut slow = Sma::new(4).unwrap();
+		let mut cross = Crossover::new();
+
+		let signals: Vec<CrossoverSignal> = [10.0, 9.0, 8.0, 7.0, 9.0, 11.0, 10.0, 6.0]
+			.iter()
+			.map(|&price| cross.next((fast.next(price), slow.next(price))))
+			.collect();
+
+		let crossovers: Vec<usize> = (0..signals.len())
+			.filter(|&i| signals[i].crossover)
+			.collect();
+		let crossunders: Vec<usize> = (0..signals.len())
+			.filter(|&i| signals[i].crossunder)
+			.collect();
+		assert_eq!(crossovers, vec![4]);
+		assert_eq!(crossunders, vec![1, 7]);
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut cross = Crossover::new();
+		assert!(!cross.is_ready());
+		cross.next((1.0, 2.0));
+		assert!(cross.is_ready());
+
+		cross.reset();
+		assert!(!cross.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut cross = Crossover::new();
+		cross.next((1.0, 2.0));
+		assert!(cross.next((3.0, 2.0)).crossover);
+
+		cross.reset();
+		assert!(!cross.next((3.0, 2.0)).crossover);
+	}
+
+	#[test]
+	fn test_default() {
+		Crossover::default();
+	}
+
+	#[test]
+	fn test_display() {
+		assert_eq!(format!("{}", Crossover::new()), "CROSSOVER");
+	}
+}
@@ -0,0 +1,268 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{Maximum, Minimum, RelativeStrengthIndex};
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Divergence between the price and an indicator.
+///
+/// The price and the indicator usually make new highs and new lows together. When one of
+/// them does and the other does not, the trend may be about to change (regular divergence)
+/// or to continue (hidden divergence).
+///
+/// A new high or low is a value above the highest or below the lowest of the previous
+/// _lookback_ values of the same series. No divergence is reported until _lookback_ values
+/// have been consumed.
+///
+/// # Signals
+///
+/// * _regular_bull_ - the price makes a new low, but the indicator does not
+/// * _regular_bear_ - the price makes a new high, but the indicator does not
+/// * _hidden_bull_ - the indicator makes a new low, but the price does not
+/// * _hidden_bear_ - the indicator makes a new high, but the price does not
+///
+/// # Parameters
+///
+/// * _lookback_ - number of previous periods to compare with (integer greater than 0).
+///   Default is 14.
+/// * _indicator_ - the indicator fed with the price. Default is
+///   [RSI(14)](../indicators/struct.RelativeStrengthIndex.html).
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::RateOfChange;
+/// use ta::signals::Divergence;
+/// use ta::Next;
+///
+/// let roc = RateOfChange::new(1).unwrap();
+/// let mut div = Divergence::with_indicator(2, roc).unwrap();
+///
+/// div.next(10.0);
+/// div.next(11.0);
+///
+/// // a new high of the price with a slower rate of change
+/// let out = div.next(12.0);
+/// assert!(out.regular_bear);
+/// assert!(!out.regular_bull);
+/// ```
+///
+/// # Links
+///
+/// * [Divergence, investopedia](https://www.investopedia.com/terms/d/divergence.asp)
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Divergence<I = RelativeStrengthIndex> {
+	indicator: I,
+	price_min: Minimum,
+	price_max: Maximum,
+	indicator_min: Minimum,
+	indicator_max: Maximum,
+	// lowest and highest prices and indicator values of the previous lookback periods
+	extremes: (f64, f64, f64, f64),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DivergenceOutput {
+	pub regular_bull: bool,
+	pub regular_bear: bool,
+	pub hidden_bull: bool,
+	pub hidden_bear: bool,
+}
+
+impl From<DivergenceOutput> for (bool, bool, bool, bool) {
+	fn from(d: DivergenceOutput) -> Self {
+		(d.regular_bull, d.regular_bear, d.hidden_bull, d.hidden_bear)
+	}
+}
+
+impl Divergence {
+	pub fn new(lookback: usize) -> Result<Self> {
+		Self::with_indicator(lookback, RelativeStrengthIndex::default())
+	}
+}
+
+impl<I> Divergence<I> {
+	pub fn with_indicator(lookback: usize, indicator: I) -> Result<Self> {
+		Ok(Self {
+			indicator,
+			price_min: Minimum::new(lookback)?,
+			price_max: Maximum::new(lookback)?,
+			indicator_min: Minimum::new(lookback)?,
+			indicator_max: Maximum::new(lookback)?,
+			extremes: (0.0, 0.0, 0.0, 0.0),
+		})
+	}
+
+	pub fn indicator(&self) -> &I {
+		&self.indicator
+	}
+}
+
+impl<I> Period for Divergence<I> {
+	fn period(&self) -> usize {
+		self.price_min.period()
+	}
+}
+
+impl<I> Ready for Divergence<I> {
+	fn is_ready(&self) -> bool {
+		self.price_min.is_ready()
+	}
+}
+
+impl<I: Next<f64, Output = f64>> Next<f64> for Divergence<I> {
+	type Output = DivergenceOutput;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		let value = self.indicator.next(input);
+
+		let output = if self.is_ready() {
+			let (price_low, price_high, value_low, value_high) = self.extremes;
+			DivergenceOutput {
+				regular_bull: input < price_low && value >= value_low,
+				regular_bear: input > price_high && value <= value_high,
+				hidden_bull: value < value_low && input >= price_low,
+				hidden_bear: value > value_high && input <= price_high,
+			}
+		} else {
+			DivergenceOutput {
+				regular_bull: false,
+				regular_bear: false,
+				hidden_bull: false,
+				hidden_bear: false,
+			}
+		};
+
+		self.extremes = (
+			self.price_min.next(input),
+			self.price_max.next(input),
+			self.indicator_min.next(value),
+			self.indicator_max.next(value),
+		);
+
+		output
+	}
+}
+
+impl<I: Next<f64, Output = f64>, T: Close> Next<&T> for Divergence<I> {
+	type Output = DivergenceOutput;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl<I: Reset> Reset for Divergence<I> {
+	fn reset(&mut self) {
+		self.indicator.reset();
+		self.price_min.reset();
+		self.price_max.reset();
+		self.indicator_min.reset();
+		self.indicator_max.reset();
+		self.extremes = (0.0, 0.0, 0.0, 0.0);
+	}
+}
+
+impl Default for Divergence {
+	fn default() -> Self {
+		Self::new(14).unwrap()
+	}
+}
+
+impl<I: fmt::Display> fmt::Display for Divergence<I> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "DIVERGENCE({}, {})", self.period(), self.indicator)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::indicators::RateOfChange;
+	use crate::test_helper::*;
+
+	test_indicator!(Divergence);
+
+	fn roc_divergence(lookback: usize) -> Divergence<RateOfChange> {
+		Divergence::with_indicator(lookback, RateOfChange::new(1).unwrap()).unwrap()
+	}
+
+	#[test]
+	fn test_new() {
+		assert!(Divergence::new(0).is_err());
+		assert!(Divergence::new(1).is_ok());
+		assert!(Divergence::with_indicator(0, RateOfChange::new(1).unwrap()).is_err());
+	}
+
+	#[test]
+	fn test_next() {
+		let test_data = vec![
+			// price, regular_bull, regular_bear, hidden_bull, hidden_bear
+			(10.0, false, false, false, false),
+			(11.0, false, false, false, false),
+			(12.0, false, true, false, false),
+			(13.0, false, true, true, false),
+			(12.0, false, false, true, false),
+			(11.5, true, false, false, false),
+			(11.0, true, false, false, false),
+			(10.9, true, false, false, true),
+			(11.5, false, false, false, false),
+			(12.5, false, false, false, false),
+		];
+
+		let mut div = roc_divergence(2);
+		for (price, regular_bull, regular_bear, hidden_bull, hidden_bear) in test_data {
+			let out: (bool, bool, bool, bool) = div.next(price).into();
+			assert_eq!(out, (regular_bull, regular_bear, hidden_bull, hidden_bear));
+		}
+	}
+
+	#[test]
+	fn test_next_bar() {
+		let mut div = roc_divergence(2);
+		div.next(&Bar::new().close(10));
+		div.next(&Bar::new().close(11));
+		assert!(div.next(&Bar::new().close(12)).regular_bear);
+	}
+
+	#[test]
+	fn test_is_ready() {
+		let mut div = roc_divergence(2);
+		div.next(10.0);
+		assert!(!div.is_ready());
+		div.next(11.0);
+		assert!(div.is_ready());
+
+		div.reset();
+		assert!(!div.is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut div = roc_divergence(2);
+		div.next(10.0);
+		div.next(11.0);
+		assert!(div.next(12.0).regular_bear);
+
+		div.reset();
+		assert!(!div.next(12.0).regular_bear);
+		div.next(10.0);
+		div.next(11.0);
+		assert!(div.next(12.0).regular_bear);
+	}
+
+	#[test]
+	fn test_default() {
+		Divergence::default();
+	}
+
+	#[test]
+	fn test_display() {
+		let div = Divergence::new(10).unwrap();
+		assert_eq!(format!("{}", div), "DIVERGENCE(10, RSI(14))");
+		assert_eq!(format!("{}", roc_divergence(3)), "DIVERGENCE(3, ROC(1))");
+	}
+}
@@ -0,0 +1,10 @@
+//! Signals derived from prices and indicators, e.g. to trigger orders.
+
+mod divergence;
+pub use self::divergence::{Divergence, DivergenceOutput};
+
+mod crossover;
+pub use self::crossover::{Crossover, CrossoverSignal};
+
+mod threshold_signal;
+pub use self::threshold_signal::{Signal, ThresholdSignal};
@@ -0,0 +1,248 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::RelativeStrengthIndex;
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Trading signal.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Signal {
+	Buy,
+	Sell,
+	Hold,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Zone {
+	Above,
+	Below,
+	Neutral,
+}
+
+/// Buy and sell signals of an oscillator crossing thresholds, e.g. an RSI leaving the 30..70
+/// range.
+///
+/// A buy signal is emitted when the indicator falls to the buy threshold or below (oversold),
+/// a sell signal when it rises to the sell threshold or above (overbought). Each signal is
+/// emitted only once per crossing: the following values on the same side of the threshold
+/// hold, until the indicator goes back between the thresholds and crosses again.
+///
+/// The indicator starts between the thresholds, so a first value beyond a threshold emits a
+/// signal.
+///
+/// # Parameters
+///
+/// * _indicator_ - the indicator fed with the price. Default is
+///   [RSI(14)](../indicators/struct.RelativeStrengthIndex.html).
+/// * _buy_threshold_ - finite value lower than _sell_threshold_. Default is 30.
+/// * _sell_threshold_ - finite value. Default is 70.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SimpleMovingAverage;
+/// use ta::signals::{Signal, ThresholdSignal};
+/// use ta::Next;
+///
+/// let sma = SimpleMovingAverage::new(1).unwrap();
+/// let mut signal = ThresholdSignal::new(sma, 30.0, 70.0).unwrap();
+///
+/// assert_eq!(signal.next(50.0), Signal::Hold);
+/// assert_eq!(signal.next(25.0), Signal::Buy);
+/// assert_eq!(signal.next(20.0), Signal::Hold);
+/// assert_eq!(signal.next(75.0), Signal::Sell);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThresholdSignal<I = RelativeStrengthIndex> {
+	indicator: I,
+	buy_threshold: f64,
+	sell_threshold: f64,
+	zone: Zone,
+}
+
+impl<I> ThresholdSignal<I> {
+	pub fn new(indicator: I, buy_threshold: f64, sell_threshold: f64) -> Result<Self> {
+		if !buy_threshold.is_finite()
+			|| !sell_threshold.is_finite()
+			|| buy_threshold >= sell_threshold
+		{
+			return Err(TaError::InvalidParameter);
+		}
+
+		Ok(Self {
+			indicator,
+			buy_threshold,
+			sell_threshold,
+			zone: Zone::Neutral,
+		})
+	}
+
+	pub fn buy_threshold(&self) -> f64 {
+		self.buy_threshold
+	}
+
+	pub fn sell_threshold(&self) -> f64 {
+		self.sell_threshold
+	}
+
+	pub fn indicator(&self) -> &I {
+		&self.indicator
+	}
+}
+
+impl<I: Period> Period for ThresholdSignal<I> {
+	fn period(&self) -> usize {
+		self.indicator.period()
+	}
+}
+
+impl<I: Ready> Ready for ThresholdSignal<I> {
+	fn is_ready(&self) -> bool {
+		self.indicator.is_ready()
+	}
+}
+
+impl<I: Next<f64, Output = f64>> Next<f64> for ThresholdSignal<I> {
+	type Output = Signal;
+
+	fn next(&mut self, input: f64) -> Self::Output {
+		let value = self.indicator.next(input);
+		let zone = if value <= self.buy_threshold {
+			Zone::Below
+		} else if value >= self.sell_threshold {
+			Zone::Above
+		} else {
+			Zone::Neutral
+		};
+
+		if zone == core::mem::replace(&mut self.zone, zone) {
+			return Signal::Hold;
+		}
+
+		match zone {
+			Zone::Below => Signal::Buy,
+			Zone::Above => Signal::Sell,
+			Zone::Neutral => Signal::Hold,
+		}
+	}
+}
+
+impl<I: Next<f64, Output = f64>, T: Close> Next<&T> for ThresholdSignal<I> {
+	type Output = Signal;
+
+	fn next(&mut self, input: &T) -> Self::Output {
+		self.next(input.close())
+	}
+}
+
+impl<I: Reset> Reset for ThresholdSignal<I> {
+	fn reset(&mut self) {
+		self.indicator.reset();
+		self.zone = Zone::Neutral;
+	}
+}
+
+impl Default for ThresholdSignal {
+	fn default() -> Self {
+		Self::new(RelativeStrengthIndex::default(), 30.0, 70.0).unwrap()
+	}
+}
+
+impl<I: fmt::Display> fmt::Display for ThresholdSignal<I> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"THRESHOLD({}, {}, {})",
+			self.indicator, self.buy_threshold, self.sell_threshold
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::indicators::SimpleMovingAverage as Sma;
+	use crate::test_helper::*;
+
+	test_indicator!(ThresholdSignal);
+
+	fn identity_signal() -> ThresholdSignal<Sma> {
+		ThresholdSignal::new(Sma::new(1).unwrap(), 30.0, 70.0).unwrap()
+	}
+
+	#[test]
+	fn test_new() {
+		let sma = Sma::new(1).unwrap();
+		assert!(ThresholdSignal::new(sma.clone(), 70.0, 30.0).is_err());
+		assert!(ThresholdSignal::new(sma.clone(), 50.0, 50.0).is_err());
+		assert!(ThresholdSignal::new(sma.clone(), f64::NAN, 70.0).is_err());
+		assert!(ThresholdSignal::new(sma.clone(), 30.0, f64::INFINITY).is_err());
+		assert!(ThresholdSignal::new(sma, 30.0, 70.0).is_ok());
+	}
+
+	#[test]
+	fn test_next() {
+		let test_data = vec![
+			(50.0, Signal::Hold),
+			(30.0, Signal::Buy),
+			(20.0, Signal::Hold),
+			(25.0, Signal::Hold),
+			(40.0, Signal::Hold),
+			(29.0, Signal::Buy),
+			(75.0, Signal::Sell),
+			(80.0, Signal::Hold),
+			(70.0, Signal::Hold),
+			(69.0, Signal::Hold),
+			(71.0, Signal::Sell),
+			(10.0, Signal::Buy),
+		];
+
+		let mut signal = identity_signal();
+		for (value, expected) in test_data {
+			assert_eq!(signal.next(value), expected);
+		}
+	}
+
+	#[test]
+	fn test_first_value_beyond_threshold() {
+		let mut signal = identity_signal();
+		assert_eq!(signal.next(80.0), Signal::Sell);
+		assert_eq!(signal.next(90.0), Signal::Hold);
+	}
+
+	#[test]
+	fn test_next_bar() {
+		let mut signal = identity_signal();
+		assert_eq!(signal.next(&Bar::new().close(20)), Signal::Buy);
+		assert_eq!(signal.next(&Bar::new().close(90)), Signal::Sell);
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut signal = identity_signal();
+		assert_eq!(signal.next(20.0), Signal::Buy);
+		assert_eq!(signal.next(20.0), Signal::Hold);
+
+		signal.reset();
+		assert_eq!(signal.next(20.0), Signal::Buy);
+	}
+
+	#[test]
+	fn test_default() {
+		let signal = ThresholdSignal::default();
+		assert_eq!(signal.buy_threshold(), 30.0);
+		assert_eq!(signal.sell_threshold(), 70.0);
+		assert_eq!(signal.period(), 14);
+	}
+
+	#[test]
+	fn test_display() {
+		let signal = ThresholdSignal::default();
+		assert_eq!(format!("{}", signal), "THRESHOLD(RSI(14), 30, 70)");
+	}
+}
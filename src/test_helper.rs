@@ -20,10 +20,10 @@ impl Bar {
 		}
 	}
 
-	//pub fn open<T: Into<f64>>(mut self, val :T ) -> Self {
-	//    self.open = val.into();
-	//    self
-	//}
+	pub fn open<T: Into<f64>>(mut self, val: T) -> Self {
+		self.open = val.into();
+		self
+	}
 
 	pub fn high<T: Into<f64>>(mut self, val: T) -> Self {
 		self.high = val.into();
@@ -99,6 +99,13 @@ macro_rules! test_indicator {
 			indicator.reset();
 			assert_eq!(indicator.next(12.3), first_output);
 
+			// ensure PartialEq is implemented and Reset restores the initial state
+			indicator.reset();
+			assert_eq!(indicator, $i::default());
+
+			// ensure Period is implemented
+			assert!(indicator.period() > 0);
+
 			// ensure Display is implemented
 			_ = format!("{}", indicator);
 		}
@@ -1,16 +1,29 @@
 // Indicator traits
 //
 
+use alloc::vec::Vec;
+
 /// Resets an indicator to the initial state.
 pub trait Reset {
 	fn reset(&mut self);
 }
 
 /// Return the period used by the indicator.
+///
+/// Indicators combining several windows return their main one, indicators without a window,
+/// e.g. cumulative ones, return 1.
 pub trait Period {
 	fn period(&self) -> usize;
 }
 
+/// Tells whether an indicator has consumed enough input to fill its internal window(s).
+///
+/// Until an indicator is ready, its output is calculated over an incomplete window and
+/// should be treated with caution. Indicators without a window are always ready.
+pub trait Ready {
+	fn is_ready(&self) -> bool;
+}
+
 /// Consumes a data item of type `T` and returns `Output`.
 ///
 /// Typically `T` can be `f64` or a struct similar to [DataItem](struct.DataItem.html), that implements
@@ -23,6 +36,48 @@ pub trait Period {
 pub trait Next<T> {
 	type Output;
 	fn next(&mut self, input: T) -> Self::Output;
+
+	/// Returns the output that `next` would return for the given input, without advancing
+	/// the state of the indicator.
+	///
+	/// The default implementation feeds the input into a clone of the indicator.
+	fn peek(&self, input: T) -> Self::Output
+	where
+		Self: Clone,
+	{
+		self.clone().next(input)
+	}
+
+	/// Feeds all the inputs into the indicator and appends the outputs to `out`.
+	fn next_slice(&mut self, inputs: &[T], out: &mut Vec<Self::Output>)
+	where
+		T: Copy,
+	{
+		out.reserve(inputs.len());
+		for &input in inputs {
+			out.push(self.next(input));
+		}
+	}
+
+	/// Feeds all the inputs into the indicator and returns the outputs.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use ta::indicators::SimpleMovingAverage;
+	/// use ta::Next;
+	///
+	/// let mut sma = SimpleMovingAverage::new(2).unwrap();
+	/// assert_eq!(sma.next_slice_collect(&[2.0, 4.0, 8.0]), vec![2.0, 3.0, 6.0]);
+	/// ```
+	fn next_slice_collect(&mut self, inputs: &[T]) -> Vec<Self::Output>
+	where
+		T: Copy,
+	{
+		let mut out = Vec::with_capacity(inputs.len());
+		self.next_slice(inputs, &mut out);
+		out
+	}
 }
 
 /// Open price of a particular period.
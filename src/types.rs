@@ -0,0 +1,108 @@
+//! Types shared by the indicators.
+
+use core::fmt;
+use core::num::NonZeroUsize;
+
+use crate::errors::TaError;
+
+/// Number of periods of an indicator, which is never 0.
+///
+/// The [SimpleMovingAverage](../indicators/struct.SimpleMovingAverage.html),
+/// [ExponentialMovingAverage](../indicators/struct.ExponentialMovingAverage.html),
+/// [Minimum](../indicators/struct.Minimum.html) and [Maximum](../indicators/struct.Maximum.html)
+/// can be constructed from a `PeriodValue` without failing on their length, with their
+/// `new_with_period` constructors. It is named so as not to clash with the
+/// [Period](../trait.Period.html) trait.
+///
+/// # Example
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use ta::types::PeriodValue;
+///
+/// let period = PeriodValue::try_from(14_usize).unwrap();
+/// assert_eq!(usize::from(period), 14);
+///
+/// assert!(PeriodValue::try_from(0_usize).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PeriodValue(NonZeroUsize);
+
+impl PeriodValue {
+	pub const fn new(period: NonZeroUsize) -> Self {
+		Self(period)
+	}
+
+	pub const fn get(self) -> usize {
+		self.0.get()
+	}
+}
+
+impl TryFrom<usize> for PeriodValue {
+	type Error = TaError;
+
+	fn try_from(period: usize) -> Result<Self, Self::Error> {
+		NonZeroUsize::new(period)
+			.map(Self)
+			.ok_or(TaError::InvalidParameter)
+	}
+}
+
+impl TryFrom<u32> for PeriodValue {
+	type Error = TaError;
+
+	fn try_from(period: u32) -> Result<Self, Self::Error> {
+		usize::try_from(period)
+			.map_err(|_| TaError::InvalidParameter)
+			.and_then(Self::try_from)
+	}
+}
+
+impl From<NonZeroUsize> for PeriodValue {
+	fn from(period: NonZeroUsize) -> Self {
+		Self(period)
+	}
+}
+
+impl From<PeriodValue> for usize {
+	fn from(period: PeriodValue) -> Self {
+		period.get()
+	}
+}
+
+impl fmt::Display for PeriodValue {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}", self.0)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_try_from() {
+		assert_eq!(
+			PeriodValue::try_from(0_usize),
+			Err(TaError::InvalidParameter)
+		);
+		assert_eq!(PeriodValue::try_from(0_u32), Err(TaError::InvalidParameter));
+		assert_eq!(PeriodValue::try_from(3_usize).unwrap().get(), 3);
+		assert_eq!(PeriodValue::try_from(3_u32).unwrap().get(), 3);
+	}
+
+	#[test]
+	fn test_into_usize() {
+		let period = PeriodValue::new(NonZeroUsize::new(9).unwrap());
+		assert_eq!(usize::from(period), 9);
+		assert_eq!(PeriodValue::from(NonZeroUsize::new(9).unwrap()), period);
+	}
+
+	#[test]
+	fn test_display() {
+		assert_eq!(
+			format!("{}", PeriodValue::try_from(20_usize).unwrap()),
+			"20"
+		);
+	}
+}
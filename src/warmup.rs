@@ -0,0 +1,159 @@
+use core::fmt;
+
+use crate::{Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Wraps an indicator to return `None` until it is [ready](trait.Ready.html), so that values
+/// computed over a partial window are not mistaken for valid ones.
+///
+/// The inputs received during the warm-up are still fed to the indicator.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Minimum;
+/// use ta::{Next, Warmup};
+///
+/// let mut min = Warmup::new(Minimum::new(3).unwrap());
+///
+/// assert_eq!(min.next(4.0), None);
+/// assert_eq!(min.next(2.0), None);
+/// assert_eq!(min.next(5.0), Some(2.0));
+/// assert_eq!(min.next(6.0), Some(2.0));
+/// assert_eq!(min.next(7.0), Some(5.0));
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Warmup<I> {
+	indicator: I,
+}
+
+impl<I> Warmup<I> {
+	pub fn new(indicator: I) -> Self {
+		Self { indicator }
+	}
+
+	pub fn inner(&self) -> &I {
+		&self.indicator
+	}
+
+	/// Returns the wrapped indicator.
+	pub fn into_inner(self) -> I {
+		self.indicator
+	}
+}
+
+impl<I: Period> Period for Warmup<I> {
+	fn period(&self) -> usize {
+		self.indicator.period()
+	}
+}
+
+impl<I: Ready> Ready for Warmup<I> {
+	fn is_ready(&self) -> bool {
+		self.indicator.is_ready()
+	}
+}
+
+impl<T, I: Next<T> + Ready> Next<T> for Warmup<I> {
+	type Output = Option<I::Output>;
+
+	fn next(&mut self, input: T) -> Self::Output {
+		let output = self.indicator.next(input);
+		if self.is_ready() {
+			Some(output)
+		} else {
+			None
+		}
+	}
+}
+
+impl<I: Reset> Reset for Warmup<I> {
+	fn reset(&mut self) {
+		self.indicator.reset();
+	}
+}
+
+impl<I: Default> Default for Warmup<I> {
+	fn default() -> Self {
+		Self::new(I::default())
+	}
+}
+
+impl<I: fmt::Display> fmt::Display for Warmup<I> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}", self.indicator)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::indicators::{
+		DoubleExponentialMovingAverage as Dema, ExponentialMovingAverage as Ema, Maximum,
+		SimpleMovingAverage as Sma,
+	};
+	use crate::test_helper::*;
+
+	#[test]
+	fn test_next() {
+		let mut sma = Warmup::new(Sma::new(3).unwrap());
+		assert_eq!(sma.next(3.0), None);
+		assert!(!sma.is_ready());
+		assert_eq!(sma.next(6.0), None);
+		assert_eq!(sma.next(9.0), Some(6.0));
+		assert!(sma.is_ready());
+		assert_eq!(sma.next(12.0), Some(9.0));
+	}
+
+	#[test]
+	fn test_next_period_one() {
+		let mut max = Warmup::new(Maximum::new(1).unwrap());
+		assert_eq!(max.next(3.0), Some(3.0));
+		assert_eq!(max.next(1.0), Some(1.0));
+	}
+
+	#[test]
+	fn test_next_bar() {
+		let mut ema = Warmup::new(Ema::new(2).unwrap());
+		assert_eq!(ema.next(&Bar::new().close(4)), None);
+		assert_eq!(ema.next(&Bar::new().close(7)), Some(6.0));
+	}
+
+	#[test]
+	fn test_next_layered() {
+		// the DEMA is ready once its second EMA has a full period of inputs
+		let mut dema = Warmup::new(Dema::new(3).unwrap());
+		for _ in 0..4 {
+			assert_eq!(dema.next(1.0), None);
+		}
+		assert_eq!(dema.next(1.0), Some(1.0));
+		assert!(dema.inner().is_ready());
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut sma = Warmup::new(Sma::new(2).unwrap());
+		sma.next(2.0);
+		assert_eq!(sma.next(4.0), Some(3.0));
+
+		sma.reset();
+		assert_eq!(sma.next(8.0), None);
+		assert_eq!(sma.next(4.0), Some(6.0));
+		assert_eq!(sma.inner().period(), 2);
+	}
+
+	#[test]
+	fn test_default() {
+		let sma = Warmup::<Sma>::default();
+		assert_eq!(sma.period(), 9);
+	}
+
+	#[test]
+	fn test_display() {
+		let sma = Warmup::new(Sma::new(5).unwrap());
+		assert_eq!(format!("{}", sma), "SMA(5)");
+		assert_eq!(format!("{}", sma.into_inner()), "SMA(5)");
+	}
+}
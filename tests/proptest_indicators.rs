@@ -0,0 +1,152 @@
+//! Property tests of the invariants of the rolling indicators, checked against the window of
+//! the last inputs after every step.
+//!
+//! The inputs are finite for now.
+
+use proptest::prelude::*;
+use ta::indicators::*;
+use ta::Next;
+
+/// Maximum error allowed for floating point results computed from values up to `scale`.
+fn tolerance(scale: f64) -> f64 {
+	1e-9 * (1.0 + scale)
+}
+
+fn inputs() -> impl Strategy<Value = Vec<f64>> {
+	prop::collection::vec(-1e6..1e6, 1..200)
+}
+
+/// Calls `check` with every output and the window of inputs it was computed from.
+fn for_each_window<I, F>(mut indicator: I, period: usize, inputs: &[f64], mut check: F)
+where
+	I: Next<f64>,
+	F: FnMut(I::Output, &[f64]),
+{
+	for (i, &input) in inputs.iter().enumerate() {
+		let output = indicator.next(input);
+		check(output, &inputs[(i + 1).saturating_sub(period)..=i]);
+	}
+}
+
+fn min(window: &[f64]) -> f64 {
+	window.iter().copied().fold(f64::INFINITY, f64::min)
+}
+
+fn max(window: &[f64]) -> f64 {
+	window.iter().copied().fold(f64::NEG_INFINITY, f64::max)
+}
+
+fn scale(window: &[f64]) -> f64 {
+	window.iter().fold(0.0, |acc: f64, x| acc.max(x.abs()))
+}
+
+/// Asserts that `x` is within the range of the window, up to rounding.
+fn assert_within(x: f64, window: &[f64]) {
+	let tolerance = tolerance(scale(window));
+	assert!(
+		min(window) - tolerance <= x && x <= max(window) + tolerance,
+		"{} is out of {:?}",
+		x,
+		window
+	);
+}
+
+proptest! {
+	#[test]
+	fn minimum_is_the_lowest_input(inputs in inputs(), period in 1..20_usize) {
+		let indicator = Minimum::new(period).unwrap();
+		for_each_window(indicator, period, &inputs, |output, window| {
+			assert_eq!(output, min(window));
+		});
+	}
+
+	#[test]
+	fn maximum_is_the_highest_input(inputs in inputs(), period in 1..20_usize) {
+		let indicator = Maximum::new(period).unwrap();
+		for_each_window(indicator, period, &inputs, |output, window| {
+			assert_eq!(output, max(window));
+		});
+	}
+
+	#[test]
+	fn sma_is_the_mean(inputs in inputs(), period in 1..20_usize) {
+		let indicator = SimpleMovingAverage::new(period).unwrap();
+		for_each_window(indicator, period, &inputs, |output, window| {
+			let mean = window.iter().sum::<f64>() / window.len() as f64;
+			assert!((output - mean).abs() <= tolerance(scale(window)));
+			assert_within(output, window);
+		});
+	}
+
+	#[test]
+	fn wma_is_within_range(inputs in inputs(), period in 1..20_usize) {
+		let indicator = WeightedMovingAverage::new(period).unwrap();
+		for_each_window(indicator, period, &inputs, assert_within);
+	}
+
+	#[test]
+	fn median_is_within_range(inputs in inputs(), period in 1..20_usize) {
+		let indicator = Median::new(period).unwrap();
+		for_each_window(indicator, period, &inputs, assert_within);
+	}
+
+	#[test]
+	fn percentile_is_within_range(
+		inputs in inputs(),
+		period in 1..20_usize,
+		quantile in 0.0..=1.0,
+	) {
+		let indicator = Percentile::new(period, quantile).unwrap();
+		for_each_window(indicator, period, &inputs, assert_within);
+	}
+
+	#[test]
+	fn ema_is_within_range_of_all_inputs(inputs in inputs(), period in 1..20_usize) {
+		// the EMA has an infinite memory, so the whole series is the window
+		let indicator = ExponentialMovingAverage::new(period).unwrap();
+		for_each_window(indicator, usize::MAX, &inputs, assert_within);
+	}
+
+	#[test]
+	fn variance_is_not_negative(inputs in inputs(), period in 2..20_usize) {
+		let indicator = Variance::new(period, 1).unwrap();
+		for_each_window(indicator, period, &inputs, |output, window| {
+			assert!(output >= -tolerance(scale(window).powi(2)));
+		});
+	}
+
+	#[test]
+	fn donchian_channel_is_ordered(inputs in inputs(), period in 1..20_usize) {
+		let indicator = DonchianChannel::new(period).unwrap();
+		for_each_window(indicator, period, &inputs, |output, window| {
+			assert_eq!(output.upper, max(window));
+			assert_eq!(output.lower, min(window));
+			assert!(output.lower <= output.middle && output.middle <= output.upper);
+		});
+	}
+
+	#[test]
+	fn linear_regression_r_squared_is_within_unit_range(
+		inputs in inputs(),
+		period in 2..20_usize,
+	) {
+		let indicator = LinearRegression::new(period).unwrap();
+		for_each_window(indicator, period, &inputs, |output, window| {
+			assert!(-1e-6 <= output.r_squared && output.r_squared <= 1.0 + 1e-6);
+			assert_eq!(output.intercept + output.slope * (window.len() - 1) as f64, output.forecast);
+		});
+	}
+
+	#[test]
+	fn bollinger_bands_are_ordered(
+		inputs in inputs(),
+		period in 1..20_usize,
+		multiplier in 0.0..5.0,
+	) {
+		let indicator = BollingerBands::new(period, multiplier).unwrap();
+		for_each_window(indicator, period, &inputs, |output, window| {
+			assert!(output.lower <= output.average && output.average <= output.upper);
+			assert_within(output.average, window);
+		});
+	}
+}
@@ -0,0 +1,163 @@
+//! Checks that every indicator resumes with the same outputs after a JSON round trip.
+//!
+//! serde_json is built with `float_roundtrip`, so the floats are restored bit for bit and the
+//! outputs can be compared exactly.
+#![cfg(feature = "serde")]
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use ta::indicators::*;
+use ta::signals::*;
+use ta::{Close, DataItem, Next, Open};
+
+const SEED: u64 = 42;
+const BEFORE: usize = 10;
+const AFTER: usize = 5;
+
+fn rand_data_items(count: usize) -> Vec<DataItem> {
+	let mut rng = StdRng::seed_from_u64(SEED);
+
+	(0..count)
+		.map(|_| {
+			let low = rng.gen_range(1.0, 500.0);
+			let high = rng.gen_range(500.0, 1000.0);
+			DataItem::builder()
+				.open(rng.gen_range(low, high))
+				.high(high)
+				.low(low)
+				.close(rng.gen_range(low, high))
+				.volume(rng.gen_range(1.0, 10_000.0))
+				.build()
+				.unwrap()
+		})
+		.collect()
+}
+
+fn assert_roundtrip<I, T>(mut indicator: I, inputs: &[T])
+where
+	I: Next<T> + PartialEq + std::fmt::Debug + serde::Serialize + serde::de::DeserializeOwned,
+	I::Output: PartialEq + std::fmt::Debug,
+	T: Copy,
+{
+	for &input in &inputs[..BEFORE] {
+		indicator.next(input);
+	}
+
+	let json = serde_json::to_string(&indicator).unwrap();
+	let mut deserialized: I = serde_json::from_str(&json).unwrap();
+	assert_eq!(deserialized, indicator);
+
+	for &input in &inputs[BEFORE..] {
+		assert_eq!(deserialized.next(input), indicator.next(input), "{}", json);
+	}
+	assert_eq!(deserialized, indicator);
+}
+
+macro_rules! test_roundtrip {
+    ($($indicator:ident), *) => {
+        #[test]
+        #[allow(non_snake_case)]
+        fn test_roundtrip_bars() {
+            let items = rand_data_items(BEFORE + AFTER);
+            let inputs: Vec<&DataItem> = items.iter().collect();
+            $(
+                assert_roundtrip($indicator::default(), &inputs);
+            )*
+        }
+    }
+}
+
+test_roundtrip!(
+	AccumulationDistribution,
+	Aroon,
+	AverageTrueRange,
+	AverageTrueRangePercent,
+	BalanceOfPower,
+	BollingerBandPctB,
+	BollingerBandWidth,
+	BollingerBands,
+	ChaikinMoneyFlow,
+	ChaikinOscillator,
+	ChandeMomentumOscillator,
+	ChandelierExit,
+	ChoppinessIndex,
+	CommodityChannelIndex,
+	CoppockCurve,
+	CumulativeReturn,
+	DetrendedPriceOscillator,
+	DirectionalMovementIndex,
+	Divergence,
+	DonchianChannel,
+	DoubleExponentialMovingAverage,
+	EaseOfMovement,
+	EfficiencyRatio,
+	ElderRay,
+	ExponentialMovingAverage,
+	FastStochastic,
+	ForceIndex,
+	HistoricalVolatility,
+	HullMovingAverage,
+	IchimokuCloud,
+	KaufmanAdaptiveMovingAverage,
+	KeltnerChannel,
+	KlingerOscillator,
+	Kurtosis,
+	LinearRegression,
+	LogReturn,
+	MassIndex,
+	MaxDrawdown,
+	Maximum,
+	MeanAbsoluteDeviation,
+	Median,
+	MedianPrice,
+	Minimum,
+	MoneyFlowIndex,
+	MovingAverageConvergenceDivergence,
+	OnBalanceVolume,
+	ParabolicSar,
+	PercentagePriceOscillator,
+	Percentile,
+	PivotPoints,
+	PriceVolumeTrend,
+	RateOfChange,
+	RelativeStrengthIndex,
+	RollingSum,
+	SharpeRatio,
+	SimpleMovingAverage,
+	Skewness,
+	SlowStochastic,
+	StandardDeviation,
+	StandardError,
+	StochasticOscillator,
+	StochasticRsi,
+	ThresholdSignal,
+	TripleExponentialAverage,
+	TripleExponentialAverageSignal,
+	TripleExponentialMovingAverage,
+	TimeSeriesForecast,
+	TrueRange,
+	TypicalPrice,
+	UltimateOscillator,
+	Variance,
+	VolumeRsi,
+	VolumeWeightedAveragePrice,
+	VolumeWeightedAveragePriceBands,
+	VortexIndicator,
+	WeightedClose,
+	WeightedMovingAverage,
+	WilliamsPercentRange,
+	ZeroLagExponentialMovingAverage,
+	Zigzag
+);
+
+#[test]
+fn test_roundtrip_pairs() {
+	let inputs: Vec<(f64, f64)> = rand_data_items(BEFORE + AFTER)
+		.iter()
+		.map(|item| (item.close(), item.open()))
+		.collect();
+
+	assert_roundtrip(Correlation::default(), &inputs);
+	assert_roundtrip(Covariance::default(), &inputs);
+	assert_roundtrip(Crossover::default(), &inputs);
+}
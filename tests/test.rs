@@ -20,4 +20,26 @@ mod test {
 			assert_eq!(deserialized.next(2.0), macd.next(2.0));
 		}
 	}
+	mod generic_float {
+		use ta::indicators::{ExponentialMovingAverage, Maximum, Minimum, SimpleMovingAverage};
+		use ta::Next;
+
+		#[test]
+		fn test_f32() {
+			let mut min = Minimum::<f32>::new_generic(3).unwrap();
+			let mut max = Maximum::<f32>::new_generic(3).unwrap();
+			let mut sma = SimpleMovingAverage::<f32>::new_generic(3).unwrap();
+			let mut ema = ExponentialMovingAverage::<f32>::new_generic(3).unwrap();
+
+			let outputs: Vec<(f32, f32, f32, f32)> = [4.0f32, 2.0, 6.0, 8.0]
+				.iter()
+				.map(|&x| (min.next(x), max.next(x), sma.next(x), ema.next(x)))
+				.collect();
+
+			assert_eq!(outputs[0], (4.0, 4.0, 4.0, 4.0));
+			assert_eq!(outputs[1], (2.0, 4.0, 3.0, 3.0));
+			assert_eq!(outputs[2], (2.0, 6.0, 4.0, 4.5));
+			assert_eq!(outputs[3], (2.0, 8.0, 16.0 / 3.0, 6.25));
+		}
+	}
 }